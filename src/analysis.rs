@@ -0,0 +1,522 @@
+//! Analyse des coups possibles d’une position
+//!
+//! Les intelligences artificielles capables d’évaluer chaque coup implémentent le trait
+//! [`Analyzer`]. Leur analyse permet notamment de conseiller un joueur ou de commenter une partie
+//! terminée (voir [`review`]).
+//!
+//! Le module fourni également des outils de détection des menaces doubles (voir
+//! [`has_double_threat`]), contre lesquelles l’adversaire ne peut plus se défendre, et
+//! d’analyse de la parité des menaces (voir [`ThreatReport`]), qui décide des fins de partie.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, State};
+//! use connect_four::analysis::{Analyzer, Score};
+//! use connect_four::minimax_bot::MinimaxBot;
+//!
+//! let area : Area = "
+//!     .......
+//!     .......
+//!     .......
+//!     .......
+//!     .......
+//!     YYY.RR.
+//! ".parse().unwrap();
+//!
+//! let mut bot = MinimaxBot::new("Robot", 4);
+//! let scores = bot.analyze(&area, State::YellowToken).unwrap();
+//!
+//! // Les jetons jaunes gagnent immédiatement en colonne 3
+//! assert_eq!(scores.iter().find(|s| s.column == 3).unwrap().score, Score::Win(1));
+//! ```
+
+use super::connect_four::{Area, State, GameRecord, PlayerId, Error, Result};
+use super::{i18n, tr};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// Écart d’estimation à partir duquel un coup est une imprécision
+const INACCURACY_THRESHOLD : i64 = 30;
+
+/// Écart d’estimation à partir duquel un coup est une erreur
+const MISTAKE_THRESHOLD : i64 = 80;
+
+/// Écart d’estimation à partir duquel un coup est une gaffe
+const BLUNDER_THRESHOLD : i64 = 200;
+
+/// Évaluation d’un coup du point de vue du joueur le jouant
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Score {
+    /// Le joueur gagne au plus tard à son n-ième coup, celui analysé compris
+    Win(usize),
+    /// L’adversaire gagne au plus tard à son n-ième coup suivant celui analysé
+    Loss(usize),
+    /// La partie se termine par un match nul
+    Draw,
+    /// Estimation de la position, positive si elle est favorable au joueur
+    Heuristic(i32),
+}
+
+impl Score {
+    /// Indique si l’issue de la partie est connue
+    pub fn is_exact(&self) -> bool {
+        return !matches!(self, Score::Heuristic(_));
+    }
+
+    /// Clé de tri des évaluations, de la pire à la meilleure
+    fn rank(&self) -> (i8, i64) {
+        match self {
+            Score::Loss(n) => (0, *n as i64),
+            Score::Draw => (1, 0),
+            Score::Heuristic(score) => (1, *score as i64),
+            Score::Win(n) => (2, -(*n as i64)),
+        }
+    }
+}
+
+/// Les évaluations sont ordonnées de la pire à la meilleure: une défaite tardive est meilleure
+/// qu’une défaite rapide, une victoire rapide meilleure qu’une victoire tardive. Un match nul est
+/// placé juste au-dessus d’une estimation nulle.
+impl Ord for Score {
+    fn cmp(&self, other : &Self) -> Ordering {
+        return self.rank().cmp(&other.rank()).then_with(|| self.is_exact().cmp(&other.is_exact()));
+    }
+}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Score::Win(1) => f.write_str(i18n::message("score.wins_now")),
+            Score::Win(n) => f.write_str(&tr!("score.wins_in", n)),
+            Score::Loss(1) => f.write_str(i18n::message("score.loses_next")),
+            Score::Loss(n) => f.write_str(&tr!("score.loses_in", n)),
+            Score::Draw => f.write_str(i18n::message("score.draw")),
+            Score::Heuristic(score) => write!(f, "{:+}", score),
+        }
+    }
+}
+
+/// Évaluation d’une colonne jouable
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnScore {
+    /// Colonne jouée
+    pub column : usize,
+    /// Évaluation du coup
+    pub score : Score,
+}
+
+/// Intelligence artificielle capable d’évaluer chaque coup possible
+pub trait Analyzer {
+    /// Évalue chaque coup possible d’une position
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position à analyser
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///
+    /// # Retour
+    ///
+    /// L’évaluation de chaque colonne jouable, par ordre croissant de colonne, ou une erreur si la
+    /// position ne peut pas être analysée.
+    fn analyze(&mut self, area : &Area, token : State) -> Result<Vec<ColumnScore>>;
+
+    /// Recherche le coup le mieux évalué
+    ///
+    /// Parmi les coups de meilleure évaluation, le premier fourni par [`Analyzer::analyze`] est
+    /// choisi.
+    ///
+    /// # Retour
+    ///
+    /// L’évaluation du meilleur coup ou `None` si aucune colonne n’est jouable
+    fn best(&mut self, area : &Area, token : State) -> Result<Option<ColumnScore>> {
+        let scores = self.analyze(area, token)?;
+
+        return Ok(scores.into_iter().rev().max_by_key(|s| s.score));
+    }
+}
+
+/// Qualification d’un coup s’écartant du meilleur coup
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Annotation {
+    /// Le coup est légèrement moins bon que le meilleur coup, ou retarde une victoire ou accélère
+    /// une défaite
+    Inaccuracy,
+    /// Le coup est nettement moins bon que le meilleur coup ou laisse échapper une victoire
+    Mistake,
+    /// Le coup transforme une position non perdue en défaite ou est bien moins bon que le
+    /// meilleur coup
+    Blunder,
+}
+
+impl Annotation {
+    /// Qualifie le coup joué par rapport au meilleur coup
+    ///
+    /// # Arguments
+    ///
+    ///  * `best` : L’évaluation du meilleur coup
+    ///  * `played` : L’évaluation du coup joué
+    ///
+    /// # Retour
+    ///
+    /// La qualification du coup ou `None` si l’écart est négligeable
+    pub fn classify(best : Score, played : Score) -> Option<Self> {
+        if played >= best {
+            return None;
+        }
+
+        return match (best, played) {
+            (_, Score::Loss(_)) if !matches!(best, Score::Loss(_)) => Some(Annotation::Blunder),
+            (Score::Win(_), Score::Win(_)) | (Score::Loss(_), Score::Loss(_)) => Some(Annotation::Inaccuracy),
+            (Score::Win(_), _) => Some(Annotation::Mistake),
+            (best, played) => match best.rank().1 - played.rank().1 {
+                d if d >= BLUNDER_THRESHOLD => Some(Annotation::Blunder),
+                d if d >= MISTAKE_THRESHOLD => Some(Annotation::Mistake),
+                d if d >= INACCURACY_THRESHOLD => Some(Annotation::Inaccuracy),
+                _ => None,
+            },
+        };
+    }
+}
+
+/// Les annotations sont affichées suivant la notation échiquéenne: `?!`, `?` et `??`
+impl Display for Annotation {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(match self {
+            Annotation::Inaccuracy => "?!",
+            Annotation::Mistake => "?",
+            Annotation::Blunder => "??",
+        });
+    }
+}
+
+/// Analyse d’un coup d’une partie
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveReview {
+    /// L’identifiant du joueur ayant joué le coup (1 ou 2)
+    pub player : PlayerId,
+    /// Le jeton placé
+    pub token : State,
+    /// Le coup joué et son évaluation
+    pub played : ColumnScore,
+    /// Le meilleur coup de la position et son évaluation
+    pub best : ColumnScore,
+    /// La qualification du coup joué, `None` s’il est satisfaisant
+    pub annotation : Option<Annotation>,
+    /// Indique si le coup joué permet à l’adversaire de former une menace double (voir
+    /// [`has_double_threat`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub allowed_double_threat : bool,
+}
+
+/// Indique si un joueur possède des menaces que son adversaire ne peut pas toutes parer en un coup
+///
+/// Il s’agit soit de plusieurs menaces immédiates (voir [`Area::immediate_threats`]), soit d’une
+/// menace immédiate surmontée d’une seconde case gagnante: le jeton bloquant la première menace
+/// permet au joueur de jouer la seconde.
+///
+/// # Arguments
+///
+///  * `area` : La position à analyser
+///  * `token` : Le jeton du joueur menaçant
+///
+/// # Retour
+///
+/// `true` si le joueur gagnera à son prochain coup quelle que soit la réponse adverse, à moins que
+/// l’adversaire ne gagne immédiatement.
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, State};
+/// use connect_four::analysis::has_double_threat;
+///
+/// let area : Area = "
+///     .......
+///     .......
+///     .......
+///     .......
+///     ..RR...
+///     .YYY.R.
+/// ".parse().unwrap();
+///
+/// // Les jetons jaunes menacent les colonnes 0 et 4
+/// assert!(has_double_threat(&area, State::YellowToken));
+/// assert!(!has_double_threat(&area, State::RedToken));
+/// ```
+pub fn has_double_threat(area : &Area, token : State) -> bool {
+    let threats = area.immediate_threats(token);
+    let blocker = match State::TOKENS.iter().find(|other| **other != token) {
+        Some(blocker) if !threats.is_empty() => *blocker,
+        _ => return false,
+    };
+
+    // Seules les colonnes menacées peuvent parer la victoire
+    return threats.iter().all(|column| {
+        let mut blocked = area.clone();
+
+        blocked.set_token(blocker, *column).is_ok() && !blocked.immediate_threats(token).is_empty()
+    });
+}
+
+/// Fourni les coups permettant à un joueur de former une menace double
+///
+/// Les coups gagnant immédiatement ne sont pas retenus.
+///
+/// # Arguments
+///
+///  * `area` : La position à analyser
+///  * `token` : Le jeton du joueur devant jouer
+///
+/// # Retour
+///
+/// Les colonnes concernées, dans l’ordre croissant
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, State};
+/// use connect_four::analysis::double_threat_moves;
+///
+/// let area : Area = "
+///     .......
+///     .......
+///     .......
+///     .......
+///     ..RR...
+///     ..YY...
+/// ".parse().unwrap();
+///
+/// // Un jeton jaune en colonne 1 ou 4 menace les deux extrémités de l’alignement
+/// assert_eq!(double_threat_moves(&area, State::YellowToken), vec![1, 4]);
+/// ```
+pub fn double_threat_moves(area : &Area, token : State) -> Vec<usize> {
+    return area.get_available_columns().into_iter().filter(|column| {
+        let mut next = area.clone();
+
+        matches!(next.set_token(token, *column), Ok(false)) && has_double_threat(&next, token)
+    }).collect();
+}
+
+/// Menaces d’un joueur, classées selon la parité de leur ligne
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerThreats {
+    /// Le jeton du joueur
+    pub token : State,
+    /// Les cases `(colonne, ligne)` gagnantes situées sur une ligne impaire en partant du bas (la
+    /// première, la troisième…), par colonne puis ligne croissante
+    pub odd : Vec<(usize, usize)>,
+    /// Les cases gagnantes situées sur une ligne paire, par colonne puis ligne croissante
+    pub even : Vec<(usize, usize)>,
+}
+
+impl PlayerThreats {
+    /// Recherche les cases gagnantes d’un joueur non jouables immédiatement
+    fn new(area : &Area, token : State) -> Self {
+        let config = area.config();
+        let mut threats = Self { token, odd: Vec::new(), even: Vec::new() };
+
+        for (col, height) in area.legal_moves() {
+            for row in height + 1..config.rows {
+                if !matches!(area.is_winning_cell(token, col, row), Ok(true)) {
+                    continue;
+                }
+
+                if row.is_multiple_of(2) {
+                    threats.odd.push((col, row));
+                } else {
+                    threats.even.push((col, row));
+                }
+            }
+        }
+
+        return threats;
+    }
+
+    /// Indique si une menace de ce joueur est située sous la case donnée, dans la même colonne
+    fn below(&self, (col, row) : (usize, usize)) -> bool {
+        return self.odd.iter().chain(self.even.iter()).any(|(c, r)| *c == col && *r < row);
+    }
+}
+
+/// Analyse de la parité des menaces d’une partie à deux joueurs
+///
+/// Une menace est une case vide où un jeton formerait un alignement. Lorsqu’elle n’est pas
+/// jouable immédiatement, aucun joueur ne souhaite jouer sous elle: en fin de partie, les
+/// colonnes se remplissent et le joueur ayant commencé la partie peut s’assurer d’occuper les
+/// cases des lignes impaires (en partant du bas), son adversaire celles des lignes paires. Une
+/// menace impaire profite donc au premier joueur et une menace paire au second. Les menaces
+/// jouables immédiatement sont données par [`Area::immediate_threats`].
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, State};
+/// use connect_four::analysis::ThreatReport;
+///
+/// let area : Area = "
+///     .......
+///     .......
+///     .......
+///     YYY....
+///     RRY....
+///     YRR..R.
+/// ".parse().unwrap();
+///
+/// let report = ThreatReport::new(&area, State::YellowToken);
+///
+/// // La case (3, 2) est sur la troisième ligne: elle profite aux jaunes, ayant commencé
+/// assert_eq!(report.first.token, State::YellowToken);
+/// assert_eq!(report.first.odd, vec![(3, 2)]);
+/// assert_eq!(report.favorable(State::YellowToken), 1);
+/// assert_eq!(report.zugzwang(), Some((State::YellowToken, (3, 2))));
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThreatReport {
+    /// Les menaces du joueur ayant commencé la partie
+    pub first : PlayerThreats,
+    /// Les menaces de son adversaire
+    pub second : PlayerThreats,
+}
+
+impl ThreatReport {
+    /// Analyse les menaces d’une position
+    ///
+    /// Le joueur ayant commencé la partie est déduit du nombre de jetons placés.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position à analyser
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    pub fn new(area : &Area, token : State) -> Self {
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+        let (first, second) = if area.token_count().is_multiple_of(2) { (token, opponent) } else { (opponent, token) };
+
+        return Self {
+            first: PlayerThreats::new(area, first),
+            second: PlayerThreats::new(area, second),
+        };
+    }
+
+    /// Fourni les menaces du joueur d’un jeton donné, ou `None` si aucun joueur ne le possède
+    pub fn threats(&self, token : State) -> Option<&PlayerThreats> {
+        return [&self.first, &self.second].iter().find(|threats| threats.token == token).copied();
+    }
+
+    /// Fourni le nombre de menaces d’un joueur situées sur une ligne lui étant favorable
+    ///
+    /// Il s’agit des menaces impaires du premier joueur ou des menaces paires du second.
+    pub fn favorable(&self, token : State) -> usize {
+        return if token == self.first.token {
+            self.first.odd.len()
+        } else if token == self.second.token {
+            self.second.even.len()
+        } else {
+            0
+        };
+    }
+
+    /// Recherche le joueur contrôlant la fin de partie selon la règle de parité simplifiée
+    ///
+    /// Une menace n’est retenue que si aucune menace adverse n’est située sous elle. Le premier
+    /// joueur l’emporte s’il possède une menace impaire retenue, sinon le second s’il possède une
+    /// menace paire retenue.
+    ///
+    /// # Retour
+    ///
+    /// Le jeton du joueur et la case de la menace décisive, ou `None` si aucun joueur ne contrôle
+    /// la fin de partie
+    pub fn zugzwang(&self) -> Option<(State, (usize, usize))> {
+        if let Some(cell) = self.first.odd.iter().copied().find(|cell| !self.second.below(*cell)) {
+            return Some((self.first.token, cell));
+        }
+
+        return self.second.even.iter().copied().find(|cell| !self.first.below(*cell)).map(|cell| (self.second.token, cell));
+    }
+}
+
+/// Analyse chaque coup d’une partie enregistrée
+///
+/// Les coups sont rejoués depuis une zone de jeu vide. Avant chaque coup, la position est
+/// analysée et le coup joué est comparé au meilleur coup afin de le qualifier.
+///
+/// # Arguments
+///
+///  * `record` : L’enregistrement de la partie
+///  * `analyzer` : L’intelligence artificielle évaluant les coups
+///
+/// # Retour
+///
+/// L’analyse de chaque coup, du premier au dernier
+///
+/// # Liste des erreurs possibles
+///
+///  * `InvalidConfig` : La configuration de l’enregistrement est invalide.
+///  * `InvalidPlayerId` : L’identifiant du premier joueur est invalide (doit valoir 1 ou 2)
+///  * `NotAToken` : Le jeton du premier joueur ne correspond pas à celui d’un jeton.
+///  * `InvalidColumn`, `FilledColumn`, `GameAlreadyFinished` : L’un des coups enregistrés est
+///    illégal.
+///  * Toute erreur émise par l’analyseur
+pub fn review(record : &GameRecord, analyzer : &mut dyn Analyzer) -> Result<Vec<MoveReview>> {
+    if record.first_player.get() > 2 {
+        return Err(Error::InvalidPlayerId(record.first_player.get()));
+    }
+
+    if record.player_one_token == State::NoToken {
+        return Err(Error::NotAToken);
+    }
+
+    let mut area = Area::with_config(record.config)?;
+    let mut player = record.first_player;
+    let mut reviews = Vec::with_capacity(record.moves.len());
+
+    for column in record.moves.iter().copied() {
+        if !area.winning_cells().is_empty() {
+            return Err(Error::GameAlreadyFinished);
+        }
+
+        let token = match (player, record.player_one_token) {
+            (PlayerId::ONE, token) => token,
+            (_, State::RedToken) => State::YellowToken,
+            _ => State::RedToken,
+        };
+
+        let scores = analyzer.analyze(&area, token)?;
+        let played = scores.iter().find(|s| s.column == column).copied().ok_or(if column < record.config.cols {
+            Error::FilledColumn
+        } else {
+            Error::InvalidColumn
+        })?;
+        let best = scores.iter().rev().max_by_key(|s| s.score).copied().unwrap_or(played);
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+        let already_forked = has_double_threat(&area, opponent);
+
+        reviews.push(MoveReview {
+            player, token, played, best,
+            annotation: Annotation::classify(best.score, played.score),
+            allowed_double_threat: false,
+        });
+
+        let won = area.set_token(token, column)?;
+
+        // Une menace double n’est effective que si le joueur ne peut pas gagner immédiatement
+        if !won && !already_forked && area.immediate_threats(token).is_empty() {
+            reviews.last_mut().unwrap().allowed_double_threat = has_double_threat(&area, opponent) || !double_threat_moves(&area, opponent).is_empty();
+        }
+        player = player.next(2);
+    }
+
+    return Ok(reviews);
+}
@@ -0,0 +1,248 @@
+//! Archive des parties terminées
+//!
+//! Chaque partie terminée peut être ajoutée à la fin d’un fichier d’archive, accompagnée du nom
+//! des joueurs, de sa date et de son résultat. L’archive constitue ainsi une base de parties
+//! exploitable par les outils d’analyse.
+//!
+//! Le fichier est un fichier texte contenant une ligne par partie. Les champs, séparés par des
+//! tabulations, sont:
+//!  * la date de fin de la partie, en secondes depuis le 1er janvier 1970 (UTC)
+//!  * le résultat: `1` ou `2` pour une victoire du joueur correspondant, `=` pour un match nul,
+//!    `T1` ou `T2` pour un dépassement du temps de réflexion du joueur correspondant et `*` pour
+//!    une partie inachevée ou interrompue
+//!  * le nombre de colonnes, de lignes et de jetons à aligner, ce dernier étant suivi de `c` pour
+//!    un plateau cylindrique (voir [`AreaConfig::wrap_columns`])
+//!  * le joueur ayant commencé la partie (1 ou 2) et le jeton du joueur 1 (`Y` ou `R`)
+//!  * les coups joués dans la notation compacte (voir [`GameRecord::to_notation`]), une partie
+//!    contenant des retraits étant relue comme jouée dans la variante Pop Out et une partie
+//!    contenant un échange des camps avec la règle du gâteau
+//!  * le nom du joueur 1 puis celui du joueur 2
+//!
+//! L’objectif des parties (voir [`Objective`]) n’est pas conservé: le résultat archivé reste
+//! néanmoins celui de la partie jouée. Les parties avec handicap (voir
+//! [`Handicap`](crate::Handicap)) et celles jouées sur un plateau comportant des cases bloquées
+//! (voir [`AreaConfig::blocked`]) ne peuvent pas être archivées, le format ne décrivant ni les
+//! jetons placés avant le premier coup ni les obstacles.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{AreaConfig, GameRecord, GameStatus, Handicap, PlayerId};
+//! use connect_four::archive::{self, ArchivedGame};
+//!
+//! let game = ArchivedGame {
+//!     timestamp: 1_700_000_000,
+//!     players: [String::from("Alice"), String::from("Bob")],
+//!     record: GameRecord::from_notation("1212121", AreaConfig::default()).unwrap(),
+//!     status: GameStatus::Won(PlayerId::ONE),
+//! };
+//!
+//! let mut data = Vec::new();
+//!
+//! archive::write(&mut data, &game).unwrap();
+//! archive::write(&mut data, &game).unwrap();
+//!
+//! assert_eq!(archive::read(&mut data.as_slice()).unwrap(), vec![game.clone(), game.clone()]);
+//!
+//! // Sur un plateau cylindrique, l’alignement passe de la dernière colonne à la première
+//! let config = AreaConfig { wrap_columns: true, ..AreaConfig::default() };
+//! let cylinder = ArchivedGame {
+//!     record: GameRecord::from_notation("1122667", config).unwrap(),
+//!     ..game.clone()
+//! };
+//! let mut data = Vec::new();
+//!
+//! archive::write(&mut data, &cylinder).unwrap();
+//!
+//! assert_eq!(archive::read(&mut data.as_slice()).unwrap(), vec![cylinder.clone()]);
+//!
+//! // Les jetons de handicap ne sont pas décrits par le format
+//! let mut handicapped = cylinder;
+//!
+//! handicapped.record = GameRecord { handicap: Some(Handicap { player_id: PlayerId::TWO, columns: vec![3] }), ..handicapped.record };
+//!
+//! assert!(archive::write(&mut Vec::new(), &handicapped).is_err());
+//!
+//! // Pas plus que les cases bloquées
+//! let mut blocked = game;
+//!
+//! blocked.record.config.blocked = 1;
+//!
+//! assert!(archive::write(&mut Vec::new(), &blocked).is_err());
+//! ```
+
+use super::connect_four::{AreaConfig, GameRecord, GameStatus, Objective, PlayerId, State, Variant};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Partie archivée
+#[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArchivedGame {
+    /// Date de fin de la partie, en secondes depuis le 1er janvier 1970 (UTC)
+    pub timestamp : u64,
+    /// Noms des joueurs 1 et 2
+    pub players : [String; 2],
+    /// Enregistrement de la partie
+    pub record : GameRecord,
+    /// État de la partie à son archivage
+    pub status : GameStatus,
+}
+
+impl ArchivedGame {
+    /// Crée une partie archivée datée de l’instant présent
+    ///
+    /// # Arguments
+    ///
+    ///  * `players` : Les noms des joueurs 1 et 2
+    ///  * `record` : L’enregistrement de la partie
+    ///  * `status` : L’état de la partie
+    pub fn now(players : [String; 2], record : GameRecord, status : GameStatus) -> Self {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+        return Self { timestamp, players, record, status };
+    }
+}
+
+/// Ajoute une partie à la fin d’un fichier d’archive, créé s’il n’existe pas
+///
+/// # Liste des erreurs possibles
+///
+/// Voir [`write()`]
+pub fn append<P : AsRef<Path>>(path : P, game : &ArchivedGame) -> io::Result<()> {
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+
+    return write(&mut file, game);
+}
+
+/// Charge l’ensemble des parties d’un fichier d’archive
+///
+/// # Liste des erreurs possibles
+///
+/// Voir [`read`]
+pub fn load<P : AsRef<Path>>(path : P) -> io::Result<Vec<ArchivedGame>> {
+    return read(&mut io::BufReader::new(std::fs::File::open(path)?));
+}
+
+/// Écrit une partie sur une ligne
+///
+/// # Liste des erreurs possibles
+///
+/// Toute erreur d’entrée/sortie, `InvalidInput` si la partie rejouée ne correspond pas à son
+/// état (voir [`GameRecord::verify`]), si l’un des coups ne peut pas être noté, si
+/// l’enregistrement ne désigne pas de jeton pour le joueur 1, si la partie a été jouée avec
+/// handicap ou sur un plateau comportant des cases bloquées ou si un nom de joueur contient une
+/// tabulation ou un retour à la ligne.
+pub fn write<W : Write>(writer : &mut W, game : &ArchivedGame) -> io::Result<()> {
+    let invalid = |msg : &str| io::Error::new(io::ErrorKind::InvalidInput, msg);
+
+    if game.record.handicap.is_some() {
+        return Err(invalid("Les parties avec handicap ne peuvent pas être archivées"));
+    }
+
+    if game.record.config.blocked != 0 {
+        return Err(invalid("Les cases bloquées ne peuvent pas être archivées"));
+    }
+
+    game.record.verify(game.status).map_err(|e| invalid(&e.to_string()))?;
+
+    let status = match game.status {
+        GameStatus::Won(player_id) => player_id.to_string(),
+        GameStatus::Draw => String::from("="),
+        GameStatus::Timeout(player_id) => format!("T{}", player_id),
+        GameStatus::InProgress | GameStatus::Aborted => String::from("*"),
+    };
+    let token = match game.record.player_one_token {
+        State::YellowToken => 'Y',
+        State::RedToken => 'R',
+        _ => return Err(invalid("Le joueur 1 n’a pas de jeton")),
+    };
+    let notation = game.record.to_notation().map_err(|e| invalid(&e.to_string()))?;
+
+    if game.players.iter().any(|name| name.contains(['\t', '\n', '\r'])) {
+        return Err(invalid("Nom de joueur invalide"));
+    }
+
+    let config = game.record.config;
+    let wrap = if config.wrap_columns { "c" } else { "" };
+
+    return writeln!(writer, "{}\t{}\t{}\t{}\t{}{}\t{}\t{}\t{}\t{}\t{}", game.timestamp, status, config.cols, config.rows,
+        config.win_length, wrap, game.record.first_player, token, notation, game.players[0], game.players[1]);
+}
+
+/// Lit l’ensemble des parties d’une archive
+///
+/// # Liste des erreurs possibles
+///
+/// Toute erreur d’entrée/sortie, `InvalidData` si l’une des lignes n’est pas une partie valide.
+pub fn read<R : BufRead>(reader : &mut R) -> io::Result<Vec<ArchivedGame>> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Le fichier n’est pas une archive de parties");
+    let mut games = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields : Vec<&str> = line.splitn(10, '\t').collect();
+
+        if fields.len() != 10 {
+            return Err(invalid());
+        }
+
+        let number = |field : &str| field.parse::<usize>().map_err(|_| invalid());
+
+        let status = match fields[1] {
+            "=" => GameStatus::Draw,
+            "*" => GameStatus::InProgress,
+            "1" => GameStatus::Won(PlayerId::ONE),
+            "2" => GameStatus::Won(PlayerId::TWO),
+            "T1" => GameStatus::Timeout(PlayerId::ONE),
+            "T2" => GameStatus::Timeout(PlayerId::TWO),
+            _ => return Err(invalid()),
+        };
+        let (win_length, wrap_columns) = match fields[4].strip_suffix('c') {
+            Some(win_length) => (win_length, true),
+            None => (fields[4], false),
+        };
+        let config = AreaConfig { wrap_columns, ..AreaConfig::new(number(fields[2])?, number(fields[3])?, number(win_length)?).map_err(|_| invalid())? };
+
+        config.validate().map_err(|_| invalid())?;
+
+        let player_one_token = match fields[6] {
+            "Y" => State::YellowToken,
+            "R" => State::RedToken,
+            _ => return Err(invalid()),
+        };
+        let (moves, pops, swaps) = GameRecord::parse_moves(fields[7]).map_err(|_| invalid())?;
+        let record = GameRecord {
+            config,
+            first_player: PlayerId::new(number(fields[5])?).map_err(|_| invalid())?,
+            player_one_token,
+            moves,
+            variant: if pops.is_empty() { Variant::Standard } else { Variant::PopOut },
+            pops,
+            objective: Objective::Connect,
+            players: 2,
+            handicap: None,
+            swap_rule: !swaps.is_empty(),
+            swaps,
+            think_times: Vec::new(),
+            nodes: Vec::new(),
+        };
+
+        record.positions().map_err(|_| invalid())?;
+
+        games.push(ArchivedGame {
+            timestamp: fields[0].parse().map_err(|_| invalid())?,
+            players: [String::from(fields[8]), String::from(fields[9])],
+            record,
+            status,
+        });
+    }
+
+    return Ok(games);
+}
@@ -0,0 +1,342 @@
+//! Confrontation de deux intelligences artificielles sur une série de parties
+//!
+//! Une série de parties permet de mesurer l’écart de niveau entre deux intelligences
+//! artificielles, par exemple afin de vérifier qu’une modification les améliore. Le joueur
+//! commençant la partie change à chaque partie et joue toujours les jetons jaunes.
+//!
+//! Un match au meilleur de N parties ([`Series`]) oppose de la même manière deux joueurs
+//! quelconques, humains ou non, jusqu’à ce que l’un d’eux remporte la majorité des parties.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::Engine;
+//! use connect_four::arena::play_match;
+//! use connect_four::greedy_bot::GreedyBot;
+//! use connect_four::random_bot::RandomBot;
+//!
+//! let mut engine = Engine::new(Box::new(GreedyBot::new("Glouton")), Box::new(RandomBot::new("Aléatoire")));
+//!
+//! let stats = play_match(&mut engine, 20).unwrap();
+//!
+//! assert_eq!(stats.games(), 20);
+//! println!("Score du robot glouton: {:.1} %", 100.0 * stats.score());
+//! ```
+
+use super::connect_four::{Engine, Error, GameResult, GameStatus, PlayerId, State, Result};
+
+/// Coefficient de la loi normale correspondant à un intervalle de confiance de 95 %
+const CONFIDENCE_Z : f64 = 1.96;
+
+/// Résultats d’une série de parties du point de vue du premier joueur
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchStats {
+    /// Nombre de parties gagnées par le premier joueur
+    pub wins : usize,
+    /// Nombre de matchs nuls
+    pub draws : usize,
+    /// Nombre de parties perdues par le premier joueur
+    pub losses : usize,
+}
+
+impl MatchStats {
+    /// Comptabilise le résultat d’une partie
+    ///
+    /// Une partie interrompue n’est pas comptabilisée.
+    ///
+    /// # Arguments
+    ///
+    ///  * `result` : Le résultat de la partie
+    pub fn add(&mut self, result : &GameResult) {
+        match result {
+            GameResult::Winner { player_id: PlayerId::ONE, .. } | GameResult::Timeout { player_id: PlayerId::TWO } => self.wins += 1,
+            GameResult::Winner { .. } | GameResult::Timeout { .. } => self.losses += 1,
+            GameResult::Draw => self.draws += 1,
+            GameResult::Aborted => {},
+        }
+    }
+
+    /// Fourni le nombre de parties comptabilisées
+    pub fn games(&self) -> usize {
+        return self.wins + self.draws + self.losses;
+    }
+
+    /// Fourni le score moyen du premier joueur, une victoire valant un point et un match nul un
+    /// demi-point
+    ///
+    /// # Retour
+    ///
+    /// Le score, compris entre 0 et 1, ou 0.5 si aucune partie n’a été comptabilisée
+    pub fn score(&self) -> f64 {
+        if self.games() == 0 {
+            return 0.5;
+        }
+
+        return (self.wins as f64 + self.draws as f64 / 2.0) / self.games() as f64;
+    }
+
+    /// Fourni l’intervalle de confiance à 95 % du score du premier joueur
+    ///
+    /// L’intervalle de Wilson est utilisé, la variance d’une partie tenant compte des matchs nuls.
+    /// Contrairement à l’approximation normale, il reste significatif lorsque l’un des joueurs
+    /// remporte toutes les parties.
+    ///
+    /// # Retour
+    ///
+    /// Les bornes inférieure et supérieure de l’intervalle, comprises entre 0 et 1
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::arena::MatchStats;
+    ///
+    /// let stats = MatchStats { wins: 0, draws: 0, losses: 10 };
+    /// let (low, high) = stats.confidence_interval();
+    ///
+    /// // Dix défaites n’excluent pas un score moyen de 25 %
+    /// assert_eq!(low, 0.0);
+    /// assert!(high > 0.25 && high < 0.3);
+    /// ```
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        let games = self.games() as f64;
+
+        if games == 0.0 {
+            return (0.0, 1.0);
+        }
+
+        let score = self.score();
+        let variance = (self.wins as f64 * (1.0 - score).powi(2)
+            + self.draws as f64 * (0.5 - score).powi(2)
+            + self.losses as f64 * score.powi(2)) / games;
+        let z2 = CONFIDENCE_Z * CONFIDENCE_Z;
+        let denominator = 1.0 + z2 / games;
+        let center = (score + z2 / (2.0 * games)) / denominator;
+        let margin = CONFIDENCE_Z * (variance / games + z2 / (4.0 * games * games)).sqrt() / denominator;
+
+        return (f64::max(center - margin, 0.0), f64::min(center + margin, 1.0));
+    }
+
+    /// Estime l’écart de classement Elo entre le premier et le second joueur
+    ///
+    /// # Retour
+    ///
+    /// L’écart estimé, positif si le premier joueur est le plus fort, ou `None` si l’un des
+    /// joueurs a remporté toutes les parties
+    pub fn elo_difference(&self) -> Option<f64> {
+        let score = self.score();
+
+        if score <= 0.0 || score >= 1.0 {
+            return None;
+        }
+
+        return Some(-400.0 * (1.0 / score - 1.0).log10());
+    }
+}
+
+/// Fait jouer une série de parties aux joueurs d’un gestionnaire de jeux
+///
+/// Le joueur commençant la partie change à chaque partie et reçoit les jetons jaunes. La partie
+/// en cours du gestionnaire est abandonnée au profit de la série, le premier joueur et les
+/// couleurs restant ceux de la dernière partie jouée.
+///
+/// # Arguments
+///
+///  * `engine` : Le gestionnaire de jeux dont les joueurs s’affrontent
+///  * `games` : Le nombre de parties à jouer
+///
+/// # Retour
+///
+/// Les résultats de la série du point de vue du premier joueur ou la première erreur survenue
+/// lors d’une partie (voir [`Engine::play`])
+pub fn play_match(engine : &mut Engine, games : usize) -> Result<MatchStats> {
+    let mut stats = MatchStats::default();
+
+    engine.set_alternate_first_player(false);
+    engine.set_first_player(PlayerId::ONE)?;
+    engine.reset();
+
+    for game in 0..games {
+        let first_player = if game.is_multiple_of(2) { PlayerId::ONE } else { PlayerId::TWO };
+
+        engine.set_first_player(first_player)?;
+        engine.set_colors(if first_player == PlayerId::ONE { State::YellowToken } else { State::RedToken })?;
+
+        stats.add(&engine.play()?);
+
+        engine.reset();
+    }
+
+    return Ok(stats);
+}
+
+/// Match au meilleur de N parties entre deux joueurs
+///
+/// Le joueur commençant la partie change à chaque partie et reçoit les jetons jaunes, le joueur 1
+/// commençant la première. Le match est remporté par le premier joueur gagnant la majorité des
+/// parties. Les matchs nuls comptent comme des parties jouées: si aucun joueur n’a atteint la
+/// majorité une fois les N parties jouées, le joueur ayant gagné le plus de parties remporte le
+/// match, qui est nul en cas d’égalité.
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{GameStatus, PlayerId};
+/// use connect_four::arena::Series;
+///
+/// let mut series = Series::new(3).unwrap();
+///
+/// series.add(GameStatus::Won(PlayerId::ONE));
+/// assert_eq!(series.first_player(), PlayerId::TWO);
+///
+/// series.add(GameStatus::Draw);
+/// assert!(!series.is_over());
+///
+/// series.add(GameStatus::Won(PlayerId::ONE));
+/// assert!(series.is_over());
+/// assert_eq!(series.winner(), Some(PlayerId::ONE));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Series {
+    #[doc(hidden)]
+    best_of : usize,
+    #[doc(hidden)]
+    stats : MatchStats,
+}
+
+impl Series {
+    /// Crée un match au meilleur de `best_of` parties
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - Le nombre de parties est nul ou pair.
+    pub fn new(best_of : usize) -> Result<Self> {
+        if best_of.is_multiple_of(2) {
+            return Err(Error::InvalidConfig);
+        }
+
+        return Ok(Self { best_of, stats: MatchStats::default() });
+    }
+
+    /// Fourni le nombre maximal de parties du match
+    pub fn best_of(&self) -> usize {
+        return self.best_of;
+    }
+
+    /// Fourni les résultats des parties jouées du point de vue du joueur 1
+    pub fn stats(&self) -> MatchStats {
+        return self.stats;
+    }
+
+    /// Comptabilise le résultat d’une partie, ignoré si le match est terminé ou si la partie est
+    /// en cours
+    ///
+    /// # Arguments
+    ///
+    ///  * `status` : L’état final de la partie
+    pub fn add(&mut self, status : GameStatus) {
+        if self.is_over() {
+            return;
+        }
+
+        match status {
+            GameStatus::Won(PlayerId::ONE) | GameStatus::Timeout(PlayerId::TWO) => self.stats.wins += 1,
+            GameStatus::Won(_) | GameStatus::Timeout(_) => self.stats.losses += 1,
+            GameStatus::Draw => self.stats.draws += 1,
+            GameStatus::InProgress | GameStatus::Aborted => {},
+        }
+    }
+
+    /// Fourni l’identifiant du joueur commençant la prochaine partie (1 ou 2)
+    pub fn first_player(&self) -> PlayerId {
+        return if self.stats.games().is_multiple_of(2) { PlayerId::ONE } else { PlayerId::TWO };
+    }
+
+    /// Indique si le match est terminé
+    pub fn is_over(&self) -> bool {
+        let majority = self.best_of / 2 + 1;
+
+        return self.stats.wins >= majority || self.stats.losses >= majority || self.stats.games() >= self.best_of;
+    }
+
+    /// Fourni le vainqueur du match
+    ///
+    /// # Retour
+    ///
+    /// L’identifiant du joueur ayant remporté le match (1 ou 2), ou `None` si le match est en
+    /// cours ou nul
+    pub fn winner(&self) -> Option<PlayerId> {
+        if !self.is_over() {
+            return None;
+        }
+
+        return match self.stats.wins.cmp(&self.stats.losses) {
+            std::cmp::Ordering::Greater => Some(PlayerId::ONE),
+            std::cmp::Ordering::Less => Some(PlayerId::TWO),
+            std::cmp::Ordering::Equal => None,
+        };
+    }
+
+    /// Prépare la prochaine partie du match: la partie en cours du gestionnaire de jeux est
+    /// abandonnée, puis le premier joueur et les couleurs sont choisis
+    ///
+    /// # Arguments
+    ///
+    ///  * `engine` : Le gestionnaire de jeux dont les joueurs s’affrontent
+    pub fn prepare(&self, engine : &mut Engine) -> Result<()> {
+        let first_player = self.first_player();
+
+        engine.set_alternate_first_player(false);
+        engine.reset();
+        engine.set_first_player(first_player)?;
+
+        return engine.set_colors(if first_player == PlayerId::ONE { State::YellowToken } else { State::RedToken });
+    }
+}
+
+/// Fait jouer un match au meilleur de `best_of` parties aux joueurs d’un gestionnaire de jeux
+///
+/// La partie en cours du gestionnaire est abandonnée au profit du match (voir [`Series`]).
+///
+/// # Arguments
+///
+///  * `engine` : Le gestionnaire de jeux dont les joueurs s’affrontent
+///  * `best_of` : Le nombre maximal de parties, impair
+///
+/// # Retour
+///
+/// Le match terminé ou la première erreur survenue lors d’une partie (voir [`Engine::play`])
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::Engine;
+/// use connect_four::arena::play_series;
+/// use connect_four::greedy_bot::GreedyBot;
+/// use connect_four::random_bot::RandomBot;
+///
+/// let mut engine = Engine::new(Box::new(GreedyBot::new("Glouton")), Box::new(RandomBot::new("Aléatoire")));
+/// let series = play_series(&mut engine, 5).unwrap();
+///
+/// assert!(series.is_over());
+/// assert!(series.stats().games() <= 5);
+/// ```
+pub fn play_series(engine : &mut Engine, best_of : usize) -> Result<Series> {
+    let mut series = Series::new(best_of)?;
+
+    while !series.is_over() {
+        series.prepare(engine)?;
+
+        let status = loop {
+            match engine.step()? {
+                GameStatus::InProgress => {},
+                status => break status,
+            }
+        };
+
+        series.add(status);
+    }
+
+    return Ok(series);
+}
@@ -0,0 +1,80 @@
+//! Interface asynchrone entre les joueurs et le jeu
+//!
+//! Permet aux joueurs distants (réseau, interface web…) de jouer sans bloquer le fil d’exécution
+//! pendant la réflexion du joueur. Les parties sont menées via [`Engine::play_async`].
+//!
+//! Ce module n’est disponible qu’avec la fonctionnalité `tokio`.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Engine, GameResult, Area, State, PlayError};
+//! use connect_four::async_interface::{AsyncInterface, async_trait};
+//! use connect_four::random_bot::RandomBot;
+//!
+//! struct FirstColumnBot;
+//!
+//! #[async_trait(?Send)]
+//! impl AsyncInterface for FirstColumnBot {
+//!     async fn play(&mut self, area : &Area, _ : State) -> Result<usize, PlayError> {
+//!         return Ok(area.get_available_columns()[0]);
+//!     }
+//!
+//!     fn name(&self) -> String {
+//!         return String::from("Premier");
+//!     }
+//! }
+//!
+//! let mut game = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+//!
+//! let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+//!
+//! let mut p1 = FirstColumnBot;
+//! let mut p2 = RandomBot::new("Robot aléatoire");
+//!
+//! let result = runtime.block_on(game.play_async(&mut p1, &mut p2)).unwrap();
+//!
+//! assert!(result != GameResult::Aborted);
+//! ```
+//!
+//! [`Engine::play_async`]: ../connect_four/struct.Engine.html#method.play_async
+
+use crate::connect_four::{Interface, Area, State, PlayError};
+
+pub use async_trait::async_trait;
+
+/// Interface asynchrone entre les joueurs et le jeu.
+///
+/// Équivalent asynchrone du trait [`Interface`], ce dernier étant implémenté pour toute interface
+/// synchrone. Les implémentations doivent utiliser l’attribut `#[async_trait(?Send)]`.
+///
+/// [`Interface`]: ../connect_four/trait.Interface.html
+#[async_trait(?Send)]
+pub trait AsyncInterface {
+    /// Demande au joueur de jouer
+    ///
+    /// # Arguments
+    ///
+    ///  * area : La référence vers l’aire de jeu actuel
+    ///  * token : Le jeton joué par le joueur
+    ///
+    /// # Retour
+    ///
+    /// Une valeur correspondant à l’indice où placer le jeton ou une erreur si le joueur ne peut
+    /// pas jouer (la partie est alors interrompue).
+    async fn play(&mut self, area : &Area, token : State) -> Result<usize, PlayError>;
+
+    /// Donne le nom donné au joueur.
+    fn name(&self) -> String;
+}
+
+#[async_trait(?Send)]
+impl<T : Interface> AsyncInterface for T {
+    async fn play(&mut self, area : &Area, token : State) -> Result<usize, PlayError> {
+        return Interface::play(self, area, token);
+    }
+
+    fn name(&self) -> String {
+        return Interface::name(self);
+    }
+}
@@ -0,0 +1,289 @@
+//! Bibliothèque d’ouvertures
+//!
+//! Les premiers coups d’une partie sont les plus coûteux à calculer. La bibliothèque associe aux
+//! positions du début de partie leur meilleur coup et leur score, calculés une fois pour toutes
+//! par le [`Solver`] via [`OpeningBook::build`] puis enregistrés dans un fichier binaire.
+//!
+//! Les positions sont identifiées indépendamment de la couleur des jetons et sous leur forme
+//! canonique (voir [`Area::canonical`]): une position et son symétrique horizontal partagent la
+//! même entrée, ce qui divise par deux la taille de la bibliothèque.
+//!
+//! # Format du fichier
+//!
+//! Les entiers sont stockés en petit-boutiste:
+//!  * La signature `C4BK` suivie de la version du format (1 octet)
+//!  * Le nombre de colonnes, de lignes et de jetons à aligner (1 octet chacun), le bit de poids
+//!    fort du dernier indiquant un plateau cylindrique
+//!  * Le nombre d’entrées (4 octets)
+//!  * Pour chaque entrée, la clé de la position (8 octets), la colonne à jouer (1 octet) et le
+//!    score de la position (1 octet signé)
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use super::connect_four::{Area, AreaConfig, Error, State, Result};
+use super::solver::Solver;
+
+/// Signature des fichiers de bibliothèque d’ouvertures
+const MAGIC : &[u8; 4] = b"C4BK";
+
+/// Version du format des fichiers
+const VERSION : u8 = 1;
+
+/// Coup enregistré pour une position
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct BookEntry {
+    /// Colonne à jouer
+    pub column : usize,
+    /// Score de la position du point de vue du joueur devant jouer (voir le module
+    /// [`solver`](crate::solver))
+    pub score : i32,
+}
+
+/// Bibliothèque d’ouvertures
+pub struct OpeningBook {
+    #[doc(hidden)]
+    config : AreaConfig,
+    #[doc(hidden)]
+    entries : HashMap<u64, BookEntry>,
+}
+
+impl OpeningBook {
+    /// Crée une bibliothèque vide pour la configuration donnée
+    pub fn new(config : AreaConfig) -> Self {
+        Self {
+            config,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Construit la bibliothèque des positions comptant moins de `plies` jetons
+    ///
+    /// Toutes les positions atteignables sont résolues, la durée de construction croissant donc
+    /// très vite avec `plies`. Sur le plateau classique, la résolution de la position initiale
+    /// peut à elle seule prendre plusieurs heures.
+    ///
+    /// # Arguments
+    ///
+    ///  * `config` : La configuration de la zone de jeu
+    ///  * `plies` : Le nombre de coups couverts par la bibliothèque
+    ///  * `solver` : Le solveur utilisé afin de calculer les meilleurs coups
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - La configuration de la zone de jeu est invalide ou contient des cases
+    ///    bloquées, que le format des fichiers ne décrit pas.
+    pub fn build(config : AreaConfig, plies : usize, solver : &mut Solver) -> Result<Self> {
+        if config.blocked != 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut book = Self::new(config);
+        let mut area = Area::with_config(config)?;
+
+        book.explore(&mut area, State::YellowToken, plies, solver)?;
+
+        return Ok(book);
+    }
+
+    /// Lit une bibliothèque depuis un fichier
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, `InvalidData` si le fichier n’est pas une bibliothèque
+    /// d’ouvertures valide.
+    pub fn load<P : AsRef<Path>>(path : P) -> io::Result<Self> {
+        return Self::read(&mut io::BufReader::new(std::fs::File::open(path)?));
+    }
+
+    /// Enregistre la bibliothèque dans un fichier
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie
+    pub fn save<P : AsRef<Path>>(&self, path : P) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(std::fs::File::create(path)?);
+
+        self.write(&mut writer)?;
+
+        return writer.flush();
+    }
+
+    /// Lit une bibliothèque
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, `InvalidData` si les données ne sont pas une bibliothèque
+    /// d’ouvertures valide.
+    pub fn read<R : Read>(reader : &mut R) -> io::Result<Self> {
+        let invalid = |msg : &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+        let mut header = [0u8; 12];
+
+        reader.read_exact(&mut header)?;
+
+        if &header[0..4] != MAGIC || header[4] != VERSION {
+            return Err(invalid("Le fichier n’est pas une bibliothèque d’ouvertures"));
+        }
+
+        let mut config = AreaConfig::new(header[5] as usize, header[6] as usize, (header[7] & 0x7f) as usize)
+            .map_err(|e| invalid(&e.to_string()))?;
+
+        config.wrap_columns = header[7] & 0x80 != 0;
+        config.validate().map_err(|e| invalid(&e.to_string()))?;
+
+        let count = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+        let mut book = Self::new(config);
+        let mut entry = [0u8; 10];
+
+        for _ in 0..count {
+            reader.read_exact(&mut entry)?;
+
+            let mut key = [0u8; 8];
+
+            key.copy_from_slice(&entry[0..8]);
+
+            if entry[8] as usize >= config.cols {
+                return Err(invalid("Colonne invalide dans la bibliothèque d’ouvertures"));
+            }
+
+            book.entries.insert(u64::from_le_bytes(key), BookEntry {
+                column: entry[8] as usize,
+                score: entry[9] as i8 as i32,
+            });
+        }
+
+        return Ok(book);
+    }
+
+    /// Écrit la bibliothèque
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, `InvalidInput` si la zone de jeu contient des cases bloquées
+    pub fn write<W : Write>(&self, writer : &mut W) -> io::Result<()> {
+        if self.config.blocked != 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Les cases bloquées ne peuvent pas être enregistrées"));
+        }
+
+        writer.write_all(MAGIC)?;
+        // Le bit de poids fort du nombre de jetons à aligner indique un plateau cylindrique
+        let win_length = self.config.win_length as u8 | (self.config.wrap_columns as u8) << 7;
+
+        writer.write_all(&[VERSION, self.config.cols as u8, self.config.rows as u8, win_length])?;
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+
+        for (key, entry) in self.entries.iter() {
+            writer.write_all(&key.to_le_bytes())?;
+            writer.write_all(&[entry.column as u8, entry.score as i8 as u8])?;
+        }
+
+        return Ok(());
+    }
+
+    /// Fourni la configuration des positions de la bibliothèque
+    pub fn config(&self) -> &AreaConfig {
+        return &self.config;
+    }
+
+    /// Fourni le nombre de positions de la bibliothèque
+    pub fn len(&self) -> usize {
+        return self.entries.len();
+    }
+
+    /// Vérifie si la bibliothèque est vide
+    pub fn is_empty(&self) -> bool {
+        return self.entries.is_empty();
+    }
+
+    /// Recherche le coup à jouer dans une position
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///
+    /// # Retour
+    ///
+    /// Le coup enregistré pour la position ou `None` si la position n’est pas dans la
+    /// bibliothèque ou si sa configuration diffère de celle de la bibliothèque
+    pub fn get(&self, area : &Area, token : State) -> Option<BookEntry> {
+        if *area.config() != self.config {
+            return None;
+        }
+
+        let (key, mirrored) = self.key(area, token);
+
+        return self.entries.get(&key).map(|entry| BookEntry {
+            column: if mirrored { self.config.cols - 1 - entry.column } else { entry.column },
+            score: entry.score,
+        });
+    }
+
+    /// Enregistre le coup à jouer dans une position
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position, dont la configuration doit être celle de la bibliothèque
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///  * `entry` : Le coup à jouer ainsi que le score de la position
+    pub fn insert(&mut self, area : &Area, token : State, entry : BookEntry) {
+        let (key, mirrored) = self.key(area, token);
+
+        self.entries.insert(key, BookEntry {
+            column: if mirrored { self.config.cols - 1 - entry.column } else { entry.column },
+            score: entry.score,
+        });
+    }
+
+    /// Résout la position puis ses successeurs jusqu’à `plies` jetons
+    fn explore(&mut self, area : &mut Area, token : State, plies : usize, solver : &mut Solver) -> Result<()> {
+        if area.token_count() >= plies || self.get(area, token).is_some() {
+            return Ok(());
+        }
+
+        let (column, score) = solver.best_move(area, token)?;
+
+        self.insert(area, token, BookEntry { column, score });
+
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+
+        for col in area.get_available_columns() {
+            if !area.set_token(token, col)? && !area.get_available_columns().is_empty() {
+                self.explore(area, opponent, plies, solver)?;
+            }
+
+            area.unset_token(col)?;
+        }
+
+        return Ok(());
+    }
+
+    /// Calcule la clé canonique d’une position, indépendante de la couleur des jetons, et indique
+    /// si elle correspond au symétrique horizontal de la position
+    fn key(&self, area : &Area, token : State) -> (u64, bool) {
+        let height = self.config.rows + 1;
+        let cols = self.config.cols;
+        let (mut key, mut mirror) = ((0u64, 0u64), (0u64, 0u64));
+
+        for ((col, row), state) in area.iter() {
+            let bit = 1u64 << (col * height + row);
+            let mirror_bit = 1u64 << ((cols - 1 - col) * height + row);
+
+            if state != State::NoToken {
+                key.1 |= bit;
+                mirror.1 |= mirror_bit;
+            }
+
+            if state == token {
+                key.0 |= bit;
+                mirror.0 |= mirror_bit;
+            }
+        }
+
+        let key = key.0.wrapping_add(key.1);
+        let mirror = mirror.0.wrapping_add(mirror.1);
+
+        return if mirror < key { (mirror, true) } else { (key, false) };
+    }
+}
@@ -6,25 +6,23 @@
 //!
 //! Ce dernier fournit trois éléments conçu pour cela:
 //!  * L’objet [`Engine`] permettant de gérer une partie de puissance 4 ainsi que les interactions entre les joueurs et le jeu.
-//!  * L’objet [`Area`] correspondant à l’aire de jeu (ici le plateau virtuel de puissance 4)
+//!  * L’objet [`Area`] correspondant à l’aire de jeu (ici le plateau virtuel de puissance 4),
+//!    dont les dimensions sont définies par [`AreaConfig`]
 //!  * Le trait [`Interface`] permettant d’implémenter les fonctions essentielles entre l’interface utilisateur et le jeu.
 //!
 //! # Exemple
 //!
-//! Voici un exemple faisant jouer deux intelligences artificielles de manière aléatoire:
+//! Voici un exemple faisant jouer deux intelligences artificielles choisissant toujours la première
+//! colonne disponible:
 //!
 //! ```rust
-//! extern crate getrandom;
+//! use connect_four::{Engine, GameResult, Interface, Area, State, PlayError};
 //!
-//! mod connect_four;
-//!
-//! use super::connect_four::{Interface,Area};
-//!
-//! pub struct RandomBot {
+//! pub struct FirstColumnBot {
 //!     name : String
 //! }
 //!
-//! impl RandomBot {
+//! impl FirstColumnBot {
 //!     pub fn new(name : &str) -> Self {
 //!         Self {
 //!             name: String::from(name)
@@ -32,18 +30,9 @@
 //!     }
 //! }
 //!
-//! impl Interface for RandomBot {
-//!     fn play(&self, area: &Area) -> usize {
-//!         let available = area.get_available_columns();
-//!         const SZ : usize = std::mem::size_of::<usize>();
-//!
-//!         let mut idx_a : [u8; SZ] = [0; SZ];
-//!
-//!         getrandom::getrandom(&mut idx_a).unwrap();
-//!
-//!         let idx : usize = unsafe { std::mem::transmute::<[u8; SZ], usize>(idx_a) } % available.len();
-//!
-//!         return available[idx];
+//! impl Interface for FirstColumnBot {
+//!     fn play(&mut self, area: &Area, _ : State) -> Result<usize, PlayError> {
+//!         return Ok(area.get_available_columns()[0]);
 //!     }
 //!
 //!     fn name(&self) -> String {
@@ -51,50 +40,65 @@
 //!     }
 //! }
 //!
-//! fn main() {
-//!     let p1 = RandomBot::new("Joueur 1");
-//!     let p2 = RandomBot::new("Joueur 2");
+//! let p1 = Box::new(FirstColumnBot::new("Joueur 1"));
+//! let p2 = Box::new(FirstColumnBot::new("Joueur 2"));
 //!
-//!     let mut game = p4e::Engine::new(&p1, &p2);
+//! let mut game = Engine::new(p1, p2);
 //!
-//!     if let Ok(v) = game.play() {
-//!         println!("{}", game.get_disposition());
+//! if let Ok(v) = game.play() {
+//!     println!("{}", game.get_disposition());
 //!
-//!         if let Some(p) = v {
-//!             println!("{} a gagné", p);
-//!         } else {
-//!             println!("Match nul");
-//!         }
+//!     match v {
+//!         GameResult::Winner { player_id, .. } => println!("{} a gagné", game.player_name(player_id).unwrap()),
+//!         GameResult::Draw => println!("Match nul"),
+//!         _ => println!("Partie interrompue"),
 //!     }
 //! }
 //! ```
 //!
 //! [`Engine`]: struct.Engine.html
 //! [`Area`]: struct.Area.html
+//! [`AreaConfig`]: struct.AreaConfig.html
 //! [`Interface`]: trait.Interface.html
 
 use std::fmt::{Debug, Formatter, Display};
-use std::cell::RefCell;
-
+use std::time::{Duration, Instant};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use crate::render::{Renderer, RenderOptions, TextRenderer};
+use crate::{i18n, tr};
+#[cfg(feature = "tokio")]
+use crate::async_interface::AsyncInterface;
 /// Nombre de lignes sur le plateau.
 ///
 /// Nombre de jetons pouvant être verticalement alignés.
 ///
-/// Défini directement la zone de jeu et vaut 6 par default.
+/// Valeur par défaut de [`AreaConfig::rows`], vaut 6.
 pub const AREA_ROWS : usize = 6;
 /// Nombre de colonnes sur le plateau.
 ///
 /// Nombre de jetons pouvant être horizontalement alignés.
 ///
-/// Défini directement la zone de jeu et vaut 7 par default.
+/// Valeur par défaut de [`AreaConfig::cols`], vaut 7.
 pub const AREA_COLS : usize = 7;
 
 /// Nombre de jetons à aligner afin d’enclencher la victoire.
 ///
 /// Ce dernier correspond au nombre de jetons à aligner horizontalement, verticalement ou en
 /// diagonale et permettant à l’un des deux joueurs de gagner.
+///
+/// Valeur par défaut de [`AreaConfig::win_length`], vaut 4.
 pub const VICTORY_NUMBER : usize = 4;
 
+/// Nombre maximal de joueurs d’une partie, chacun disposant d’une couleur de [`State::TOKENS`]
+pub const MAX_PLAYERS : usize = 4;
+
+/// Clés de Zobrist de chaque bit des bitboards, pour les jetons rouges, jaunes, verts puis bleus
+const ZOBRIST_KEYS : [[u64; 64]; 4] = zobrist_keys();
+
+/// Alphabet de l’encodage base64 sûr pour les URL (RFC 4648, section 5) des codes de position
+const BASE64URL_ALPHABET : &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
 /// Un type [`Result`] spécialisé aux opérations du moteur de jeu.
 ///
 /// Ce type est utilisé dans tout le module [`connect_four`] pour toutes les opération pouvant
@@ -108,26 +112,139 @@ pub const VICTORY_NUMBER : usize = 4;
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// État d’une case dans la zone de jeu.
-#[derive(Eq, PartialEq, Copy, Clone)]
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::enum_variant_names)]
 pub enum State {
     /// La case ne contient pas de jeton. Cette valeur est interne à l’objet Area
+    #[default]
     NoToken,
     /// La case contient un jeton rouge
     RedToken,
     /// La case contient un jeton jaune
     YellowToken,
+    /// La case contient un jeton vert, utilisé par le troisième joueur
+    GreenToken,
+    /// La case contient un jeton bleu, utilisé par le quatrième joueur
+    BlueToken,
 }
 
 /// Liste des erreurs pouvant être émises par les objet du module
 pub enum Error {
-    /// L’identifiant de la colonne est invalide. Cette dernière doit être comprise entre 0 et `AREA_COLS-1`.
+    /// L’identifiant de la colonne est invalide. Cette dernière doit être comprise entre 0 et le
+    /// nombre de colonnes moins un.
     InvalidColumn,
     /// L’état demandé ne correspond pas à celui d’un jeton.
     NotAToken,
     /// La colonne dont il est demandé une modification est déjà remplie.
     FilledColumn,
-    /// L’identifiant du joueur est invalide (doit valoir 0 ou 1)
+    /// L’identifiant du joueur est invalide (doit être compris entre 1 et le nombre de joueurs)
     InvalidPlayerId(usize),
+    /// Le nombre de joueurs donné n’est pas supporté, soit parce qu’il n’est pas compris entre 2
+    /// et 4, soit parce que les règles demandées ne se jouent qu’à deux joueurs.
+    InvalidPlayerCount(usize),
+    /// La configuration de la zone de jeu est invalide (dimensions nulles, nombre de jetons à
+    /// aligner nul ou zone de jeu trop grande).
+    InvalidConfig,
+    /// La partie est déjà terminée, aucun coup ne peut plus être joué.
+    GameAlreadyFinished,
+    /// La partie est déjà commencée, l’opération n’est possible qu’avant le premier coup.
+    GameAlreadyStarted,
+    /// L’interface d’un joueur n’a pas pu fournir de coup.
+    InterfaceFailure(PlayError),
+    /// La position décrite est impossible (jeton flottant ou nombre de jetons incohérent).
+    InvalidPosition,
+    /// L’identifiant de la ligne est invalide. Cette dernière doit être comprise entre 0 et le
+    /// nombre de lignes moins un.
+    InvalidRow,
+    /// La colonne dont il est demandé de retirer un jeton est vide.
+    EmptyColumn,
+    /// La partie est terminée, le joueur donné ayant dépassé son temps de réflexion.
+    Timeout(PlayerId),
+    /// La partie a été interrompue avant son terme.
+    Aborted,
+    /// Aucun coup n’a été joué, il n’y a rien à annuler.
+    NothingToUndo,
+    /// Retirer un jeton n’est possible que dans la variante Pop Out (voir [`Variant::PopOut`]).
+    PopNotAllowed,
+    /// Le jeton situé en bas de la colonne n’appartient pas au joueur souhaitant le retirer.
+    NotOwnToken,
+    /// L’échange des camps n’est possible qu’avec la règle du gâteau, en réponse à un premier coup
+    /// ajoutant un jeton, dans une partie sans handicap (voir [`Engine::set_swap_rule`]).
+    SwapNotAllowed,
+    /// Aucune position de départ assez équilibrée n’a été trouvée (voir
+    /// [`Scramble`](crate::scramble::Scramble)).
+    NoBalancedPosition,
+    /// La partie rejouée ne correspond pas à l’enregistrement (voir [`GameRecord::verify`]).
+    RecordMismatch,
+    /// Aucune partie ne porte l’identifiant donné, sous la forme de sa valeur numérique (voir
+    /// [`GameServer`](crate::server::GameServer)).
+    UnknownGame(u64),
+}
+
+/// Liste des erreurs pouvant être émises par une [`Interface`] lorsqu’elle ne peut pas jouer
+pub enum PlayError {
+    /// Une erreur d’entrée/sortie est survenue lors de la communication avec le joueur.
+    Io(std::io::Error),
+    /// Le joueur s’est déconnecté de la partie.
+    Disconnected,
+    /// Toute autre erreur empêchant le joueur de jouer, décrite par le message donné.
+    Other(String),
+}
+
+/// Configuration de la zone de jeu.
+///
+/// Défini les dimensions du plateau ainsi que le nombre de jetons à aligner pour gagner. La
+/// configuration par défaut correspond au puissance 4 classique (7 colonnes, 6 lignes et 4 jetons
+/// à aligner).
+///
+/// La zone de jeu étant stockée sur des entiers de 64 bits, le produit du nombre de colonnes par le
+/// nombre de lignes plus un ne peut pas dépasser 64.
+///
+/// Le plateau peut également être cylindrique (voir [`AreaConfig::wrap_columns`]): la première et
+/// la dernière colonne sont alors voisines et les alignements horizontaux et diagonaux peuvent
+/// passer d’un bord à l’autre.
+///
+/// Certaines cases peuvent enfin être bloquées (voir [`AreaConfig::block`]), par exemple pour
+/// proposer des problèmes: aucun jeton ne peut y être placé et elles interrompent les
+/// alignements.
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, AreaConfig, State};
+///
+/// let config = AreaConfig { wrap_columns: true, ..AreaConfig::default() };
+/// let mut area = Area::with_config(config).unwrap();
+///
+/// for col in [5, 6, 0].iter() {
+///     area.set_token(State::YellowToken, *col).unwrap();
+/// }
+///
+/// // Les colonnes 5, 6, 0 et 1 forment un alignement sur un plateau cylindrique
+/// assert!(area.is_winning_move(State::YellowToken, 1).unwrap());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AreaConfig {
+    /// Nombre de lignes sur le plateau.
+    pub rows : usize,
+    /// Nombre de colonnes sur le plateau.
+    pub cols : usize,
+    /// Nombre de jetons à aligner afin d’enclencher la victoire.
+    pub win_length : usize,
+    /// Relie la dernière colonne à la première (plateau cylindrique). Le nombre de jetons à
+    /// aligner ne peut alors pas dépasser le nombre de colonnes.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "std::ops::Not::not"))]
+    pub wrap_columns : bool,
+    /// Cases bloquées, le bit `col * (rows + 1) + row` correspondant à la case `(col, row)` comme
+    /// dans les bitboards de la zone de jeu. Les jetons passent à côté de ces cases en tombant.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub blocked : u64,
+    /// Les jetons reposent sur les cases bloquées au lieu de passer à côté: les cases situées
+    /// sous une case bloquée sont alors également bloquées.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "std::ops::Not::not"))]
+    pub rest_on_blocked : bool,
 }
 
 /// Gère les parties de puissance 4.
@@ -136,304 +253,4573 @@ pub enum Error {
 ///  * Générer une zone de jeu,
 ///  * Réinitialiser cette dernière,
 ///  * Permettre les interaction entre les joueur ou les intelligences artificielles via une unique
-/// interface.
-pub struct Engine<'a> {
+///    interface.
+pub struct Engine {
     #[doc(hidden)]
     area : Area,
+    /// Interfaces des joueurs, le joueur `player_id` utilisant l’interface `player_id - 1`
+    #[doc(hidden)]
+    interfaces : Vec<SharedInterface>,
+    /// Noms des joueurs, relevés lors de l’enregistrement de leur interface afin de ne pas
+    /// attendre une interface encore occupée par un coup hors délai
+    #[doc(hidden)]
+    names : Vec<String>,
+    #[doc(hidden)]
+    current_player : PlayerId,
+    #[doc(hidden)]
+    status : GameStatus,
+    #[doc(hidden)]
+    retry_policy : RetryPolicy,
+    #[doc(hidden)]
+    history : Vec<Move>,
+    #[doc(hidden)]
+    observers : Vec<Box<dyn Observer + Send>>,
     #[doc(hidden)]
-    player_one_interface : &'a RefCell<dyn Interface>,
+    started : bool,
     #[doc(hidden)]
-    player_two_interface : &'a RefCell<dyn Interface>,
+    time_control : Option<TimeControl>,
+    #[doc(hidden)]
+    clocks : Vec<Duration>,
+    #[doc(hidden)]
+    turn_started : Option<Instant>,
+    #[doc(hidden)]
+    move_timeout : Option<MoveTimeout>,
+    #[doc(hidden)]
+    first_player : PlayerId,
+    #[doc(hidden)]
+    alternate_first_player : bool,
+    #[doc(hidden)]
+    player_one_token : State,
+    #[doc(hidden)]
+    variant : Variant,
+    #[doc(hidden)]
+    objective : Objective,
+    #[doc(hidden)]
+    handicap : Option<Handicap>,
+    #[doc(hidden)]
+    swap_rule : bool,
 }
 
-/// Zone de jeu
+/// Handicap accordé au joueur le plus faible: des jetons placés avant le premier coup
 ///
-/// Ce dernier possède les fonctionnalités suivantes:
-///  * Gérer l’ajout des jetons,
-///  * Vérifier l’alignment de quatre jetons identiques.
-#[derive(Clone)]
-pub struct Area {
-    #[doc(hidden)]
-    area : [[State; AREA_ROWS]; AREA_COLS]
+/// Voir [`Engine::set_handicap`].
+#[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Handicap {
+    /// Joueur recevant les jetons
+    pub player_id : PlayerId,
+    /// Colonnes où les jetons sont placés, dans l’ordre
+    pub columns : Vec<usize>,
 }
 
-/// Interface entre les joueurs et le jeu.
+/// Paramètres d’un gestionnaire de jeux
 ///
-/// Met en place les fonctions permettant le bon déroulement du jeu.
-pub trait Interface {
-    /// Demande au joueur de jouer
-    ///
-    /// # Arguments
-    ///
-    ///  * area : La référence vers l’aire de jeu actuel. Ce dernier peut être cloné pour tester
-    /// des coups où être affiché par le joueur
-    ///  * token : Le jeton joué par le joueur
-    ///
-    /// # Retour
-    ///
-    /// Une valeur correspondant à l’indice où placer le jeton. Ce dernier doit être compris entre
-    /// 0 et `AREA_COLS-1`
-    fn play(&mut self, area : &Area, token : State) -> usize;
-
-    /// Donne le nom donné au joueur.
-    ///
-    /// # Retour
-    ///
-    /// Le nom du joueur sous la forme de chaine de caractères.
-    fn name(&self) -> String;
+/// Regroupe l’ensemble des options d’un [`Engine`] afin de pouvoir les sauvegarder puis les
+/// appliquer à nouveau via [`Engine::settings`] et [`Engine::apply_settings`].
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EngineSettings {
+    /// Configuration de la zone de jeu
+    pub area : AreaConfig,
+    /// Comportement en cas de coup illégal
+    pub retry_policy : RetryPolicy,
+    /// Contrôle du temps des parties
+    pub time_control : Option<TimeControl>,
+    /// Limite de temps imposée aux interfaces pour chaque coup
+    pub move_timeout : Option<MoveTimeout>,
+    /// Joueur jouant le premier coup (compris entre 1 et le nombre de joueurs)
+    pub first_player : PlayerId,
+    /// Alternance du premier joueur entre les parties
+    pub alternate_first_player : bool,
+    /// Jeton du premier joueur
+    pub player_one_token : State,
+    /// Règles de la partie
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub variant : Variant,
+    /// Objectif des joueurs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub objective : Objective,
+    /// Règle du gâteau (voir [`Engine::set_swap_rule`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub swap_rule : bool,
 }
 
-impl Default for State {
-    fn default() -> Self {
-        State::NoToken
-    }
+/// Enregistrement d’une partie
+///
+/// Contient le nécessaire pour rejouer une partie depuis son début via
+/// [`Engine::load_record`], notamment afin de la sauvegarder puis de la reprendre plus tard.
+#[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    /// Configuration de la zone de jeu
+    pub config : AreaConfig,
+    /// Joueur ayant joué le premier coup (compris entre 1 et `players`)
+    pub first_player : PlayerId,
+    /// Jeton du premier joueur
+    pub player_one_token : State,
+    /// Colonnes jouées, du premier au dernier coup
+    pub moves : Vec<usize>,
+    /// Règles de la partie
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Variant::is_standard"))]
+    pub variant : Variant,
+    /// Indices, dans `moves`, des coups ayant retiré un jeton au lieu d’en ajouter un (variante
+    /// Pop Out)
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub pops : Vec<usize>,
+    /// Objectif des joueurs
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Objective::is_connect"))]
+    pub objective : Objective,
+    /// Nombre de joueurs de la partie (de 2 à 4)
+    #[cfg_attr(feature = "serde", serde(default = "two_players", skip_serializing_if = "is_two_players"))]
+    pub players : usize,
+    /// Jetons placés avant le premier coup
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub handicap : Option<Handicap>,
+    /// Règle du gâteau (voir [`Engine::set_swap_rule`])
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "std::ops::Not::not"))]
+    pub swap_rule : bool,
+    /// Indices, dans `moves`, des coups ayant échangé les camps au lieu de jouer. La colonne notée
+    /// pour un échange est celle du premier coup.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub swaps : Vec<usize>,
+    /// Temps de réflexion de chaque coup, dans l’ordre de `moves`. Les coups sans temps
+    /// enregistré, notamment ceux d’une partie lue depuis une notation, comptent pour une durée
+    /// nulle.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub think_times : Vec<Duration>,
+    /// Nombre de positions explorées pour trouver chaque coup, dans l’ordre de `moves`, vide si
+    /// aucune interface ne les a comptabilisées
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub nodes : Vec<Option<u64>>,
 }
 
-impl Display for Area {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let sep = String::from_utf8(vec![0x2D;5*AREA_COLS+1]).unwrap();
-
-        for col in 0..AREA_COLS {
-            if let Err(e) = f.write_fmt(format_args!("|{: ^4}", col)) {
-                return Err(e);
-            }
-        }
+/// Interface partagée avec les fils d’exécution chargés de faire jouer les joueurs
+type SharedInterface = Arc<Mutex<Box<dyn Interface + Send>>>;
 
-        if let Err(e) = f.write_fmt(format_args!("|\n{}\n", sep)) {
-            return Err(e);
-        }
+/// Limite de temps imposée à chaque appel de [`Interface::play`]
+///
+/// Lorsqu’une limite est définie, l’interface est appelée depuis un fil d’exécution dédié. Si
+/// elle ne répond pas à temps, le moteur applique l’action donnée sans attendre sa réponse. Une
+/// interface en retard n’est de nouveau sollicitée qu’une fois son calcul précédent terminé, ce
+/// temps étant décompté de son coup suivant.
+///
+/// Le moteur n’attend jamais une interface en retard: son nom est relevé lors de son
+/// enregistrement et les notifications qui lui sont destinées (début de partie, réflexion et coups
+/// de ses adversaires, proposition d’échange, interruption) sont ignorées tant que son calcul
+/// n’est pas terminé.
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, Engine, Interface, MoveTimeout, PlayError, PlayerId, State, TimeoutAction};
+/// use connect_four::random_bot::RandomBot;
+/// use std::time::{Duration, Instant};
+///
+/// struct SlowBot;
+///
+/// impl Interface for SlowBot {
+///     fn play(&mut self, area : &Area, _ : State) -> Result<usize, PlayError> {
+///         std::thread::sleep(Duration::from_secs(1));
+///
+///         return Ok(area.get_available_columns()[0]);
+///     }
+///
+///     fn name(&self) -> String {
+///         return String::from("Robot lent");
+///     }
+/// }
+///
+/// let limit = Duration::from_millis(50);
+/// let mut engine = Engine::new(Box::new(SlowBot), Box::new(RandomBot::new("Robot")));
+///
+/// engine.set_move_timeout(Some(MoveTimeout { limit, action: TimeoutAction::Fallback }));
+/// engine.step().unwrap();
+///
+/// // Le robot lent réfléchit encore à son premier coup: le moteur ne l’attend pas
+/// let started = Instant::now();
+///
+/// engine.step().unwrap();
+/// engine.step().unwrap();
+///
+/// assert_eq!(engine.player_name(PlayerId::ONE).unwrap(), "Robot lent");
+/// assert!(started.elapsed() < 5 * limit);
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveTimeout {
+    /// Temps maximal accordé à l’interface pour chaque coup
+    pub limit : Duration,
+    /// Action effectuée lorsque l’interface dépasse la limite
+    pub action : TimeoutAction,
+}
 
-        for row in 0..AREA_ROWS {
+/// Action effectuée lorsqu’une interface dépasse la limite de temps d’un coup
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeoutAction {
+    /// Le joueur perd la partie (voir [`GameStatus::Timeout`])
+    Forfeit,
+    /// Le moteur joue à la place du joueur dans la colonne disponible la plus proche du centre
+    Fallback,
+}
 
-            for col in 0..AREA_COLS {
-                if let Err(e) = f.write_fmt(format_args!("|{}", match self.area[col][AREA_ROWS - 1 - row] {
-                    State::NoToken => "    ",
-                    State::RedToken => " 🔴 ",
-                    State::YellowToken => " 🟡 ",
-                })) {
-                    return Err(e);
-                }
-            }
+/// Contrôle du temps de réflexion des joueurs, à la manière des pendules d’échecs
+///
+/// Chaque joueur dispose d’un temps initial, décompté pendant qu’il réfléchit. Une fois son coup
+/// joué, l’incrément est ajouté à son temps restant. Un joueur dont le temps est écoulé perd la
+/// partie.
+///
+/// Lorsque les coups sont demandés par le moteur ([`Engine::step`], [`Engine::play`]), le temps
+/// restant borne la réflexion du joueur comme une limite de temps par coup (voir
+/// [`MoveTimeout`]): la partie se termine dès que la pendule est écoulée, sans attendre son coup.
+/// Lorsque les coups sont donnés via [`Engine::apply_move`], le dépassement n’est constaté qu’à la
+/// réception du coup: l’appelant doit surveiller [`Engine::remaining_time`] pour réagir à temps.
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, Engine, GameStatus, Interface, PlayError, PlayerId, State, TimeControl};
+/// use connect_four::random_bot::RandomBot;
+/// use std::time::{Duration, Instant};
+///
+/// struct SlowBot;
+///
+/// impl Interface for SlowBot {
+///     fn play(&mut self, area : &Area, _ : State) -> Result<usize, PlayError> {
+///         std::thread::sleep(Duration::from_secs(1));
+///
+///         return Ok(area.get_available_columns()[0]);
+///     }
+///
+///     fn name(&self) -> String {
+///         return String::from("Robot lent");
+///     }
+/// }
+///
+/// let initial = Duration::from_millis(50);
+/// let mut engine = Engine::new(Box::new(SlowBot), Box::new(RandomBot::new("Robot")));
+///
+/// engine.set_time_control(Some(TimeControl { initial, increment: Duration::default() }));
+///
+/// let started = Instant::now();
+///
+/// assert_eq!(engine.step().unwrap(), GameStatus::Timeout(PlayerId::ONE));
+/// assert_eq!(engine.remaining_time(PlayerId::ONE).unwrap(), Some(Duration::default()));
+/// assert!(started.elapsed() < 5 * initial);
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeControl {
+    /// Temps dont dispose chaque joueur en début de partie
+    pub initial : Duration,
+    /// Temps ajouté au joueur après chacun de ses coups
+    pub increment : Duration,
+}
 
-            if let Err(e) = f.write_str("|\n") {
-                return Err(e);
-            }
-        }
+/// Identifiant d’un joueur, compris entre 1 et [`MAX_PLAYERS`]
+///
+/// Le joueur 1 joue le jeton choisi via [`Engine::set_colors`], le joueur 2 l’autre jeton parmi
+/// les jaunes et les rouges, les joueurs 3 et 4 les jetons verts puis bleus. Un identifiant ne
+/// peut être créé que s’il est valide, le gestionnaire de jeux vérifiant en outre qu’il ne dépasse
+/// pas le nombre de joueurs de la partie.
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::PlayerId;
+///
+/// assert_eq!(PlayerId::new(2).unwrap(), PlayerId::TWO);
+/// assert_eq!(PlayerId::TWO.get(), 2);
+/// assert!(PlayerId::new(0).is_err());
+/// assert!(PlayerId::new(5).is_err());
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "usize", into = "usize"))]
+pub struct PlayerId(#[doc(hidden)] usize);
 
-        if let Err(e) = f.write_str(&sep) {
-            return Err(e);
-        }
+/// Joueur d’une partie, transmis à son interface au début de la partie (voir
+/// [`Interface::on_game_start`])
+#[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    /// L’identifiant du joueur
+    pub id : PlayerId,
+    /// Le nom du joueur, fourni par son interface
+    pub name : String,
+    /// Le jeton joué par le joueur
+    pub token : State,
+}
 
-        return Ok(());
-    }
+/// Coup joué durant une partie
+///
+/// Les coups sont enregistrés dans l’ordre par le moteur et accessibles via [`Engine::history`].
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Move {
+    /// L’identifiant du joueur ayant joué le coup (compris entre 1 et le nombre de joueurs)
+    pub player : PlayerId,
+    /// La colonne où le jeton a été placé
+    pub column : usize,
+    /// La ligne où le jeton est tombé, en partant du bas. Un jeton retiré l’est toujours de la
+    /// ligne du bas.
+    pub row : usize,
+    /// Le jeton placé ou retiré
+    pub token : State,
+    /// La nature du coup, un ajout sauf dans la variante Pop Out
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind : MoveKind,
+    /// Le temps écoulé entre le début du tour et le coup
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub think_time : Duration,
+    /// Le nombre de positions explorées par l’interface pour trouver le coup, si elle les
+    /// comptabilise (voir [`Interface::nodes_searched`])
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub nodes : Option<u64>,
 }
 
-impl Debug for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Error::FilledColumn => f.write_str("La colonne choisie est déjà pleine"),
-            Error::InvalidColumn => f.write_str("La colonne choisie est invalide"),
-            Error::NotAToken => f.write_str("L’élément fourni n’est pas un jeton"),
-            Error::InvalidPlayerId(id) => f.write_str(format!("Le joueur {} n’existe pas. Seul 1 et 2 sont acceptés", id).as_str())
-        }
-    }
+/// Nature d’un coup
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoveKind {
+    /// Un jeton est ajouté au sommet de la colonne
+    #[default]
+    Drop,
+    /// Le jeton du bas de la colonne est retiré, les jetons situés au-dessus descendant d’une
+    /// case (variante Pop Out)
+    Pop,
+    /// Le second joueur prend le camp du premier après son premier coup (règle du gâteau). Le
+    /// coup enregistré reprend la case et le jeton de ce premier coup.
+    Swap,
 }
 
-impl std::ops::Index<(usize, usize)> for Area {
-    type Output = State;
+/// Coup proposé par un joueur via [`Interface::play_action`]
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// Ajoute un jeton dans la colonne donnée
+    Drop(usize),
+    /// Retire le jeton du bas de la colonne donnée (variante Pop Out)
+    Pop(usize),
+    /// Échange les camps après le premier coup (règle du gâteau, voir [`Engine::set_swap_rule`])
+    Swap,
+}
 
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        return self.area.index(index.0).index(AREA_ROWS - 1 - index.1);
+impl Action {
+    /// Fourni la colonne concernée par le coup, `None` pour un échange des camps
+    pub fn column(&self) -> Option<usize> {
+        return match self {
+            Action::Drop(column) | Action::Pop(column) => Some(*column),
+            Action::Swap => None,
+        };
     }
-}
 
-impl std::ops::IndexMut<(usize, usize)> for Area {
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        return self.area.index_mut(index.0).index_mut(AREA_ROWS - 1 - index.1);
+    /// Fourni la nature du coup
+    pub fn kind(&self) -> MoveKind {
+        return match self {
+            Action::Drop(_) => MoveKind::Drop,
+            Action::Pop(_) => MoveKind::Pop,
+            Action::Swap => MoveKind::Swap,
+        };
     }
 }
 
-impl<'a> Engine<'a> {
-    /// Crée un nouveau gestionnaire de jeux
-    ///
-    /// # Arguments
-    ///
-    ///  * `player_one_interface`: Interface vers le premier joueur, doit implémenter le trait `Interface`
-    ///  * `player_two_interface`: Interface vers le second joueur, doit implémenter le trait `Interface`
+/// Règles de la partie
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    /// Puissance 4 classique: chaque coup ajoute un jeton
+    #[default]
+    Standard,
+    /// Pop Out: à son tour, un joueur peut soit ajouter un jeton, soit retirer l’un de ses jetons
+    /// situé en bas d’une colonne, les jetons au-dessus descendant d’une case.
     ///
-    /// # Retour
-    ///
-    /// Une nouvelle instance de l’objet `Engine`
-    pub fn new(player_one_interface : &'a RefCell<dyn Interface>, player_two_interface : &'a RefCell<dyn Interface>) -> Self {
-        Self {
-            area: Area { area: Default::default() },
-            player_one_interface, player_two_interface
-        }
-    }
+    /// Après un retrait, les alignements des deux couleurs sont recherchés: si le joueur ayant
+    /// retiré le jeton en forme un, il gagne, sinon l’adversaire gagne s’il en forme un. La partie
+    /// est nulle lorsque la zone de jeu est remplie.
+    PopOut,
+}
 
-    /// Modifie le joueur identifié par `player_id` avec une nouvelle interface
+/// Objectif des joueurs
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Objective {
+    /// Le premier joueur alignant ses jetons gagne la partie
+    #[default]
+    Connect,
+    /// Misère: le premier joueur alignant ses jetons perd la partie
+    Misere,
+}
+
+impl PlayerId {
+    /// Le premier joueur
+    pub const ONE : PlayerId = PlayerId(1);
+    /// Le second joueur
+    pub const TWO : PlayerId = PlayerId(2);
+    /// Le troisième joueur
+    pub const THREE : PlayerId = PlayerId(3);
+    /// Le quatrième joueur
+    pub const FOUR : PlayerId = PlayerId(4);
+
+    /// Crée un identifiant de joueur
     ///
     /// # Arguments
     ///
-    ///  * `player_id`: L’identifiant du joueur (doit valoir soit 1 pour le joueur 1 ou 2 pour le
-    /// joueur 2)
-    ///  * `interface`: L’interface pour le joueur identifié par `player_id`
-    ///
-    /// # Retour
-    ///
-    /// Une erreur si l’identifiant du joueur est mauvais
+    ///  * `id` : Le numéro du joueur, compris entre 1 et [`MAX_PLAYERS`]
     ///
     /// # Liste des erreurs possibles
     ///
-    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide (doit valoir 1 ou 2)
-    pub fn set_player(&mut self, player_id : usize, interface : &'a RefCell<dyn Interface>) -> Result<()> {
-        if player_id != 1 && player_id != 2 {
-            return Err(Error::InvalidPlayerId(player_id));
+    ///  * `InvalidPlayerId` - Le numéro n’est pas compris entre 1 et [`MAX_PLAYERS`].
+    pub fn new(id : usize) -> Result<Self> {
+        if id == 0 || id > MAX_PLAYERS {
+            return Err(Error::InvalidPlayerId(id));
         }
 
-        if player_id == 1 {
-            self.player_one_interface = interface;
-        } else {
-            self.player_two_interface = interface;
-        }
-
-        return Ok(());
+        return Ok(PlayerId(id));
     }
 
-    /// Fourni une référence vers la zone de jeu
-    ///
-    /// # Retour
-    ///
-    /// Une référence vers la zone de jeu
-    pub fn get_disposition(&self) -> &Area {
-        return &self.area;
+    /// Fourni le numéro du joueur, compris entre 1 et [`MAX_PLAYERS`]
+    pub fn get(self) -> usize {
+        return self.0;
     }
 
-    /// Réinitialise la zone de jeu
-    ///
-    /// Une fois appelé, toutes les cases se retrouvent avec l’état sans jeton.
-    pub fn reset(&mut self) {
-        self.area.area = Default::default();
+    /// Fourni la position du joueur dans les listes indexées à partir de zéro
+    pub fn index(self) -> usize {
+        return self.0 - 1;
     }
 
-    /// Joues une partie de puissance 4
-    ///
-    /// # Retour
-    ///
-    /// Une fois une partie finie, retourne un objet option valant None si le match est nul ou le
-    /// nom du gagnant.
-    ///
-    /// Si une erreur se produit durant la partie, retourne l’erreur via l’objet `Error`. Ces
-    /// erreurs sont émises en cas de problème avec les joueurs.
+    /// Fourni l’identifiant du joueur jouant après celui-ci
     ///
-    /// # Liste des erreurs possibles
+    /// # Arguments
     ///
-    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise entre 0 et `AREA_COLS-1`.
-    ///  * `FilledColumn` - La colonne dont il est demandé une modification est déjà remplie.
-    pub fn play(&mut self) -> Result<Option<String>> {
-        let mut player = true;
+    ///  * `players` : Le nombre de joueurs de la partie, le premier joueur suivant le dernier
+    pub fn next(self, players : usize) -> Self {
+        return PlayerId(self.0 % players + 1);
+    }
 
-        loop {
-            let (token, interface) = if player {
-                (State::YellowToken, self.player_one_interface)
-            } else {
-                (State::RedToken, self.player_two_interface)
-            };
+    /// Fourni l’identifiant de chacun des joueurs d’une partie du nombre de joueurs donné
+    pub(crate) fn all(players : usize) -> impl Iterator<Item = PlayerId> {
+        return (1..=players).map(PlayerId);
+    }
+}
 
-            let col = interface.borrow_mut().play(&self.area, token);
+impl std::convert::TryFrom<usize> for PlayerId {
+    type Error = Error;
 
-            match self.area.set_token(token, col) {
-                Ok(t) => if t { return Ok(Some(interface.borrow().name())) },
-                Err(e) => return Err(e),
-            }
+    fn try_from(id : usize) -> Result<Self> {
+        return Self::new(id);
+    }
+}
 
-            if self.area.get_available_columns().is_empty() {
-                return Ok(None);
-            }
+impl From<PlayerId> for usize {
+    fn from(id : PlayerId) -> usize {
+        return id.0;
+    }
+}
 
-            player = !player;
-        }
+/// Affiche le numéro du joueur
+impl Display for PlayerId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.0);
     }
 }
 
-impl Area {
-    /// Récupère la liste des colonnes où on peut ajouter des jetons
+impl Objective {
+    /// Fourni le gagnant d’une partie dans laquelle un alignement a été formé
     ///
-    /// Vérifie la présence de jeton sur la dernière ligne pour chaque colonnes afin de construire
-    /// une liste contenant seulement les indices de colonnes pouvant être joués
+    /// # Arguments
+    ///
+    ///  * `player_id` : L’identifiant du joueur dont les jetons sont alignés (1 ou 2, la misère ne
+    ///    se jouant qu’à deux joueurs)
     ///
     /// # Retour
     ///
-    /// La liste des colonnes non remplies
-    pub fn get_available_columns(&self) -> Vec<usize> {
-        let mut ret = Vec::with_capacity(AREA_COLS);
+    /// L’identifiant du joueur gagnant: celui dont les jetons sont alignés ou, en misère, son
+    /// adversaire
+    pub fn winner(self, player_id : PlayerId) -> PlayerId {
+        return match self {
+            Objective::Connect => player_id,
+            Objective::Misere => player_id.next(2),
+        };
+    }
 
-        for i in 0..AREA_COLS {
-            if !self.is_filled_column(i).unwrap() {
-                ret.push(i);
-            }
-        }
+    /// Indique s’il s’agit de l’objectif classique
+    #[cfg(feature = "serde")]
+    fn is_connect(&self) -> bool {
+        return *self == Objective::Connect;
+    }
+}
 
-        return ret;
+impl Variant {
+    /// Indique s’il s’agit des règles classiques
+    #[cfg(feature = "serde")]
+    fn is_standard(&self) -> bool {
+        return *self == Variant::Standard;
     }
+}
 
-    /// Vérifie si la colonne demandée est remplie
+/// Comportement du moteur lorsqu’une interface propose un coup illégal
+///
+/// Un coup est illégal si la colonne donnée n’existe pas ou si elle est déjà remplie.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RetryPolicy {
+    /// Redemande un coup au joueur, au plus `max_attempts` fois au total avant d’interrompre la
+    /// partie avec l’erreur du dernier coup.
+    Reprompt {
+        /// Nombre maximal de tentatives
+        max_attempts : usize
+    },
+    /// Le joueur ayant proposé un coup illégal perd la partie, son adversaire la remportant. Ce
+    /// comportement n’est disponible que pour les parties à deux joueurs.
+    Forfeit,
+    /// La partie est interrompue avec l’erreur correspondant au coup illégal (comportement par
+    /// défaut).
+    #[default]
+    Abort,
+}
+
+/// Résultat d’une partie terminée
+#[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameResult {
+    /// La partie a été gagnée
+    Winner {
+        /// L’identifiant du joueur gagnant
+        player_id : PlayerId,
+        /// Le jeton du joueur gagnant
+        token : State,
+        /// Les cases `(colonne, ligne)` formant l’alignement gagnant, la ligne partant du bas.
+        /// Cette liste est vide si la partie a été gagnée par abandon de l’adversaire. En misère
+        /// (voir [`Objective::Misere`]), il s’agit de l’alignement formé par le perdant.
+        winning_line : Vec<(usize, usize)>,
+    },
+    /// La zone de jeu est remplie sans qu’aucun joueur n’ait gagné
+    Draw,
+    /// La partie a été interrompue avant son terme
+    Aborted,
+    /// Le temps de réflexion du joueur identifié est écoulé, ce dernier perd la partie
+    Timeout {
+        /// L’identifiant du joueur dont le temps est écoulé
+        player_id : PlayerId,
+    },
+}
+
+/// État d’avancement d’une partie
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameStatus {
+    /// La partie est en cours, un joueur doit encore jouer
+    InProgress,
+    /// La partie est gagnée par le joueur identifié
+    Won(PlayerId),
+    /// La zone de jeu est remplie sans qu’aucun joueur n’ait gagné
+    Draw,
+    /// Le temps de réflexion du joueur identifié est écoulé
+    Timeout(PlayerId),
+    /// La partie a été interrompue avant son terme (voir [`Engine::abort`])
+    Aborted,
+}
+
+/// Zone de jeu
+///
+/// Ce dernier possède les fonctionnalités suivantes:
+///  * Gérer l’ajout des jetons,
+///  * Vérifier l’alignment de quatre jetons identiques.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "AreaRepr", try_from = "AreaRepr"))]
+pub struct Area {
+    #[doc(hidden)]
+    config : AreaConfig,
+    /// Jetons rouges, le bit `col * (rows + 1) + row` correspondant à la case `(col, row)`. La
+    /// ligne supplémentaire de chaque colonne reste vide afin de séparer les colonnes.
+    #[doc(hidden)]
+    red : u64,
+    /// Jetons jaunes, suivant la même disposition que `red`
+    #[doc(hidden)]
+    yellow : u64,
+    /// Jetons verts, suivant la même disposition que `red`
+    #[doc(hidden)]
+    green : u64,
+    /// Jetons bleus, suivant la même disposition que `red`
+    #[doc(hidden)]
+    blue : u64,
+    #[doc(hidden)]
+    winning_cells : Vec<(usize, usize)>,
+    #[doc(hidden)]
+    last_move : Option<(usize, usize, State)>,
+    /// Clé de Zobrist de la position, mise à jour à chaque modification d’une case
+    #[doc(hidden)]
+    zobrist : u64,
+    /// Jeton et nombre des jetons placés avant la partie (voir [`Area::set_handicap`])
+    #[doc(hidden)]
+    handicap : Option<(State, usize)>,
+}
+
+/// Interface entre les joueurs et le jeu.
+///
+/// Met en place les fonctions permettant le bon déroulement du jeu.
+pub trait Interface {
+    /// Demande au joueur de jouer
     ///
     /// # Arguments
     ///
-    ///  * `column` : La colonne à vérifier, doit être comprise entre 0 et `AREA_COLS-1`
+    ///  * area : La référence vers l’aire de jeu actuel. Ce dernier peut être cloné pour tester
+    ///    des coups où être affiché par le joueur
+    ///  * token : Le jeton joué par le joueur
     ///
     /// # Retour
     ///
-    /// Un booléen valant `true` si la colonne est remplie sinon `false` ou une erreur si la valeur
-    /// donnée dans l’argument `column` est mauvaise.
-    ///
-    /// # Liste des erreurs possibles
-    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise
-    /// entre 0 et `AREA_COLS-1`.
-    pub fn is_filled_column(&self, column : usize) -> Result<bool> {
-        if column >= AREA_COLS {
-            return Err(Error::InvalidColumn);
-        }
-
-        Ok(State::NoToken != self.area[column][AREA_ROWS-1])
-    }
+    /// Une valeur correspondant à l’indice où placer le jeton. Ce dernier doit être compris entre
+    /// 0 et le nombre de colonnes de l’aire de jeu moins un, ou une erreur si le joueur ne peut
+    /// pas jouer (la partie est alors interrompue).
+    fn play(&mut self, area : &Area, token : State) -> std::result::Result<usize, PlayError>;
 
-    /// Ajoute, si possible, un jeton dans l’aire de jeu
+    /// Demande au joueur de jouer, en lui permettant de retirer un jeton
     ///
-    /// # Arguments
+    /// C’est cette méthode que le moteur appelle à chaque tour. Un retrait n’est accepté que dans
+    /// la variante Pop Out (voir [`Variant::PopOut`]).
     ///
-    ///  * `token` : Le jeton. Doit avoir pour valeur `State::RedToken` ou `State::YellowToken`
-    ///  * `column` : La colonne où ajouter le jeton, doit être comprise entre 0 et `AREA_COLS-1`.
+    /// L’implémentation par défaut ajoute un jeton dans la colonne donnée par
+    /// [`Interface::play`].
     ///
-    /// # Retour
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu actuelle
+    ///  * `token` : Le jeton joué par le joueur
+    fn play_action(&mut self, area : &Area, token : State) -> std::result::Result<Action, PlayError> {
+        return self.play(area, token).map(Action::Drop);
+    }
+
+    /// Propose au joueur d’échanger les camps en réponse au premier coup de son adversaire
+    ///
+    /// Le moteur appelle cette méthode avant [`Interface::play_action`] lorsque la règle du gâteau
+    /// le permet (voir [`Engine::set_swap_rule`]). En cas d’acceptation, le joueur prend le jeton
+    /// et le coup de son adversaire, qui joue alors le coup suivant.
+    ///
+    /// L’implémentation par défaut refuse l’échange.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu après le premier coup
+    ///  * `token` : Le jeton joué par le joueur s’il refuse l’échange
+    ///
+    /// # Retour
+    ///
+    /// `true` pour échanger les camps, ou une erreur si le joueur ne peut pas répondre
+    fn offer_swap(&mut self, _area : &Area, _token : State) -> std::result::Result<bool, PlayError> {
+        return Ok(false);
+    }
+
+    /// Donne le nom donné au joueur.
+    ///
+    /// # Retour
+    ///
+    /// Le nom du joueur sous la forme de chaine de caractères.
+    fn name(&self) -> String;
+
+    /// Appelée lorsque l’adversaire commence sa réflexion
+    ///
+    /// Permet à une intelligence artificielle de réfléchir pendant le tour de l’adversaire, par
+    /// exemple en préparant sa réponse au coup qu’elle prévoit. L’implémentation ne doit pas
+    /// bloquer: tout calcul doit être mené sur un autre fil d’exécution.
+    ///
+    /// L’implémentation par défaut ne fait rien.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu sur laquelle l’adversaire doit jouer
+    ///  * `token` : Le jeton joué par l’adversaire
+    fn on_opponent_thinking(&mut self, _area : &Area, _token : State) {}
+
+    /// Appelée après chaque coup de l’adversaire
+    ///
+    /// L’implémentation par défaut ne fait rien.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu après le coup
+    ///  * `action` : Le coup joué par l’adversaire: ajout ou retrait d’un jeton dans une colonne
+    ///    ou échange des camps
+    fn on_opponent_move(&mut self, _area : &Area, _action : Action) {}
+
+    /// Appelée au début de chaque partie, avant le premier coup
+    ///
+    /// Le jeton du joueur peut encore changer si la règle du gâteau est appliquée (voir
+    /// [`Engine::set_swap_rule`]).
+    ///
+    /// L’implémentation par défaut ne fait rien.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu au début de la partie
+    ///  * `player` : Le joueur représenté par l’interface
+    fn on_game_start(&mut self, _area : &Area, _player : &Player) {}
+
+    /// Appelée lorsque la partie est interrompue avant son terme (voir [`Engine::abort`])
+    ///
+    /// L’implémentation par défaut ne fait rien.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu au moment de l’interruption
+    ///  * `reason` : La raison de l’interruption
+    fn on_game_aborted(&mut self, _area : &Area, _reason : &str) {}
+
+    /// Fourni le nombre de positions explorées pour trouver le dernier coup joué
+    ///
+    /// Appelée par le moteur juste après [`Interface::play_action`], la valeur est enregistrée
+    /// avec le coup (voir [`Move::nodes`]). L’implémentation par défaut ne comptabilise rien.
+    fn nodes_searched(&self) -> Option<u64> {
+        return None;
+    }
+}
+
+/// Observateur des évènements d’une partie.
+///
+/// Permet aux journaux, spectateurs ou interfaces graphiques de suivre le déroulement d’une partie
+/// sans être l’un des deux joueurs. Un observateur est enregistré via [`Engine::add_observer`].
+///
+/// Toutes les méthodes possèdent une implémentation par défaut ne faisant rien.
+pub trait Observer {
+    /// Appelée avant le premier coup d’une partie
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu au début de la partie
+    fn on_game_start(&mut self, _area : &Area) {}
+
+    /// Appelée après chaque coup joué
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu après le coup
+    ///  * `played` : Le coup joué
+    fn on_move_played(&mut self, _area : &Area, _played : &Move) {}
+
+    /// Appelée lorsqu’un joueur propose un coup illégal
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu, inchangée
+    ///  * `player_id` : L’identifiant du joueur ayant proposé le coup
+    ///  * `column` : La colonne proposée, pour un ajout comme pour un retrait
+    ///  * `error` : La raison pour laquelle le coup est illégal
+    fn on_invalid_move(&mut self, _area : &Area, _player_id : PlayerId, _column : usize, _error : &Error) {}
+
+    /// Appelée après l’annulation d’un coup (voir [`Engine::undo`])
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu après l’annulation
+    ///  * `undone` : Le coup annulé
+    fn on_move_undone(&mut self, _area : &Area, _undone : &Move) {}
+
+    /// Appelée une fois la partie terminée
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu finale
+    ///  * `result` : Le résultat de la partie
+    fn on_game_end(&mut self, _area : &Area, _result : &GameResult) {}
+}
+
+/// Affiche la zone de jeu avec le moteur de rendu [`TextRenderer`] et les options par défaut
+impl Display for Area {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(&TextRenderer.render(self, &RenderOptions::default()));
+    }
+}
+
+impl State {
+    /// Jetons des joueurs, dans l’ordre d’attribution lors des parties à plus de deux joueurs: le
+    /// jeton du premier joueur, celui du second puis les jetons vert et bleu
+    pub const TOKENS : [State; 4] = [State::YellowToken, State::RedToken, State::GreenToken, State::BlueToken];
+
+    /// Fourni la position du jeton dans [`State::TOKENS`] ou `None` pour une case vide
+    fn color_rank(self) -> Option<usize> {
+        return State::TOKENS.iter().position(|token| *token == self);
+    }
+
+    /// Fourni l’indice de la couleur du jeton, utilisé pour les clés de Zobrist, ou `None` pour
+    /// une case vide
+    fn color_index(self) -> Option<usize> {
+        return match self {
+            State::NoToken => None,
+            State::RedToken => Some(0),
+            State::YellowToken => Some(1),
+            State::GreenToken => Some(2),
+            State::BlueToken => Some(3),
+        };
+    }
+}
+
+/// Affiche le symbole du jeton ou, avec le format alternatif (`{:#}`), le nom de sa couleur
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self, f.alternate()) {
+            (State::NoToken, false) => f.write_str("  "),
+            (State::RedToken, false) => f.write_str("🔴"),
+            (State::YellowToken, false) => f.write_str("🟡"),
+            (State::GreenToken, false) => f.write_str("🟢"),
+            (State::BlueToken, false) => f.write_str("🔵"),
+            (State::NoToken, true) => f.write_str(i18n::message("token.empty")),
+            (State::RedToken, true) => f.write_str(i18n::message("token.red")),
+            (State::YellowToken, true) => f.write_str(i18n::message("token.yellow")),
+            (State::GreenToken, true) => f.write_str(i18n::message("token.green")),
+            (State::BlueToken, true) => f.write_str(i18n::message("token.blue")),
+        }
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return Display::fmt(self, f);
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::FilledColumn => f.write_str(i18n::message("error.filled_column")),
+            Error::InvalidColumn => f.write_str(i18n::message("error.invalid_column")),
+            Error::NotAToken => f.write_str(i18n::message("error.not_a_token")),
+            Error::InvalidPlayerId(id) => f.write_str(&tr!("error.invalid_player_id", id)),
+            Error::InvalidPlayerCount(count) => f.write_str(&tr!("error.invalid_player_count", count)),
+            Error::InvalidConfig => f.write_str(i18n::message("error.invalid_config")),
+            Error::GameAlreadyFinished => f.write_str(i18n::message("error.game_already_finished")),
+            Error::GameAlreadyStarted => f.write_str(i18n::message("error.game_already_started")),
+            Error::InterfaceFailure(e) => f.write_str(&tr!("error.interface_failure", e)),
+            Error::InvalidPosition => f.write_str(i18n::message("error.invalid_position")),
+            Error::InvalidRow => f.write_str(i18n::message("error.invalid_row")),
+            Error::EmptyColumn => f.write_str(i18n::message("error.empty_column")),
+            Error::Timeout(id) => f.write_str(&tr!("error.timeout", id)),
+            Error::Aborted => f.write_str(i18n::message("error.aborted")),
+            Error::NothingToUndo => f.write_str(i18n::message("error.nothing_to_undo")),
+            Error::PopNotAllowed => f.write_str(i18n::message("error.pop_not_allowed")),
+            Error::NotOwnToken => f.write_str(i18n::message("error.not_own_token")),
+            Error::SwapNotAllowed => f.write_str(i18n::message("error.swap_not_allowed")),
+            Error::NoBalancedPosition => f.write_str(i18n::message("error.no_balanced_position")),
+            Error::RecordMismatch => f.write_str(i18n::message("error.record_mismatch")),
+            Error::UnknownGame(id) => f.write_str(&tr!("error.unknown_game", format!("{:016x}", id))),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InterfaceFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Debug for PlayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return Display::fmt(self, f);
+    }
+}
+
+impl Display for PlayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlayError::Io(e) => f.write_str(&tr!("error.io", e)),
+            PlayError::Disconnected => f.write_str(i18n::message("error.disconnected")),
+            PlayError::Other(msg) => f.write_str(msg),
+        }
+    }
+}
+
+/// Lecture d’une zone de jeu depuis un schéma textuel
+///
+/// Chaque ligne non vide du texte décrit une ligne de la zone de jeu, de la plus haute à la plus
+/// basse. Une case vide est notée `.`, un jeton rouge `R`, un jeton jaune `Y`, un jeton vert `G`,
+/// un jeton bleu `B` (parties à plus de deux joueurs) et une case bloquée `#`, les espaces étant
+/// ignorés. Le nombre de jetons à aligner est celui par défaut, les jetons passant à côté des cases
+/// bloquées.
+///
+/// # Liste des erreurs possibles
+///
+///  * `InvalidConfig` - Le schéma est vide ou ses lignes n’ont pas toutes la même longueur.
+///  * `NotAToken` - Un caractère ne correspond ni à une case vide ni à un jeton.
+///  * `InvalidPosition` - La position ne peut pas être atteinte en cours de partie (voir
+///    [`Area::validate`]).
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, State};
+///
+/// let area : Area = "
+///     .......
+///     .......
+///     .......
+///     ...Y...
+///     ...YR..
+///     ..RYR..
+/// ".parse().unwrap();
+///
+/// assert_eq!(area[(3, 2)], State::YellowToken);
+/// assert_eq!(area[(4, 1)], State::RedToken);
+/// ```
+impl std::str::FromStr for Area {
+    type Err = Error;
+
+    fn from_str(s : &str) -> Result<Self> {
+        let lines : Vec<Vec<char>> = s.lines()
+            .map(|line| line.chars().filter(|c| !c.is_whitespace()).collect::<Vec<char>>())
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        let rows = lines.len();
+        let cols = lines.first().map(|line| line.len()).unwrap_or(0);
+
+        if lines.iter().any(|line| line.len() != cols) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = AreaConfig { rows, cols, ..AreaConfig::default() };
+
+        for (i, line) in lines.iter().enumerate() {
+            for (col, c) in line.iter().enumerate() {
+                if *c == '#' {
+                    config.block(col, rows - 1 - i)?;
+                }
+            }
+        }
+
+        let mut area = Area::with_config(config)?;
+
+        for (i, line) in lines.iter().enumerate() {
+            for (col, c) in line.iter().enumerate() {
+                area.write_cell(col, rows - 1 - i, match c {
+                    '.' | '#' => State::NoToken,
+                    'R' => State::RedToken,
+                    'Y' => State::YellowToken,
+                    'G' => State::GreenToken,
+                    'B' => State::BlueToken,
+                    _ => return Err(Error::NotAToken),
+                });
+            }
+        }
+
+        area.validate()?;
+        area.winning_cells = area.find_winner().map(|(_, cells)| cells).unwrap_or_default();
+
+        return Ok(area);
+    }
+}
+
+impl std::error::Error for PlayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PlayError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for PlayError {
+    fn from(e : std::io::Error) -> Self {
+        PlayError::Io(e)
+    }
+}
+
+/// Deux zones de jeu sont égales si elles ont la même configuration et les mêmes jetons aux mêmes
+/// positions. Les positions symétriques peuvent être identifiées via [`Area::canonical`].
+impl PartialEq for Area {
+    fn eq(&self, other : &Self) -> bool {
+        return self.config == other.config && self.red == other.red && self.yellow == other.yellow && self.green == other.green && self.blue == other.blue
+            && self.handicap == other.handicap;
+    }
+}
+
+impl Eq for Area {}
+
+impl std::hash::Hash for Area {
+    fn hash<H : std::hash::Hasher>(&self, state : &mut H) {
+        self.config.hash(state);
+        self.red.hash(state);
+        self.yellow.hash(state);
+        self.green.hash(state);
+        self.blue.hash(state);
+        self.handicap.hash(state);
+    }
+}
+
+/// Affiche la zone de jeu sous la forme d’un schéma compact, pouvant être relu via `str::parse`
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, State};
+///
+/// let mut area = Area::new();
+///
+/// area.set_token(State::YellowToken, 3).unwrap();
+/// area.set_token(State::RedToken, 3).unwrap();
+///
+/// assert_eq!(format!("{:?}", area).parse::<Area>().unwrap(), area);
+/// ```
+impl Debug for Area {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in (0..self.config.rows).rev() {
+            for col in 0..self.config.cols {
+                f.write_str(match self.cell(col, row) {
+                    State::NoToken if self.config.is_blocked(col, row) => "#",
+                    State::NoToken => ".",
+                    State::RedToken => "R",
+                    State::YellowToken => "Y",
+                    State::GreenToken => "G",
+                    State::BlueToken => "B",
+                })?;
+            }
+
+            if row != 0 {
+                f.write_str("\n")?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for Area {
+    type Output = State;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        return match self.cell(index.0, index.1) {
+            State::NoToken => &State::NoToken,
+            State::RedToken => &State::RedToken,
+            State::YellowToken => &State::YellowToken,
+            State::GreenToken => &State::GreenToken,
+            State::BlueToken => &State::BlueToken,
+        };
+    }
+}
+
+impl Engine {
+    /// Crée un nouveau gestionnaire de jeux
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_one_interface`: Interface vers le premier joueur, doit implémenter les traits `Interface` et `Send`
+    ///  * `player_two_interface`: Interface vers le second joueur, doit implémenter les traits `Interface` et `Send`
+    ///
+    /// # Retour
+    ///
+    /// Une nouvelle instance de l’objet `Engine`
+    pub fn new(player_one_interface : Box<dyn Interface + Send>, player_two_interface : Box<dyn Interface + Send>) -> Self {
+        return Self::with_config(player_one_interface, player_two_interface, AreaConfig::default()).expect("La configuration par défaut est valide");
+    }
+
+    /// Crée un nouveau gestionnaire de jeux dont la zone de jeu suit la configuration donnée
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_one_interface`: Interface vers le premier joueur, doit implémenter les traits `Interface` et `Send`
+    ///  * `player_two_interface`: Interface vers le second joueur, doit implémenter les traits `Interface` et `Send`
+    ///  * `config`: Les dimensions du plateau et le nombre de jetons à aligner
+    ///
+    /// # Retour
+    ///
+    /// Une nouvelle instance de l’objet `Engine` ou une erreur si la configuration est invalide
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig`: L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    pub fn with_config(player_one_interface : Box<dyn Interface + Send>, player_two_interface : Box<dyn Interface + Send>, config : AreaConfig) -> Result<Self> {
+        return Self::with_players(vec![player_one_interface, player_two_interface], config);
+    }
+
+    /// Crée un nouveau gestionnaire de jeux pour une partie de deux à quatre joueurs
+    ///
+    /// Les joueurs jouent à tour de rôle dans l’ordre donné. Les deux premiers joueurs reçoivent
+    /// les jetons jaunes et rouges (voir [`Engine::set_colors`]), le troisième les jetons verts et
+    /// le quatrième les jetons bleus. Le premier joueur à aligner ses jetons gagne la partie,
+    /// celle-ci étant nulle si la zone de jeu est remplie.
+    ///
+    /// La variante Pop Out et l’objectif misère ne se jouent qu’à deux joueurs. Parmi les
+    /// intelligences artificielles fournies, seules [`RandomBot`](super::random_bot::RandomBot) et
+    /// [`GreedyBot`](super::greedy_bot::GreedyBot) tiennent compte de chacun des adversaires, les
+    /// autres ne considérant qu’un seul adversaire.
+    ///
+    /// # Arguments
+    ///
+    ///  * `interfaces`: Interfaces vers les joueurs, dans l’ordre de jeu
+    ///  * `config`: Les dimensions du plateau et le nombre de jetons à aligner
+    ///
+    /// # Retour
+    ///
+    /// Une nouvelle instance de l’objet `Engine` ou une erreur si la configuration ou le nombre de
+    /// joueurs est invalide
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerCount`: Le nombre d’interfaces n’est pas compris entre 2 et 4.
+    ///  * `InvalidConfig`: L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{AreaConfig, Engine, GameStatus, PlayerId, State};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let players = (1..=3).map(|i| Box::new(RandomBot::new(&format!("Robot {}", i))) as _).collect();
+    /// let mut engine = Engine::with_players(players, AreaConfig::default()).unwrap();
+    ///
+    /// assert_eq!(engine.player_count(), 3);
+    /// assert_eq!(engine.player_token(PlayerId::THREE).unwrap(), State::GreenToken);
+    ///
+    /// // Les trois joueurs jouent à tour de rôle
+    /// for col in [0, 1, 2, 0, 1, 2, 0, 1, 2].iter() {
+    ///     engine.apply_move(*col).unwrap();
+    /// }
+    ///
+    /// assert_eq!(engine.apply_move(0).unwrap(), GameStatus::Won(PlayerId::ONE));
+    /// ```
+    pub fn with_players(interfaces : Vec<Box<dyn Interface + Send>>, config : AreaConfig) -> Result<Self> {
+        if interfaces.len() < 2 || interfaces.len() > MAX_PLAYERS {
+            return Err(Error::InvalidPlayerCount(interfaces.len()));
+        }
+
+        let count = interfaces.len();
+
+        Ok(Self {
+            area: Area::with_config(config)?,
+            names: interfaces.iter().map(|interface| interface.name()).collect(),
+            interfaces: interfaces.into_iter().map(|interface| Arc::new(Mutex::new(interface))).collect(),
+            current_player: PlayerId::ONE,
+            status: GameStatus::InProgress,
+            retry_policy: RetryPolicy::default(),
+            history: Vec::new(),
+            observers: Vec::new(),
+            started: false,
+            time_control: None,
+            clocks: vec![Duration::default(); count],
+            turn_started: None,
+            move_timeout: None,
+            first_player: PlayerId::ONE,
+            alternate_first_player: false,
+            player_one_token: State::YellowToken,
+            variant: Variant::Standard,
+            objective: Objective::Connect,
+            handicap: None,
+            swap_rule: false,
+        })
+    }
+
+    /// Modifie le joueur identifié par `player_id` avec une nouvelle interface
+    ///
+    /// Le moteur prend possession de l’interface, l’ancienne interface du joueur est détruite. Les
+    /// joueurs peuvent ainsi être remplacés à tout moment, y compris entre deux coups.
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_id`: L’identifiant du joueur (compris entre 1 et le nombre de joueurs)
+    ///  * `interface`: L’interface pour le joueur identifié par `player_id`
+    ///
+    /// # Retour
+    ///
+    /// Une erreur si l’identifiant du joueur est mauvais
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    pub fn set_player(&mut self, player_id : PlayerId, interface : Box<dyn Interface + Send>) -> Result<()> {
+        self.check_player(player_id)?;
+
+        self.names[player_id.index()] = interface.name();
+        self.interfaces[player_id.index()] = Arc::new(Mutex::new(interface));
+
+        return Ok(());
+    }
+
+    /// Fourni le nombre de joueurs participant aux parties (de 2 à 4)
+    pub fn player_count(&self) -> usize {
+        return self.interfaces.len();
+    }
+
+    /// Fourni une référence vers la zone de jeu
+    ///
+    /// # Retour
+    ///
+    /// Une référence vers la zone de jeu
+    pub fn get_disposition(&self) -> &Area {
+        return &self.area;
+    }
+
+    /// Enregistre un observateur qui sera notifié des évènements des parties suivantes
+    ///
+    /// # Arguments
+    ///
+    ///  * `observer`: L’observateur, doit implémenter le trait `Observer` et pouvoir être
+    ///    transmis à un autre fil d’exécution
+    pub fn add_observer(&mut self, observer : Box<dyn Observer + Send>) {
+        self.observers.push(observer);
+    }
+
+    /// Modifie le contrôle du temps des parties
+    ///
+    /// Les pendules des joueurs sont remises au temps initial. Le temps de réflexion est
+    /// décompté à partir du début du tour de chaque joueur; lorsque les coups sont donnés via
+    /// [`Engine::apply_move`], le premier coup de la partie n’est pas décompté.
+    ///
+    /// # Arguments
+    ///
+    ///  * `time_control`: Le temps initial et l’incrément, `None` pour des parties sans limite de
+    ///    temps
+    pub fn set_time_control(&mut self, time_control : Option<TimeControl>) {
+        self.time_control = time_control;
+        self.reset_clocks();
+    }
+
+    /// Fourni le contrôle du temps des parties, s’il existe
+    pub fn time_control(&self) -> Option<&TimeControl> {
+        return self.time_control.as_ref();
+    }
+
+    /// Fourni le temps de réflexion restant au joueur identifié par `player_id`
+    ///
+    /// Le temps écoulé depuis le début du tour du joueur courant est pris en compte.
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_id`: L’identifiant du joueur (compris entre 1 et le nombre de joueurs)
+    ///
+    /// # Retour
+    ///
+    /// Le temps restant ou `None` si les parties ne sont pas chronométrées
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    pub fn remaining_time(&self, player_id : PlayerId) -> Result<Option<Duration>> {
+        self.check_player(player_id)?;
+
+        if self.time_control.is_none() {
+            return Ok(None);
+        }
+
+        let remaining = self.clocks[player_id.index()];
+
+        if player_id == self.current_player && self.status == GameStatus::InProgress {
+            return Ok(Some(remaining.checked_sub(self.elapsed()).unwrap_or_default()));
+        }
+
+        return Ok(Some(remaining));
+    }
+
+    /// Modifie la limite de temps imposée aux interfaces pour chaque coup
+    ///
+    /// Seuls les coups demandés par le moteur ([`Engine::step`], [`Engine::play`]) sont concernés.
+    ///
+    /// # Arguments
+    ///
+    ///  * `move_timeout`: La limite de temps et l’action à effectuer en cas de dépassement, `None`
+    ///    pour laisser les interfaces réfléchir sans limite
+    pub fn set_move_timeout(&mut self, move_timeout : Option<MoveTimeout>) {
+        self.move_timeout = move_timeout;
+    }
+
+    /// Modifie le comportement du moteur lorsqu’un joueur propose un coup illégal
+    ///
+    /// Par défaut, la partie est interrompue (voir [`RetryPolicy::Abort`]).
+    ///
+    /// # Arguments
+    ///
+    ///  * `policy`: Le comportement à adopter
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerCount`: L’abandon ([`RetryPolicy::Forfeit`]) est demandé pour plus de deux
+    ///    joueurs, aucun vainqueur ne pouvant alors être désigné.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{AreaConfig, Engine, Error, Interface, RetryPolicy};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let players = (1..=3).map(|id| Box::new(RandomBot::new(&format!("Robot {}", id))) as Box<dyn Interface + Send>).collect();
+    /// let mut engine = Engine::with_players(players, AreaConfig::default()).unwrap();
+    ///
+    /// assert!(matches!(engine.set_retry_policy(RetryPolicy::Forfeit), Err(Error::InvalidPlayerCount(3))));
+    /// assert!(engine.set_retry_policy(RetryPolicy::Reprompt { max_attempts: 3 }).is_ok());
+    /// ```
+    pub fn set_retry_policy(&mut self, policy : RetryPolicy) -> Result<()> {
+        self.check_retry_policy(policy)?;
+
+        self.retry_policy = policy;
+
+        return Ok(());
+    }
+
+    /// Fourni la configuration de la zone de jeu utilisée par la partie
+    pub fn config(&self) -> &AreaConfig {
+        return self.area.config();
+    }
+
+    /// Remplace la configuration de la zone de jeu
+    ///
+    /// La zone de jeu est recréée vide avec les nouvelles dimensions, sans handicap, et la partie
+    /// recommence.
+    ///
+    /// # Arguments
+    ///
+    ///  * `config`: Les dimensions du plateau et le nombre de jetons à aligner
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig`: L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    pub fn set_config(&mut self, config : AreaConfig) -> Result<()> {
+        self.area = Area::with_config(config)?;
+        self.handicap = None;
+        self.restart();
+
+        return Ok(());
+    }
+
+    /// Crée un nouveau gestionnaire de jeux reprenant une partie à partir d’une position donnée
+    ///
+    /// Permet de continuer une partie sauvegardée, un problème ou une analyse à partir d’une zone
+    /// de jeu déjà entamée. L’historique des coups de la partie est vide.
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_one_interface`: Interface vers le premier joueur, doit implémenter les traits `Interface` et `Send`
+    ///  * `player_two_interface`: Interface vers le second joueur, doit implémenter les traits `Interface` et `Send`
+    ///  * `area`: La zone de jeu à partir de laquelle la partie reprend
+    ///  * `next_player`: L’identifiant du joueur devant jouer le prochain coup
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    ///  * `InvalidPosition`: La position ne peut pas être atteinte en cours de partie (voir
+    ///    [`Area::validate`]).
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, Engine, GameStatus, PlayerId, State};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut area = Area::new();
+    ///
+    /// for col in 0..4 {
+    ///     area.set_cell(col, 0, State::YellowToken).unwrap();
+    /// }
+    ///
+    /// for col in 0..3 {
+    ///     area.set_cell(col, 1, State::RedToken).unwrap();
+    /// }
+    ///
+    /// let engine = Engine::from_position(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")), area, PlayerId::TWO).unwrap();
+    ///
+    /// assert_eq!(engine.status(), GameStatus::Won(PlayerId::ONE));
+    /// ```
+    pub fn from_position(player_one_interface : Box<dyn Interface + Send>, player_two_interface : Box<dyn Interface + Send>, area : Area, next_player : PlayerId) -> Result<Self> {
+        let mut engine = Self::with_config(player_one_interface, player_two_interface, area.config)?;
+
+        engine.set_position(area, next_player)?;
+
+        return Ok(engine);
+    }
+
+    /// Remplace la partie en cours par une position donnée
+    ///
+    /// La configuration de la zone de jeu est celle de la position. L’historique des coups est
+    /// vidé. Les alignements de la zone de jeu sont recherchés sur l’ensemble de ses cases: la
+    /// partie est considérée gagnée si l’un d’eux est formé et nulle si la zone de jeu est
+    /// remplie.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area`: La zone de jeu à partir de laquelle la partie reprend
+    ///  * `next_player`: L’identifiant du joueur devant jouer le prochain coup
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    ///  * `InvalidPosition`: La position ne peut pas être atteinte en cours de partie dans la
+    ///    variante de la partie (voir [`Area::validate_variant`]).
+    pub fn set_position(&mut self, mut area : Area, next_player : PlayerId) -> Result<()> {
+        self.check_player(next_player)?;
+
+        area.validate_variant(self.variant)?;
+        area.winning_cells = area.find_winner().map(|(_, cells)| cells).unwrap_or_default();
+
+        self.area = area;
+        self.handicap = None;
+        self.restart();
+        self.current_player = next_player;
+
+        if let Some(&(col, row)) = self.area.winning_cells.first() {
+            let aligned = self.owner(self.area.cell(col, row)).ok_or(Error::InvalidPosition)?;
+
+            self.status = GameStatus::Won(self.objective.winner(aligned));
+        } else if self.area.get_available_columns().is_empty() {
+            self.status = GameStatus::Draw;
+        }
+
+        return Ok(());
+    }
+
+    /// Fourni l’ensemble des paramètres du gestionnaire de jeux
+    pub fn settings(&self) -> EngineSettings {
+        return EngineSettings {
+            area: self.area.config,
+            retry_policy: self.retry_policy,
+            time_control: self.time_control,
+            move_timeout: self.move_timeout,
+            first_player: self.first_player,
+            alternate_first_player: self.alternate_first_player,
+            player_one_token: self.player_one_token,
+            variant: self.variant,
+            objective: self.objective,
+            swap_rule: self.swap_rule,
+        };
+    }
+
+    /// Applique un ensemble de paramètres au gestionnaire de jeux
+    ///
+    /// La zone de jeu est recréée vide, sans handicap, et la partie recommence.
+    ///
+    /// # Arguments
+    ///
+    ///  * `settings`: Les paramètres à appliquer
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig`: L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    ///  * `InvalidPlayerId`: L’identifiant du premier joueur est invalide
+    ///  * `NotAToken`: Le jeton du premier joueur n’est ni jaune ni rouge.
+    ///  * `InvalidPlayerCount`: Les règles ou le comportement face aux coups illégaux demandés ne
+    ///    se jouent qu’à deux joueurs.
+    pub fn apply_settings(&mut self, settings : EngineSettings) -> Result<()> {
+        self.check_player(settings.first_player)?;
+
+        if !is_player_one_token(settings.player_one_token) {
+            return Err(Error::NotAToken);
+        }
+
+        self.check_two_player_rules(settings.variant, settings.objective, settings.swap_rule)?;
+        self.check_retry_policy(settings.retry_policy)?;
+
+        self.area = Area::with_config(settings.area)?;
+        self.handicap = None;
+        self.retry_policy = settings.retry_policy;
+        self.time_control = settings.time_control;
+        self.move_timeout = settings.move_timeout;
+        self.first_player = settings.first_player;
+        self.alternate_first_player = settings.alternate_first_player;
+        self.player_one_token = settings.player_one_token;
+        self.variant = settings.variant;
+        self.objective = settings.objective;
+        self.swap_rule = settings.swap_rule;
+        self.restart();
+
+        return Ok(());
+    }
+
+    /// Enregistre la partie en cours
+    ///
+    /// # Retour
+    ///
+    /// L’enregistrement des coups joués depuis le début de la partie
+    pub fn record(&self) -> GameRecord {
+        return GameRecord {
+            config: self.area.config,
+            first_player: self.first_player,
+            player_one_token: self.player_one_token,
+            moves: self.history.iter().map(|m| m.column).collect(),
+            variant: self.variant,
+            pops: self.history.iter().enumerate().filter(|(_, m)| m.kind == MoveKind::Pop).map(|(i, _)| i).collect(),
+            objective: self.objective,
+            players: self.player_count(),
+            handicap: self.handicap.clone(),
+            swap_rule: self.swap_rule,
+            swaps: self.history.iter().enumerate().filter(|(_, m)| m.kind == MoveKind::Swap).map(|(i, _)| i).collect(),
+            think_times: self.history.iter().map(|m| m.think_time).collect(),
+            nodes: if self.history.iter().any(|m| m.nodes.is_some()) { self.history.iter().map(|m| m.nodes).collect() } else { Vec::new() },
+        };
+    }
+
+    /// Reprend une partie enregistrée
+    ///
+    /// La zone de jeu est recréée avec la configuration de l’enregistrement puis les coups sont
+    /// rejoués dans l’ordre. Les horloges repartent de leur temps initial. L’enregistrement est
+    /// vérifié avant de remplacer la partie en cours, qui n’est pas modifiée en cas d’erreur.
+    ///
+    /// # Arguments
+    ///
+    ///  * `record`: L’enregistrement de la partie à reprendre
+    ///
+    /// # Retour
+    ///
+    /// L’état de la partie une fois tous les coups rejoués
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig`: L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    ///  * `InvalidPlayerCount`: Le nombre de joueurs de l’enregistrement diffère de celui du
+    ///    moteur ou les règles de la partie ne se jouent qu’à deux joueurs.
+    ///  * `InvalidPlayerId`: L’identifiant du premier joueur est invalide
+    ///  * `NotAToken`: Le jeton du premier joueur n’est ni jaune ni rouge.
+    ///  * `InvalidPosition`: Les jetons de handicap forment un alignement.
+    ///  * `InvalidColumn`, `FilledColumn`, `EmptyColumn`, `PopNotAllowed`, `NotOwnToken`,
+    ///    `SwapNotAllowed`, `GameAlreadyFinished`: L’un des coups enregistrés ou des jetons de
+    ///    handicap est illégal.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{AreaConfig, Engine, Error, GameRecord};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.load_record(&GameRecord::from_notation("444", AreaConfig::default()).unwrap()).unwrap();
+    ///
+    /// // La colonne 1 déborde au septième coup: la partie en cours est conservée
+    /// let mut overfilled = GameRecord::from_notation("111111", AreaConfig::default()).unwrap();
+    ///
+    /// overfilled.moves.push(0);
+    ///
+    /// assert!(matches!(engine.load_record(&overfilled), Err(Error::FilledColumn)));
+    /// assert_eq!(engine.record().to_notation().unwrap(), "444");
+    /// ```
+    pub fn load_record(&mut self, record : &GameRecord) -> Result<GameStatus> {
+        if record.players != self.player_count() {
+            return Err(Error::InvalidPlayerCount(record.players));
+        }
+
+        self.check_player(record.first_player)?;
+
+        if !is_player_one_token(record.player_one_token) {
+            return Err(Error::NotAToken);
+        }
+
+        self.check_two_player_rules(record.variant, record.objective, record.swap_rule)?;
+
+        if let Some(handicap) = &record.handicap {
+            self.check_player(handicap.player_id)?;
+        }
+
+        // Les coups sont rejoués à part afin de ne pas remplacer la partie en cours par une partie
+        // partiellement rejouée
+        record.positions()?;
+
+        self.area = Area::with_config(record.config)?;
+        self.first_player = record.first_player;
+        self.player_one_token = record.player_one_token;
+        self.variant = record.variant;
+        self.objective = record.objective;
+        self.handicap = record.handicap.clone();
+        self.swap_rule = record.swap_rule;
+        self.place_handicap()?;
+        self.restart();
+
+        for (i, action) in record.actions().enumerate() {
+            self.apply_searched_action(action, record.nodes.get(i).copied().flatten())?;
+
+            if let Some(played) = self.history.last_mut() {
+                played.think_time = record.think_times.get(i).copied().unwrap_or_default();
+            }
+        }
+
+        return Ok(self.status);
+    }
+
+    /// Modifie le joueur jouant le premier coup des parties
+    ///
+    /// Si aucun coup n’a encore été joué, la partie en cours est également concernée.
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_id`: L’identifiant du joueur commençant les parties
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    pub fn set_first_player(&mut self, player_id : PlayerId) -> Result<()> {
+        self.check_player(player_id)?;
+
+        self.first_player = player_id;
+
+        if self.history.is_empty() && self.status == GameStatus::InProgress {
+            self.current_player = player_id;
+        }
+
+        return Ok(());
+    }
+
+    /// Fourni l’identifiant du joueur jouant le premier coup de la partie
+    pub fn first_player(&self) -> PlayerId {
+        return self.first_player;
+    }
+
+    /// Alterne automatiquement le joueur commençant les parties à chaque appel de
+    /// [`Engine::reset`], pour des séries de parties équitables
+    ///
+    /// # Arguments
+    ///
+    ///  * `alternate`: `true` pour alterner le premier joueur, `false` pour le conserver
+    pub fn set_alternate_first_player(&mut self, alternate : bool) {
+        self.alternate_first_player = alternate;
+    }
+
+    /// Modifie les couleurs des joueurs
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_one_token`: Le jeton du premier joueur, jaune ou rouge, le second joueur
+    ///    recevant l’autre jeton. Les troisième et quatrième joueurs conservent les jetons verts et
+    ///    bleus.
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `NotAToken`: L’état donné n’est ni un jeton jaune ni un jeton rouge.
+    ///  * `GameAlreadyStarted`: Des coups ont déjà été joués dans la partie en cours.
+    pub fn set_colors(&mut self, player_one_token : State) -> Result<()> {
+        if !is_player_one_token(player_one_token) {
+            return Err(Error::NotAToken);
+        }
+
+        if !self.history.is_empty() {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        self.player_one_token = player_one_token;
+
+        // Les jetons de handicap prennent la nouvelle couleur de leur joueur
+        return self.place_handicap();
+    }
+
+    /// Fourni le jeton du joueur identifié par `player_id`
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_id`: L’identifiant du joueur (compris entre 1 et le nombre de joueurs)
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    pub fn player_token(&self, player_id : PlayerId) -> Result<State> {
+        self.check_player(player_id)?;
+
+        return Ok(self.token(player_id));
+    }
+
+    /// Modifie le nombre de jetons à aligner afin de gagner
+    ///
+    /// Permet de jouer aux variantes du jeu (puissance 3, 5, 6…) sans modifier les dimensions du
+    /// plateau ni les jetons déjà placés.
+    ///
+    /// # Arguments
+    ///
+    ///  * `win_length`: Le nombre de jetons à aligner
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig`: Le nombre de jetons à aligner est nul.
+    pub fn set_win_length(&mut self, win_length : usize) -> Result<()> {
+        return self.area.set_win_length(win_length);
+    }
+
+    /// Modifie les règles des parties
+    ///
+    /// # Arguments
+    ///
+    ///  * `variant`: Les règles à appliquer
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyStarted`: Des coups ont déjà été joués dans la partie en cours.
+    ///  * `InvalidPlayerCount`: La variante Pop Out est demandée pour plus de deux joueurs.
+    pub fn set_variant(&mut self, variant : Variant) -> Result<()> {
+        if !self.history.is_empty() {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        self.check_two_player_rules(variant, self.objective, self.swap_rule)?;
+
+        self.variant = variant;
+
+        return Ok(());
+    }
+
+    /// Fourni les règles des parties
+    pub fn variant(&self) -> Variant {
+        return self.variant;
+    }
+
+    /// Modifie l’objectif des joueurs
+    ///
+    /// En misère, le joueur formant un alignement perd la partie: le résultat désigne alors son
+    /// adversaire comme gagnant, l’alignement donné étant celui du perdant.
+    ///
+    /// # Arguments
+    ///
+    ///  * `objective`: L’objectif à appliquer
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyStarted`: Des coups ont déjà été joués dans la partie en cours.
+    ///  * `InvalidPlayerCount`: La misère est demandée pour plus de deux joueurs.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Engine, GameStatus, Objective, PlayerId};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.set_objective(Objective::Misere).unwrap();
+    ///
+    /// for col in [0, 1, 0, 1, 0, 1].iter() {
+    ///     engine.apply_move(*col).unwrap();
+    /// }
+    ///
+    /// // Le premier joueur aligne quatre jetons et perd la partie
+    /// assert_eq!(engine.apply_move(0).unwrap(), GameStatus::Won(PlayerId::TWO));
+    /// ```
+    pub fn set_objective(&mut self, objective : Objective) -> Result<()> {
+        if !self.history.is_empty() {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        self.check_two_player_rules(self.variant, objective, self.swap_rule)?;
+
+        self.objective = objective;
+
+        return Ok(());
+    }
+
+    /// Fourni l’objectif des joueurs
+    pub fn objective(&self) -> Objective {
+        return self.objective;
+    }
+
+    /// Active ou désactive la règle du gâteau
+    ///
+    /// Après le premier coup de la partie, le second joueur peut alors, au lieu de répondre,
+    /// échanger les camps (voir [`Interface::offer_swap`] et [`Action::Swap`]): il prend le jeton
+    /// et le coup du premier joueur, qui joue ensuite avec l’autre jeton. Le premier joueur a
+    /// ainsi intérêt à ouvrir par un coup ni trop fort ni trop faible. L’échange est enregistré
+    /// dans l’historique de la partie (voir [`MoveKind::Swap`]). Il n’est pas possible dans une
+    /// partie avec handicap.
+    ///
+    /// # Arguments
+    ///
+    ///  * `swap_rule`: `true` pour activer la règle
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyStarted`: Des coups ont déjà été joués dans la partie en cours.
+    ///  * `InvalidPlayerCount`: La règle est demandée pour plus de deux joueurs.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Action, Engine, MoveKind, PlayerId, State};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.set_swap_rule(true).unwrap();
+    /// engine.apply_move(3).unwrap();
+    ///
+    /// // Le second joueur prend le jeton jaune et le coup central du premier joueur
+    /// engine.apply_action(Action::Swap).unwrap();
+    ///
+    /// assert_eq!(engine.history()[1].kind, MoveKind::Swap);
+    /// assert_eq!(engine.player_token(PlayerId::TWO).unwrap(), State::YellowToken);
+    /// assert_eq!(engine.record().to_notation().unwrap(), "4*");
+    ///
+    /// // L’échange n’est possible qu’en réponse au premier coup
+    /// engine.apply_move(2).unwrap();
+    /// assert!(engine.apply_action(Action::Swap).is_err());
+    /// ```
+    pub fn set_swap_rule(&mut self, swap_rule : bool) -> Result<()> {
+        if !self.history.is_empty() {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        self.check_two_player_rules(self.variant, self.objective, swap_rule)?;
+
+        self.swap_rule = swap_rule;
+
+        return Ok(());
+    }
+
+    /// Indique si la règle du gâteau est appliquée
+    pub fn swap_rule(&self) -> bool {
+        return self.swap_rule;
+    }
+
+    /// Accorde un handicap à l’un des joueurs
+    ///
+    /// Les jetons du joueur désigné sont placés dans la zone de jeu avant le premier coup de la
+    /// partie en cours puis de chaque nouvelle partie (voir [`Engine::reset`]). Le joueur jouant le
+    /// premier coup reste celui défini par [`Engine::set_first_player`]. Le handicap est conservé
+    /// dans l’enregistrement de la partie.
+    ///
+    /// # Arguments
+    ///
+    ///  * `handicap`: Le joueur et les colonnes de ses jetons, `None` pour retirer le handicap
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyStarted`: Des coups ont déjà été joués dans la partie en cours.
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    ///  * `InvalidColumn`, `FilledColumn`, `InvalidPosition`: Les jetons ne peuvent pas être placés
+    ///    (voir [`Area::set_handicap`]).
+    ///
+    /// En cas d’erreur, le handicap précédent est conservé.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Engine, Handicap, PlayerId, State};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.set_handicap(Some(Handicap { player_id: PlayerId::TWO, columns: vec![3, 2] })).unwrap();
+    ///
+    /// assert_eq!(engine.get_disposition()[(3, 0)], State::RedToken);
+    /// assert_eq!(engine.get_disposition().handicap(), Some((State::RedToken, 2)));
+    ///
+    /// engine.apply_move(3).unwrap();
+    /// engine.reset();
+    ///
+    /// // Le handicap est de nouveau placé au début de la partie suivante
+    /// assert_eq!(engine.get_disposition().token_count(), 2);
+    /// ```
+    pub fn set_handicap(&mut self, handicap : Option<Handicap>) -> Result<()> {
+        if !self.history.is_empty() {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        if let Some(handicap) = &handicap {
+            self.check_player(handicap.player_id)?;
+        }
+
+        let previous = std::mem::replace(&mut self.handicap, handicap);
+
+        if let Err(e) = self.place_handicap() {
+            self.handicap = previous;
+            self.place_handicap()?;
+
+            return Err(e);
+        }
+
+        self.restart();
+
+        return Ok(());
+    }
+
+    /// Fourni le handicap accordé à l’un des joueurs, s’il existe
+    pub fn handicap(&self) -> Option<&Handicap> {
+        return self.handicap.as_ref();
+    }
+
+    /// Réinitialise la zone de jeu
+    ///
+    /// Une fois appelé, toutes les cases se retrouvent avec l’état sans jeton, à l’exception des
+    /// jetons de handicap (voir [`Engine::set_handicap`]), l’historique des coups est vidé et le
+    /// premier joueur reprend la main. Si l’alternance est activée (voir
+    /// [`Engine::set_alternate_first_player`]), le joueur suivant commence la nouvelle partie.
+    pub fn reset(&mut self) {
+        // La disposition ayant été vérifiée, seule une modification du nombre de jetons à aligner
+        // peut la rendre invalide: la partie se joue alors sans handicap
+        if self.place_handicap().is_err() {
+            self.handicap = None;
+        }
+
+        if self.alternate_first_player {
+            self.first_player = self.next_player(self.first_player);
+        }
+
+        self.restart();
+    }
+
+    /// Interrompt la partie en cours, par exemple lors de la déconnexion d’un joueur distant ou à
+    /// la demande de l’utilisateur
+    ///
+    /// Le temps écoulé depuis le début du tour est décompté du temps de réflexion du joueur
+    /// courant. Chaque interface est prévenue via [`Interface::on_game_aborted`] et chaque
+    /// observateur reçoit le résultat [`GameResult::Aborted`]. La partie ne peut ensuite plus
+    /// continuer ni être annulée: seul [`Engine::reset`] permet d’en commencer une nouvelle.
+    ///
+    /// # Arguments
+    ///
+    ///  * `reason` : La raison de l’interruption, transmise aux interfaces
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` - La partie est déjà terminée.
+    ///  * `Timeout` - La partie est déjà terminée, perdue au temps par le joueur donné.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Engine, Error, GameStatus};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.apply_move(3).unwrap();
+    /// engine.abort("Connexion perdue").unwrap();
+    ///
+    /// assert_eq!(engine.status(), GameStatus::Aborted);
+    /// assert!(matches!(engine.apply_move(3), Err(Error::GameAlreadyFinished)));
+    /// assert!(matches!(engine.undo(), Err(Error::GameAlreadyFinished)));
+    ///
+    /// engine.reset();
+    ///
+    /// assert_eq!(engine.status(), GameStatus::InProgress);
+    /// ```
+    pub fn abort(&mut self, reason : &str) -> Result<()> {
+        self.ensure_in_progress()?;
+
+        if self.time_control.is_some() {
+            let elapsed = self.elapsed();
+            let clock = &mut self.clocks[self.current_player.index()];
+
+            *clock = clock.checked_sub(elapsed).unwrap_or_default();
+        }
+
+        self.turn_started = None;
+
+        // Une interface ayant paniqué ou encore occupée par un coup hors délai ne peut pas être
+        // prévenue
+        for interface in self.interfaces.iter() {
+            if let Ok(Some(mut interface)) = try_lock(interface) {
+                interface.on_game_aborted(&self.area, reason);
+            }
+        }
+
+        self.finish(GameStatus::Aborted);
+
+        return Ok(());
+    }
+
+    /// Fourni la liste des coups joués depuis le début de la partie
+    ///
+    /// # Retour
+    ///
+    /// Les coups joués, du plus ancien au plus récent
+    pub fn history(&self) -> &[Move] {
+        return &self.history;
+    }
+
+    /// Fourni l’identifiant du joueur devant jouer le prochain coup
+    ///
+    /// Une fois la partie terminée, il s’agit du joueur qui aurait dû jouer le coup suivant.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Engine, GameStatus, PlayerId};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.set_first_player(PlayerId::TWO).unwrap();
+    /// engine.apply_move(3).unwrap();
+    ///
+    /// assert_eq!(engine.current_player(), PlayerId::ONE);
+    /// assert_eq!(engine.move_number(), 2);
+    /// assert_eq!(engine.status(), GameStatus::InProgress);
+    /// assert_eq!(engine.clocks(), None);
+    /// ```
+    pub fn current_player(&self) -> PlayerId {
+        return self.current_player;
+    }
+
+    /// Fourni le numéro du prochain coup de la partie, le premier coup portant le numéro 1
+    ///
+    /// Chaque coup joué, y compris un retrait ou un échange des camps, compte pour un coup.
+    pub fn move_number(&self) -> usize {
+        return self.history.len() + 1;
+    }
+
+    /// Fourni l’état d’avancement de la partie
+    pub fn status(&self) -> GameStatus {
+        return self.status;
+    }
+
+    /// Fourni le temps de réflexion restant à chacun des joueurs
+    ///
+    /// Le temps écoulé depuis le début du tour du joueur courant est pris en compte (voir
+    /// [`Engine::remaining_time`]).
+    ///
+    /// # Retour
+    ///
+    /// Le temps restant de chaque joueur, dans l’ordre de leurs identifiants, ou `None` si les
+    /// parties ne sont pas chronométrées
+    pub fn clocks(&self) -> Option<Vec<Duration>> {
+        return PlayerId::all(self.player_count()).map(|player_id| self.remaining_time(player_id).ok().flatten()).collect();
+    }
+
+    /// Joue le coup du joueur courant dans la colonne donnée
+    ///
+    /// Permet aux interfaces graphiques, asynchrones ou réseau de piloter elles-mêmes le
+    /// déroulement de la partie. Le joueur courant change une fois le coup joué.
+    ///
+    /// Lorsque les parties sont chronométrées, le dépassement du temps n’est constaté qu’à la
+    /// réception du coup, la partie étant alors perdue au temps: l’appelant doit surveiller
+    /// [`Engine::remaining_time`] pendant la réflexion du joueur (voir [`TimeControl`]).
+    ///
+    /// # Arguments
+    ///
+    ///  * `column`: La colonne où placer le jeton du joueur courant
+    ///
+    /// # Retour
+    ///
+    /// L’état de la partie après le coup ou une erreur si le coup est invalide. En cas d’erreur,
+    /// la main reste au joueur courant.
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` - La partie est déjà terminée.
+    ///  * `Timeout` - La partie est déjà terminée, perdue au temps par le joueur donné.
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise
+    ///    entre 0 et le nombre de colonnes moins un.
+    ///  * `FilledColumn` - La colonne dont il est demandé une modification est déjà remplie.
+    pub fn apply_move(&mut self, column : usize) -> Result<GameStatus> {
+        return self.apply_action(Action::Drop(column));
+    }
+
+    /// Joue le coup donné pour le joueur courant, ajout ou retrait d’un jeton
+    ///
+    /// Un retrait n’est possible que dans la variante Pop Out (voir [`Engine::set_variant`]) et
+    /// uniquement pour un jeton du joueur courant situé en bas d’une colonne. Le joueur courant
+    /// change une fois le coup joué. Comme pour [`Engine::apply_move`], le dépassement du temps
+    /// n’est constaté qu’à la réception du coup.
+    ///
+    /// # Arguments
+    ///
+    ///  * `action`: Le coup du joueur courant
+    ///
+    /// # Retour
+    ///
+    /// L’état de la partie après le coup ou une erreur si le coup est invalide. En cas d’erreur,
+    /// la main reste au joueur courant.
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` - La partie est déjà terminée.
+    ///  * `Timeout` - La partie est déjà terminée, perdue au temps par le joueur donné.
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    ///  * `FilledColumn` - La colonne où ajouter le jeton est déjà remplie.
+    ///  * `PopNotAllowed` - Un retrait est demandé hors de la variante Pop Out.
+    ///  * `EmptyColumn` - La colonne où retirer le jeton est vide.
+    ///  * `NotOwnToken` - Le jeton du bas de la colonne appartient à l’adversaire.
+    ///  * `SwapNotAllowed` - L’échange des camps est impossible (voir [`Engine::set_swap_rule`]).
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Action, Engine, GameStatus, MoveKind, Variant};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.set_variant(Variant::PopOut).unwrap();
+    /// engine.apply_action(Action::Drop(3)).unwrap();
+    /// engine.apply_action(Action::Drop(3)).unwrap();
+    ///
+    /// // Le jeton du bas de la colonne appartient au premier joueur
+    /// assert!(engine.apply_action(Action::Pop(3)).is_ok());
+    /// assert_eq!(engine.history()[2].kind, MoveKind::Pop);
+    /// assert_eq!(engine.get_disposition().column_height(3).unwrap(), 1);
+    ///
+    /// // Le jeton restant appartient au second joueur
+    /// engine.apply_action(Action::Drop(0)).unwrap();
+    /// assert!(engine.apply_action(Action::Pop(3)).is_err());
+    /// ```
+    pub fn apply_action(&mut self, action : Action) -> Result<GameStatus> {
+        return self.apply_searched_action(action, None);
+    }
+
+    /// Applique un coup en lui associant le nombre de positions explorées pour le trouver (voir
+    /// [`Engine::apply_action`])
+    fn apply_searched_action(&mut self, action : Action, nodes : Option<u64>) -> Result<GameStatus> {
+        self.ensure_in_progress()?;
+
+        self.start(true);
+
+        let elapsed = self.elapsed();
+
+        if self.time_control.is_some() {
+            let clock = &mut self.clocks[self.current_player.index()];
+
+            if elapsed > *clock {
+                *clock = Duration::default();
+                self.finish(GameStatus::Timeout(self.current_player));
+
+                return Ok(self.status);
+            }
+        }
+
+        let token = self.token(self.current_player);
+        let applied = match action {
+            Action::Swap => self.swapped_move(),
+            Action::Drop(column) | Action::Pop(column) => self.area.apply_action(action, token, self.variant).map(|row| (column, row, token)),
+        };
+        let (column, row, token) = match applied {
+            Ok(applied) => applied,
+            Err(e) => {
+                for observer in self.observers.iter_mut() {
+                    observer.on_invalid_move(&self.area, self.current_player, action.column().unwrap_or_default(), &e);
+                }
+
+                return Err(e);
+            }
+        };
+
+        let played = Move {
+            player: self.current_player,
+            column, row, token,
+            kind: action.kind(),
+            think_time: elapsed,
+            nodes,
+        };
+
+        self.history.push(played);
+
+        if let Some(time_control) = self.time_control {
+            let clock = &mut self.clocks[self.current_player.index()];
+
+            *clock = *clock - elapsed + time_control.increment;
+        }
+
+        self.turn_started = Some(Instant::now());
+
+        for observer in self.observers.iter_mut() {
+            observer.on_move_played(&self.area, &played);
+        }
+
+        // Un retrait peut aligner les jetons de l’adversaire
+        if let Some(&(col, row)) = self.area.winning_cells.first() {
+            let aligned = self.owner(self.area.cell(col, row)).unwrap_or(self.current_player);
+
+            self.finish(GameStatus::Won(self.objective.winner(aligned)));
+        } else if self.area.get_available_columns().is_empty() {
+            self.finish(GameStatus::Draw);
+        } else {
+            self.current_player = self.next_player(self.current_player);
+        }
+
+        return Ok(self.status);
+    }
+
+    /// Annule le dernier coup joué
+    ///
+    /// Le joueur ayant joué ce coup reprend la main, une partie gagnée ou nulle reprenant son
+    /// cours. Les pendules ne sont pas modifiées, le temps de réflexion du joueur étant de nouveau
+    /// décompté à partir de l’annulation. Les appels successifs annulent les coups du plus récent
+    /// au plus ancien.
+    ///
+    /// # Retour
+    ///
+    /// Le coup annulé
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `Timeout` - La partie est terminée, perdue au temps par le joueur donné.
+    ///  * `NothingToUndo` - Aucun coup n’a été joué dans la partie en cours.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Engine, GameStatus};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.apply_move(3).unwrap();
+    /// engine.apply_move(4).unwrap();
+    ///
+    /// assert_eq!(engine.undo().unwrap().column, 4);
+    /// assert_eq!(engine.history().len(), 1);
+    /// assert_eq!(engine.get_disposition().last_move().map(|(col, _, _)| col), Some(3));
+    /// ```
+    pub fn undo(&mut self) -> Result<Move> {
+        match self.status {
+            GameStatus::Timeout(player_id) => return Err(Error::Timeout(player_id)),
+            GameStatus::Aborted => return Err(Error::GameAlreadyFinished),
+            _ => {},
+        }
+
+        let undone = self.history.pop().ok_or(Error::NothingToUndo)?;
+
+        match undone.kind {
+            MoveKind::Drop => {
+                self.area.unset_token(undone.column)?;
+            },
+            MoveKind::Pop => self.area.push_bottom(undone.token, undone.column),
+            MoveKind::Swap => {},
+        }
+
+        // Un échange ne modifie pas la zone de jeu: le dernier coup est celui l’ayant précédé
+        self.area.last_move = self.history.iter().rev().find(|played| played.kind != MoveKind::Swap)
+            .filter(|played| played.kind == MoveKind::Drop)
+            .map(|played| (played.column, played.row, played.token));
+        self.current_player = undone.player;
+        self.status = GameStatus::InProgress;
+
+        if self.started {
+            self.turn_started = Some(Instant::now());
+        }
+
+        for observer in self.observers.iter_mut() {
+            observer.on_move_undone(&self.area, &undone);
+        }
+
+        return Ok(undone);
+    }
+
+    /// Demande au joueur courant de jouer puis applique son coup
+    ///
+    /// Si le coup donné par l’interface est illégal, le moteur applique le comportement défini
+    /// via [`Engine::set_retry_policy`].
+    ///
+    /// L’interface de chaque adversaire est prévenue du début de la réflexion du joueur courant via
+    /// [`Interface::on_opponent_thinking`] puis du coup joué via [`Interface::on_opponent_move`],
+    /// sauf si elle est encore occupée par un coup ayant dépassé la limite de temps (voir
+    /// [`MoveTimeout`]).
+    /// Lorsque la règle du gâteau le permet, l’échange des camps est d’abord proposé au joueur
+    /// courant via [`Interface::offer_swap`].
+    ///
+    /// # Retour
+    ///
+    /// L’état de la partie après le coup ou une erreur si le coup donné par l’interface est
+    /// invalide ou si l’interface n’a pas pu jouer.
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` - La partie est déjà terminée.
+    ///  * `Timeout` - La partie est déjà terminée, perdue au temps par le joueur donné.
+    ///  * `InterfaceFailure` - L’interface du joueur courant a retourné une erreur.
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise
+    ///    entre 0 et le nombre de colonnes moins un.
+    ///  * `FilledColumn` - La colonne dont il est demandé une modification est déjà remplie.
+    ///  * `EmptyColumn`, `PopNotAllowed`, `NotOwnToken` - Le retrait proposé est illégal (voir
+    ///    [`Engine::apply_action`]).
+    pub fn step(&mut self) -> Result<GameStatus> {
+        self.ensure_in_progress()?;
+
+        self.start(true);
+
+        let token = self.token(self.current_player);
+        let opponents : Vec<SharedInterface> = PlayerId::all(self.player_count())
+            .filter(|player_id| *player_id != self.current_player)
+            .map(|player_id| Arc::clone(self.interface(player_id)))
+            .collect();
+        let mut attempts = 0;
+
+        // Un adversaire encore occupé par un coup hors délai n’est pas prévenu
+        for opponent in opponents.iter() {
+            if let Some(mut opponent) = try_lock(opponent)? {
+                opponent.on_opponent_thinking(&self.area, token);
+            }
+        }
+
+        // Une interface encore occupée par un coup hors délai renonce à l’échange
+        let swap = self.swapped_move().is_ok() && match try_lock(self.interface(self.current_player))? {
+            Some(mut interface) => interface.offer_swap(&self.area, token).map_err(Error::InterfaceFailure)?,
+            None => false,
+        };
+
+        if swap {
+            return self.apply_action(Action::Swap);
+        }
+
+        loop {
+            let (action, nodes) = match self.request_move(token)? {
+                Some(requested) => requested,
+                None => return self.lose_on_time(),
+            };
+
+            attempts += 1;
+
+            let played = self.history.len();
+
+            if let Some(r) = self.apply_proposed_move(action, nodes, attempts) {
+                if self.history.len() > played {
+                    for opponent in opponents.iter() {
+                        if let Some(mut opponent) = try_lock(opponent)? {
+                            opponent.on_opponent_move(&self.area, action);
+                        }
+                    }
+                }
+
+                return r;
+            }
+        }
+    }
+
+    /// Joue le tour du joueur courant d’une partie à deux joueurs en s’adressant directement aux
+    /// joueurs donnés plutôt qu’aux interfaces enregistrées
+    ///
+    /// Les règles appliquées sont celles de [`Engine::step`]. Les joueurs étant appelés sans fil
+    /// d’exécution intermédiaire, ni la limite de temps d’un coup (voir [`MoveTimeout`]) ni la
+    /// pendule ne peuvent interrompre leur réflexion: leur dépassement est constaté une fois le
+    /// coup proposé. Utilisé par le gestionnaire générique du module
+    /// [`simulation`](crate::simulation).
+    pub(crate) fn step_with<C : Interface + ?Sized, O : Interface + ?Sized>(&mut self, current : &mut C, opponent : &mut O) -> Result<GameStatus> {
+        self.ensure_in_progress()?;
+
+        if !self.started {
+            current.on_game_start(&self.area, &self.player(self.current_player)?);
+            opponent.on_game_start(&self.area, &self.player(self.next_player(self.current_player))?);
+            self.start(false);
+        }
+
+        let token = self.token(self.current_player);
+        let mut attempts = 0;
+
+        opponent.on_opponent_thinking(&self.area, token);
+
+        if self.swapped_move().is_ok() && current.offer_swap(&self.area, token).map_err(Error::InterfaceFailure)? {
+            return self.apply_action(Action::Swap);
+        }
+
+        loop {
+            let remaining = self.remaining_time(self.current_player)?;
+
+            if remaining.is_some_and(|remaining| remaining.is_zero()) {
+                return self.lose_on_time();
+            }
+
+            let started = Instant::now();
+            let action = current.play_action(&self.area, token).map_err(Error::InterfaceFailure)?;
+            let requested = match self.move_timeout {
+                Some(timeout) if started.elapsed() > timeout.limit => self.timed_out_move(remaining),
+                _ => Some((action, current.nodes_searched())),
+            };
+            let (action, nodes) = match requested {
+                Some(requested) => requested,
+                None => return self.lose_on_time(),
+            };
+
+            attempts += 1;
+
+            let played = self.history.len();
+
+            if let Some(r) = self.apply_proposed_move(action, nodes, attempts) {
+                if self.history.len() > played {
+                    opponent.on_opponent_move(&self.area, action);
+                }
+
+                return r;
+            }
+        }
+    }
+
+    /// Joues une partie de puissance 4 avec des interfaces asynchrones
+    ///
+    /// Les interfaces données remplacent, le temps de la partie, celles enregistrées dans le
+    /// moteur. Le moteur rend la main à l’exécuteur `tokio` entre chaque coup afin de ne pas
+    /// monopoliser le fil d’exécution lors des parties entre intelligences artificielles.
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_one`: Interface asynchrone vers le premier joueur
+    ///  * `player_two`: Interface asynchrone vers le second joueur
+    ///
+    /// # Retour
+    ///
+    /// Le résultat de la partie ou une erreur dans les mêmes conditions que [`Engine::play`]. Les
+    /// parties à plus de deux joueurs donnent l’erreur `InvalidPlayerCount`.
+    #[cfg(feature = "tokio")]
+    pub async fn play_async(&mut self, player_one : &mut dyn AsyncInterface, player_two : &mut dyn AsyncInterface) -> Result<GameResult> {
+        if self.player_count() != 2 {
+            return Err(Error::InvalidPlayerCount(self.player_count()));
+        }
+
+        self.ensure_in_progress()?;
+
+        self.start(false);
+
+        loop {
+            let token = self.token(self.current_player);
+            let mut attempts = 0;
+
+            let status = loop {
+                let col = if self.current_player == PlayerId::ONE {
+                    player_one.play(&self.area, token).await
+                } else {
+                    player_two.play(&self.area, token).await
+                }.map_err(Error::InterfaceFailure)?;
+
+                attempts += 1;
+
+                if let Some(r) = self.apply_proposed_move(Action::Drop(col), None, attempts) {
+                    break r?;
+                }
+            };
+
+            if let Some(result) = self.result(status) {
+                return Ok(result);
+            }
+
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// Applique un coup proposé par une interface en suivant le comportement défini pour les coups
+    /// illégaux. Retourne `None` si l’interface doit proposer un nouveau coup.
+    fn apply_proposed_move(&mut self, action : Action, nodes : Option<u64>, attempts : usize) -> Option<Result<GameStatus>> {
+        match self.apply_searched_action(action, nodes) {
+            Err(e @ (Error::InvalidColumn | Error::FilledColumn | Error::EmptyColumn | Error::PopNotAllowed | Error::NotOwnToken | Error::SwapNotAllowed)) => match self.retry_policy {
+                RetryPolicy::Reprompt { max_attempts } => if attempts >= max_attempts {
+                    Some(Err(e))
+                } else {
+                    None
+                },
+                RetryPolicy::Forfeit => {
+                    self.finish(GameStatus::Won(self.next_player(self.current_player)));
+
+                    Some(Ok(self.status))
+                },
+                RetryPolicy::Abort => Some(Err(e)),
+            },
+            r => Some(r),
+        }
+    }
+
+    /// Fourni le nom du joueur identifié par `player_id`
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_id`: L’identifiant du joueur (compris entre 1 et le nombre de joueurs)
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    pub fn player_name(&self, player_id : PlayerId) -> Result<String> {
+        self.check_player(player_id)?;
+
+        return Ok(self.names[player_id.index()].clone());
+    }
+
+    /// Fourni l’identifiant, le nom et le jeton du joueur identifié par `player_id`
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_id`: L’identifiant du joueur (compris entre 1 et le nombre de joueurs)
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId`: L’identifiant du joueur est invalide
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Engine, PlayerId, State};
+    /// use connect_four::random_bot::RandomBot;
+    ///
+    /// let mut engine = Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+    ///
+    /// engine.set_colors(State::RedToken).unwrap();
+    ///
+    /// let player = engine.player(PlayerId::TWO).unwrap();
+    ///
+    /// assert_eq!(player.name, "Robot 2");
+    /// assert_eq!(player.token, State::YellowToken);
+    /// ```
+    pub fn player(&self, player_id : PlayerId) -> Result<Player> {
+        self.check_player(player_id)?;
+
+        return Ok(Player {
+            id: player_id,
+            name: self.names[player_id.index()].clone(),
+            token: self.token(player_id),
+        });
+    }
+
+    /// Joues une partie de puissance 4
+    ///
+    /// # Retour
+    ///
+    /// Une fois une partie finie, retourne le résultat de la partie: le gagnant, avec son jeton
+    /// et les cases alignées, ou un match nul.
+    ///
+    /// Si une erreur se produit durant la partie, retourne l’erreur via l’objet `Error`. Ces
+    /// erreurs sont émises en cas de problème avec les joueurs.
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` - La partie est déjà terminée.
+    ///  * `Timeout` - La partie est déjà terminée, perdue au temps par le joueur donné.
+    ///  * `InterfaceFailure` - L’interface d’un joueur a retourné une erreur.
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise
+    ///    entre 0 et le nombre de colonnes moins un.
+    ///  * `FilledColumn` - La colonne dont il est demandé une modification est déjà remplie.
+    pub fn play(&mut self) -> Result<GameResult> {
+        loop {
+            let status = self.step()?;
+
+            if let Some(result) = self.result(status) {
+                return Ok(result);
+            }
+        }
+    }
+
+    pub(crate) fn result(&self, status : GameStatus) -> Option<GameResult> {
+        match status {
+            GameStatus::InProgress => None,
+            GameStatus::Won(player_id) => Some(GameResult::Winner {
+                player_id,
+                token: self.token(player_id),
+                winning_line: self.area.winning_cells.clone(),
+            }),
+            GameStatus::Draw => Some(GameResult::Draw),
+            GameStatus::Timeout(player_id) => Some(GameResult::Timeout { player_id }),
+            GameStatus::Aborted => Some(GameResult::Aborted),
+        }
+    }
+
+    fn interface(&self, player_id : PlayerId) -> &SharedInterface {
+        return &self.interfaces[player_id.index()];
+    }
+
+    /// Vérifie que l’identifiant donné correspond à l’un des joueurs de la partie
+    fn check_player(&self, player_id : PlayerId) -> Result<()> {
+        if player_id.get() > self.player_count() {
+            return Err(Error::InvalidPlayerId(player_id.get()));
+        }
+
+        return Ok(());
+    }
+
+    /// Vérifie que les règles données peuvent être jouées avec le nombre de joueurs de la partie
+    fn check_two_player_rules(&self, variant : Variant, objective : Objective, swap_rule : bool) -> Result<()> {
+        if self.player_count() > 2 && (variant != Variant::Standard || objective != Objective::Connect || swap_rule) {
+            return Err(Error::InvalidPlayerCount(self.player_count()));
+        }
+
+        return Ok(());
+    }
+
+    /// Vérifie que le comportement face aux coups illégaux peut être adopté avec le nombre de
+    /// joueurs de la partie
+    fn check_retry_policy(&self, policy : RetryPolicy) -> Result<()> {
+        if self.player_count() > 2 && policy == RetryPolicy::Forfeit {
+            return Err(Error::InvalidPlayerCount(self.player_count()));
+        }
+
+        return Ok(());
+    }
+
+    /// Fourni l’identifiant du joueur jouant après le joueur donné
+    fn next_player(&self, player_id : PlayerId) -> PlayerId {
+        return player_id.next(self.player_count());
+    }
+
+    /// Vide la zone de jeu puis y place les jetons de handicap
+    fn place_handicap(&mut self) -> Result<()> {
+        self.area.clear();
+
+        return match &self.handicap {
+            Some(handicap) => self.area.set_handicap(self.token(handicap.player_id), &handicap.columns),
+            None => Ok(()),
+        };
+    }
+
+    /// Fourni l’identifiant du joueur utilisant le jeton donné, s’il participe à la partie
+    fn owner(&self, token : State) -> Option<PlayerId> {
+        return PlayerId::all(self.player_count()).find(|player_id| self.token(*player_id) == token);
+    }
+
+    /// Demande son coup au joueur courant en respectant la limite de temps définie et le temps
+    /// restant à sa pendule, accompagné du nombre de positions explorées pour le trouver. Retourne
+    /// `None` si le joueur a dépassé l’une de ces limites et doit perdre la partie.
+    fn request_move(&self, token : State) -> Result<Option<(Action, Option<u64>)>> {
+        let interface = Arc::clone(self.interface(self.current_player));
+        let search = move |interface : &mut Box<dyn Interface + Send>, area : &Area| {
+            return interface.play_action(area, token).map(|action| (action, interface.nodes_searched()));
+        };
+
+        // Le temps restant à la pendule du joueur borne également sa réflexion
+        let remaining = self.remaining_time(self.current_player)?;
+        let limit = match (self.move_timeout, remaining) {
+            (_, Some(remaining)) if remaining.is_zero() => return Ok(None),
+            (Some(timeout), Some(remaining)) => timeout.limit.min(remaining),
+            (Some(timeout), None) => timeout.limit,
+            (None, Some(remaining)) => remaining,
+            (None, None) => return search(&mut *lock(&interface)?, &self.area).map(Some).map_err(Error::InterfaceFailure),
+        };
+
+        let area = self.area.clone();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            if let Ok(mut interface) = lock(&interface) {
+                let _ = sender.send(search(&mut interface, &area));
+            }
+        });
+
+        match receiver.recv_timeout(limit) {
+            Ok(r) => r.map(Some).map_err(Error::InterfaceFailure),
+            // Seul le dépassement de la limite du coup, et non celui de la pendule, permet de jouer à
+            // la place du joueur
+            Err(mpsc::RecvTimeoutError::Timeout) => Ok(self.timed_out_move(remaining)),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(panicked_interface()),
+        }
+    }
+
+    /// Fourni le coup joué à la place du joueur courant ayant dépassé la limite de temps d’un coup,
+    /// ou `None` s’il doit perdre la partie
+    ///
+    /// Seul le dépassement de la limite du coup, et non celui de la pendule dont il restait
+    /// `remaining` au joueur, permet de jouer à sa place.
+    fn timed_out_move(&self, remaining : Option<Duration>) -> Option<(Action, Option<u64>)> {
+        return match self.move_timeout {
+            Some(MoveTimeout { limit, action: TimeoutAction::Fallback }) if remaining.is_none_or(|remaining| limit < remaining) => {
+                let center = self.area.config.cols / 2;
+
+                self.area.get_available_columns().into_iter().min_by_key(|col| (*col as isize - center as isize).abs()).map(|col| (Action::Drop(col), None))
+            },
+            _ => None,
+        };
+    }
+
+    /// Termine la partie par la défaite au temps du joueur courant
+    fn lose_on_time(&mut self) -> Result<GameStatus> {
+        if self.remaining_time(self.current_player)?.is_some_and(|remaining| remaining.is_zero()) {
+            self.clocks[self.current_player.index()] = Duration::default();
+        }
+
+        self.finish(GameStatus::Timeout(self.current_player));
+
+        return Ok(self.status);
+    }
+
+    /// Vérifie que la partie est en cours, une partie perdue au temps donnant l’erreur `Timeout`
+    fn ensure_in_progress(&self) -> Result<()> {
+        match self.status {
+            GameStatus::InProgress => Ok(()),
+            GameStatus::Timeout(player_id) => Err(Error::Timeout(player_id)),
+            _ => Err(Error::GameAlreadyFinished),
+        }
+    }
+
+    fn reset_clocks(&mut self) {
+        let initial = self.time_control.map(|t| t.initial).unwrap_or_default();
+
+        self.clocks = vec![initial; self.player_count()];
+        self.turn_started = None;
+    }
+
+    fn elapsed(&self) -> Duration {
+        return self.turn_started.map(|t| t.elapsed()).unwrap_or_default();
+    }
+
+    /// Présente à chaque interface le joueur qu’elle représente (voir
+    /// [`Interface::on_game_start`])
+    fn introduce_players(&self) -> Result<()> {
+        for player_id in PlayerId::all(self.player_count()) {
+            let player = self.player(player_id)?;
+
+            if let Some(mut interface) = try_lock(self.interface(player_id))? {
+                interface.on_game_start(&self.area, &player);
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Démarre la partie si ce n’est pas déjà fait, en présentant leur joueur aux interfaces
+    /// enregistrées si `introduce` est vrai
+    fn start(&mut self, introduce : bool) {
+        if !self.started {
+            self.started = true;
+            self.turn_started = Some(Instant::now());
+
+            // Une interface ayant paniqué est signalée lorsqu’elle doit jouer
+            if introduce {
+                let _ = self.introduce_players();
+            }
+
+            for observer in self.observers.iter_mut() {
+                observer.on_game_start(&self.area);
+            }
+        }
+    }
+
+    fn finish(&mut self, status : GameStatus) {
+        self.status = status;
+
+        if let Some(result) = self.result(status) {
+            for observer in self.observers.iter_mut() {
+                observer.on_game_end(&self.area, &result);
+            }
+        }
+    }
+
+    fn restart(&mut self) {
+        self.current_player = self.first_player;
+        self.status = GameStatus::InProgress;
+        self.history.clear();
+        self.started = false;
+        self.reset_clocks();
+    }
+
+    /// Vérifie qu’un échange des camps est possible puis fourni la case et le jeton du premier
+    /// coup, désormais joué par le joueur courant
+    fn swapped_move(&self) -> Result<(usize, usize, State)> {
+        return match self.history.as_slice() {
+            [first] if self.swap_rule && self.handicap.is_none() && first.kind == MoveKind::Drop => Ok((first.column, first.row, first.token)),
+            _ => Err(Error::SwapNotAllowed),
+        };
+    }
+
+    /// Fourni le jeton du joueur donné, les jetons des deux premiers joueurs étant échangés après
+    /// un échange des camps
+    fn token(&self, player_id : PlayerId) -> State {
+        let swapped = self.history.get(1).is_some_and(|played| played.kind == MoveKind::Swap);
+
+        return player_token(if swapped { other_token(self.player_one_token) } else { self.player_one_token }, player_id);
+    }
+}
+
+impl GameRecord {
+    /// Fourni les coups de la partie, du premier au dernier
+    pub fn actions(&self) -> impl Iterator<Item = Action> + '_ {
+        return self.moves.iter().enumerate().map(move |(i, column)| if self.swaps.contains(&i) {
+            Action::Swap
+        } else if self.pops.contains(&i) {
+            Action::Pop(*column)
+        } else {
+            Action::Drop(*column)
+        });
+    }
+
+    /// Écrit la partie dans la notation textuelle compacte
+    ///
+    /// Chaque coup est noté par le numéro de sa colonne en partant de 1 (`"4453321"`). Au-delà de
+    /// la neuvième colonne, les lettres sont utilisées (`a` pour la dixième, `b` pour la onzième,
+    /// etc.). Les retraits de la variante Pop Out sont précédés d’un tiret (`"44-4"`) et l’échange
+    /// des camps de la règle du gâteau est noté par une étoile (`"4*3"`).
+    ///
+    /// # Retour
+    ///
+    /// La notation de la partie
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’une des colonnes jouées ne peut pas être notée (au-delà de la 35ème).
+    pub fn to_notation(&self) -> Result<String> {
+        let mut notation = String::with_capacity(self.moves.len());
+
+        for action in self.actions() {
+            let column = match action {
+                Action::Drop(column) => column,
+                Action::Pop(column) => {
+                    notation.push('-');
+
+                    column
+                },
+                Action::Swap => {
+                    notation.push('*');
+
+                    continue;
+                },
+            };
+
+            notation.push(std::char::from_digit(column as u32 + 1, 36).ok_or(Error::InvalidColumn)?);
+        }
+
+        return Ok(notation);
+    }
+
+    /// Lit une partie écrite dans la notation textuelle compacte
+    ///
+    /// Les espaces sont ignorés. La partie est rejouée afin de vérifier la validité de chaque coup,
+    /// le premier joueur jouant les jetons jaunes. Une partie contenant des retraits est
+    /// considérée comme jouée dans la variante Pop Out, une partie contenant un échange des camps
+    /// avec la règle du gâteau.
+    ///
+    /// # Arguments
+    ///
+    ///  * `notation`: La notation de la partie (voir [`GameRecord::to_notation`])
+    ///  * `config`: La configuration de la zone de jeu sur laquelle la partie a été jouée
+    ///
+    /// # Retour
+    ///
+    /// L’enregistrement de la partie
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    ///  * `InvalidColumn` - Un caractère ne correspond à aucune colonne de la zone de jeu.
+    ///  * `FilledColumn` - Un coup est joué dans une colonne déjà remplie.
+    ///  * `EmptyColumn`, `NotOwnToken` - Un retrait est illégal.
+    ///  * `SwapNotAllowed` - Un échange des camps ne suit pas le premier coup.
+    ///  * `GameAlreadyFinished` - Des coups sont joués après la fin de la partie.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{AreaConfig, GameRecord};
+    ///
+    /// let record = GameRecord::from_notation("4453321", AreaConfig::default()).unwrap();
+    ///
+    /// assert_eq!(record.moves, vec![3, 3, 4, 2, 2, 1, 0]);
+    /// assert_eq!(record.to_notation().unwrap(), "4453321");
+    ///
+    /// let record = GameRecord::from_notation("44-4", AreaConfig::default()).unwrap();
+    ///
+    /// assert_eq!(record.pops, vec![2]);
+    /// assert_eq!(record.to_notation().unwrap(), "44-4");
+    ///
+    /// let record = GameRecord::from_notation("4*3", AreaConfig::default()).unwrap();
+    ///
+    /// assert_eq!(record.moves, vec![3, 3, 2]);
+    /// assert_eq!(record.swaps, vec![1]);
+    /// assert_eq!(record.to_notation().unwrap(), "4*3");
+    /// ```
+    pub fn from_notation(notation : &str, config : AreaConfig) -> Result<Self> {
+        let (moves, pops, swaps) = Self::parse_moves(notation)?;
+        let variant = if pops.is_empty() { Variant::Standard } else { Variant::PopOut };
+        let record = Self {
+            config,
+            first_player: PlayerId::ONE,
+            player_one_token: State::YellowToken,
+            moves, variant, pops,
+            objective: Objective::Connect,
+            players: 2,
+            handicap: None,
+            swap_rule: !swaps.is_empty(),
+            swaps,
+            think_times: Vec::new(),
+            nodes: Vec::new(),
+        };
+
+        record.positions()?;
+
+        return Ok(record);
+    }
+
+    /// Lit les coups d’une notation textuelle compacte sans les rejouer
+    ///
+    /// Contrairement à [`GameRecord::from_notation`], les coups ne sont pas vérifiés: la notation
+    /// peut ainsi décrire la suite d’une partie.
+    ///
+    /// # Arguments
+    ///
+    ///  * `notation`: La notation des coups (voir [`GameRecord::to_notation`])
+    ///
+    /// # Retour
+    ///
+    /// Les colonnes jouées, numérotées à partir de 0, ainsi que les indices des retraits et des
+    /// échanges des camps, la colonne d’un échange étant celle du coup précédent
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - Un caractère ne correspond à aucune colonne.
+    ///  * `SwapNotAllowed` - Un échange des camps ne suit aucun coup.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::GameRecord;
+    ///
+    /// assert_eq!(GameRecord::parse_moves("44-a").unwrap(), (vec![3, 3, 9], vec![2], vec![]));
+    /// ```
+    pub fn parse_moves(notation : &str) -> Result<(Vec<usize>, Vec<usize>, Vec<usize>)> {
+        let (mut moves, mut pops, mut swaps) = (Vec::new(), Vec::new(), Vec::new());
+        let mut pop = false;
+
+        for c in notation.chars().filter(|c| !c.is_whitespace()) {
+            if c == '-' && !pop {
+                pop = true;
+
+                continue;
+            }
+
+            if c == '*' && !pop {
+                let previous = *moves.last().ok_or(Error::SwapNotAllowed)?;
+
+                swaps.push(moves.len());
+                moves.push(previous);
+
+                continue;
+            }
+
+            match c.to_digit(36) {
+                Some(d) if d > 0 => moves.push(d as usize - 1),
+                _ => return Err(Error::InvalidColumn),
+            }
+
+            if pop {
+                pops.push(moves.len() - 1);
+                pop = false;
+            }
+        }
+
+        if pop {
+            return Err(Error::InvalidColumn);
+        }
+
+        return Ok((moves, pops, swaps));
+    }
+
+    /// Rejoue la partie coup par coup
+    ///
+    /// # Retour
+    ///
+    /// La zone de jeu avant le premier coup, avec les éventuels jetons de handicap, puis après
+    /// chacun des coups de la partie
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - La configuration de l’enregistrement est invalide.
+    ///  * `InvalidPlayerCount` - Le nombre de joueurs n’est pas compris entre 2 et 4.
+    ///  * `InvalidPlayerId` - L’identifiant du premier joueur est invalide.
+    ///  * `NotAToken` - Le jeton du premier joueur n’est ni jaune ni rouge.
+    ///  * `InvalidColumn`, `FilledColumn`, `EmptyColumn`, `PopNotAllowed`, `NotOwnToken`,
+    ///    `SwapNotAllowed`, `GameAlreadyFinished` - L’un des coups enregistrés est illégal.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{AreaConfig, GameRecord};
+    ///
+    /// let record = GameRecord::from_notation("1212121", AreaConfig::default()).unwrap();
+    /// let positions = record.positions().unwrap();
+    ///
+    /// assert_eq!(positions.len(), 8);
+    /// assert!(positions[6].winning_cells().is_empty());
+    /// assert_eq!(positions[7].winning_cells().len(), 4);
+    /// ```
+    pub fn positions(&self) -> Result<Vec<Area>> {
+        if self.players < 2 || self.players > MAX_PLAYERS {
+            return Err(Error::InvalidPlayerCount(self.players));
+        }
+
+        if self.first_player.get() > self.players {
+            return Err(Error::InvalidPlayerId(self.first_player.get()));
+        }
+
+        if !is_player_one_token(self.player_one_token) {
+            return Err(Error::NotAToken);
+        }
+
+        let mut area = Area::with_config(self.config)?;
+        let mut player_id = self.first_player;
+
+        if let Some(handicap) = &self.handicap {
+            if handicap.player_id.get() > self.players {
+                return Err(Error::InvalidPlayerId(handicap.player_id.get()));
+            }
+
+            area.set_handicap(player_token(self.player_one_token, handicap.player_id), &handicap.columns)?;
+        }
+
+        let mut positions = Vec::with_capacity(self.moves.len() + 1);
+        let mut player_one_token = self.player_one_token;
+
+        positions.push(area.clone());
+
+        for (i, action) in self.actions().enumerate() {
+            if !area.winning_cells.is_empty() || area.get_available_columns().is_empty() {
+                return Err(Error::GameAlreadyFinished);
+            }
+
+            if action == Action::Swap {
+                // Seul le second coup d’une partie à deux joueurs sans handicap peut être un échange
+                if !self.swap_rule || i != 1 || self.players != 2 || self.handicap.is_some() {
+                    return Err(Error::SwapNotAllowed);
+                }
+
+                player_one_token = other_token(player_one_token);
+            } else {
+                area.apply_action(action, player_token(player_one_token, player_id), self.variant)?;
+            }
+
+            positions.push(area.clone());
+
+            player_id = player_id.next(self.players);
+        }
+
+        return Ok(positions);
+    }
+
+    /// Décrit chaque position de la partie par un vecteur de caractéristiques (voir
+    /// [`Area::features`])
+    ///
+    /// Permet de constituer des jeux d’apprentissage à partir des parties archivées, le résultat de
+    /// la partie servant d’étiquette.
+    ///
+    /// # Retour
+    ///
+    /// Un vecteur par position donnée par [`GameRecord::positions`], de la position initiale à la
+    /// position finale
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Celles de [`GameRecord::positions`]
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{AreaConfig, GameRecord};
+    ///
+    /// let record = GameRecord::from_notation("1212121", AreaConfig::default()).unwrap();
+    /// let features = record.features().unwrap();
+    ///
+    /// assert_eq!(features.len(), 8);
+    /// assert!(features.iter().all(|f| f.len() == record.config.feature_count()));
+    /// assert_eq!(features[7].last(), Some(&8.0));
+    /// ```
+    pub fn features(&self) -> Result<Vec<Vec<f32>>> {
+        return Ok(self.positions()?.iter().map(Area::features).collect());
+    }
+
+    /// Vérifie l’enregistrement en rejouant la partie dans un nouveau gestionnaire de jeux
+    ///
+    /// La zone de jeu finale doit être identique à celle obtenue par [`GameRecord::positions`]
+    /// et l’état de la partie rejouée doit correspondre à celui donné. Une partie perdue au temps
+    /// ou par abandon à la suite d’un coup illégal (voir [`RetryPolicy::Forfeit`]) doit être
+    /// inachevée, le joueur devant jouer étant le perdant. Une partie interrompue doit également
+    /// être inachevée.
+    ///
+    /// # Arguments
+    ///
+    ///  * `status` : L’état attendu de la partie une fois tous les coups rejoués
+    ///
+    /// # Retour
+    ///
+    /// La zone de jeu finale
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `RecordMismatch` - La partie rejouée ne correspond pas à l’état donné.
+    ///  * Toute erreur de [`GameRecord::positions`] ou de [`Engine::load_record`] si
+    ///    l’enregistrement est invalide.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{AreaConfig, Error, GameRecord, GameStatus, PlayerId};
+    ///
+    /// let record = GameRecord::from_notation("1212121", AreaConfig::default()).unwrap();
+    ///
+    /// assert_eq!(record.verify(GameStatus::Won(PlayerId::ONE)).unwrap().winning_cells().len(), 4);
+    /// assert!(matches!(record.verify(GameStatus::Draw), Err(Error::RecordMismatch)));
+    ///
+    /// let unfinished = GameRecord::from_notation("121212", AreaConfig::default()).unwrap();
+    ///
+    /// assert!(unfinished.verify(GameStatus::Timeout(PlayerId::ONE)).is_ok());
+    /// assert!(matches!(unfinished.verify(GameStatus::Timeout(PlayerId::TWO)), Err(Error::RecordMismatch)));
+    /// ```
+    pub fn verify(&self, status : GameStatus) -> Result<Area> {
+        let positions = self.positions()?;
+        let interfaces = (0..self.players).map(|_| Box::new(Replay) as Box<dyn Interface + Send>).collect();
+        let mut engine = Engine::with_players(interfaces, self.config)?;
+        let replayed = engine.load_record(self)?;
+        let area = engine.get_disposition();
+        let unfinished = replayed == GameStatus::InProgress;
+
+        let consistent = match status {
+            GameStatus::Timeout(player_id) => unfinished && engine.current_player() == player_id,
+            GameStatus::Won(player_id) if unfinished => engine.next_player(engine.current_player()) == player_id,
+            GameStatus::Aborted => unfinished,
+            _ => replayed == status,
+        };
+
+        if !consistent || positions.last() != Some(area) {
+            return Err(Error::RecordMismatch);
+        }
+
+        return Ok(area.clone());
+    }
+}
+
+/// Interface ne jouant aucun coup, utilisée pour rejouer les parties enregistrées
+struct Replay;
+
+impl Interface for Replay {
+    fn play(&mut self, _area : &Area, _token : State) -> std::result::Result<usize, PlayError> {
+        return Err(PlayError::Other(String::from("Partie rejouée")));
+    }
+
+    fn name(&self) -> String {
+        return String::new();
+    }
+}
+
+/// Représentation sérialisée d’une zone de jeu, indépendante de son stockage interne
+///
+/// Chaque colonne est donnée par la liste de ses jetons en partant du bas.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct AreaRepr {
+    config : AreaConfig,
+    columns : Vec<Vec<State>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    handicap : Option<(State, usize)>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Area> for AreaRepr {
+    fn from(area : Area) -> Self {
+        let columns = (0..area.config.cols).map(|col| {
+            (0..area.config.rows).map(|row| area.cell(col, row)).take_while(|c| *c != State::NoToken).collect()
+        }).collect();
+
+        return Self { config: area.config, columns, handicap: area.handicap };
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<AreaRepr> for Area {
+    type Error = String;
+
+    fn try_from(repr : AreaRepr) -> std::result::Result<Self, Self::Error> {
+        let mut area = Area::with_config(repr.config).map_err(|e| e.to_string())?;
+
+        if repr.columns.len() != repr.config.cols {
+            return Err(format!("{} colonnes attendues, {} données", repr.config.cols, repr.columns.len()));
+        }
+
+        for (col, tokens) in repr.columns.iter().enumerate() {
+            if tokens.len() > repr.config.rows {
+                return Err(format!("La colonne {} contient plus de {} jetons", col, repr.config.rows));
+            }
+
+            for (row, token) in tokens.iter().enumerate() {
+                if *token == State::NoToken {
+                    return Err(Error::NotAToken.to_string());
+                }
+
+                area.write_cell(col, row, *token);
+            }
+        }
+
+        area.handicap = repr.handicap;
+        // La variante n’étant pas sérialisée, les positions de la variante Pop Out sont acceptées
+        area.validate_variant(Variant::PopOut).map_err(|e| e.to_string())?;
+        area.winning_cells = area.find_winner().map(|(_, cells)| cells).unwrap_or_default();
+
+        return Ok(area);
+    }
+}
+
+/// Encode des octets en base64 sûr pour les URL, sans remplissage
+fn base64url_encode(bytes : &[u8]) -> String {
+    let mut code = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |acc, (i, b)| acc | (*b as u32) << (16 - 8 * i));
+
+        for i in 0..=chunk.len() {
+            code.push(BASE64URL_ALPHABET[(group >> (18 - 6 * i) & 0x3F) as usize] as char);
+        }
+    }
+
+    return code;
+}
+
+/// Décode une chaîne encodée en base64 sûr pour les URL, sans remplissage
+fn base64url_decode(code : &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(code.len() * 3 / 4);
+
+    for chunk in code.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+
+        let mut group = 0u32;
+
+        for (i, c) in chunk.iter().enumerate() {
+            group |= (BASE64URL_ALPHABET.iter().position(|a| a == c)? as u32) << (18 - 6 * i);
+        }
+
+        for i in 0..chunk.len() - 1 {
+            bytes.push((group >> (16 - 8 * i)) as u8);
+        }
+    }
+
+    return Some(bytes);
+}
+
+/// Calcule la clé de Zobrist des jetons d’un bitboard, `color` valant 0 pour les jetons rouges et 1
+/// pour les jetons jaunes
+fn zobrist_key(mut board : u64, color : usize) -> u64 {
+    let mut key = 0;
+
+    while board != 0 {
+        key ^= ZOBRIST_KEYS[color][board.trailing_zeros() as usize];
+        board &= board - 1;
+    }
+
+    return key;
+}
+
+/// Génère les clés de Zobrist à partir d’un générateur pseudo-aléatoire déterministe (splitmix64),
+/// les clés restant ainsi identiques d’une exécution à l’autre
+const fn zobrist_keys() -> [[u64; 64]; 4] {
+    let mut keys = [[0u64; 64]; 4];
+    let mut state : u64 = 0;
+    let mut i = 0;
+
+    while i < 256 {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = state;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        keys[i / 64][i % 64] = z ^ (z >> 31);
+        i += 1;
+    }
+
+    return keys;
+}
+
+/// Fourni le jeton du joueur identifié par `player_id`, le premier joueur utilisant
+/// `player_one_token` et le second l’autre jeton parmi les jaunes et les rouges. Les joueurs
+/// suivants utilisent les jetons verts puis bleus.
+fn player_token(player_one_token : State, player_id : PlayerId) -> State {
+    return match player_id {
+        PlayerId::ONE => player_one_token,
+        PlayerId::TWO => other_token(player_one_token),
+        _ => State::TOKENS[player_id.index()],
+    };
+}
+
+/// Fourni le jeton jaune ou rouge n’étant pas celui donné
+fn other_token(token : State) -> State {
+    return if token == State::YellowToken { State::RedToken } else { State::YellowToken };
+}
+
+/// Indique si le jeton donné peut être celui du premier joueur (jaune ou rouge)
+fn is_player_one_token(token : State) -> bool {
+    return token == State::YellowToken || token == State::RedToken;
+}
+
+/// Indique si aucune case n’est bloquée
+#[cfg(feature = "serde")]
+fn is_zero(blocked : &u64) -> bool {
+    return *blocked == 0;
+}
+
+/// Nombre de joueurs des enregistrements n’en précisant pas
+#[cfg(feature = "serde")]
+fn two_players() -> usize {
+    return 2;
+}
+
+/// Indique si l’enregistrement concerne une partie à deux joueurs
+#[cfg(feature = "serde")]
+fn is_two_players(players : &usize) -> bool {
+    return *players == 2;
+}
+
+/// Verrouille une interface partagée, une interface ayant paniqué étant considérée comme défaillante
+fn lock(interface : &SharedInterface) -> Result<std::sync::MutexGuard<'_, Box<dyn Interface + Send>>> {
+    return interface.lock().map_err(|_| panicked_interface());
+}
+
+/// Verrouille une interface partagée sans attendre
+///
+/// Une interface ayant dépassé la limite de temps d’un coup reste verrouillée par le fil
+/// d’exécution chargé de la faire jouer jusqu’à la fin de son calcul: le moteur ne doit pas
+/// l’attendre pour la prévenir du déroulement de la partie.
+///
+/// # Retour
+///
+/// L’interface verrouillée ou `None` si elle est occupée
+fn try_lock(interface : &SharedInterface) -> Result<Option<std::sync::MutexGuard<'_, Box<dyn Interface + Send>>>> {
+    return match interface.try_lock() {
+        Ok(interface) => Ok(Some(interface)),
+        Err(std::sync::TryLockError::WouldBlock) => Ok(None),
+        Err(std::sync::TryLockError::Poisoned(_)) => Err(panicked_interface()),
+    };
+}
+
+fn panicked_interface() -> Error {
+    return Error::InterfaceFailure(PlayError::Other(String::from("L’interface du joueur a paniqué")));
+}
+
+impl Default for AreaConfig {
+    fn default() -> Self {
+        Self {
+            rows: AREA_ROWS,
+            cols: AREA_COLS,
+            win_length: VICTORY_NUMBER,
+            wrap_columns: false,
+            blocked: 0,
+            rest_on_blocked: false,
+        }
+    }
+}
+
+impl AreaConfig {
+    /// Crée une nouvelle configuration de zone de jeu
+    ///
+    /// # Arguments
+    ///
+    ///  * `cols` : Le nombre de colonnes du plateau
+    ///  * `rows` : Le nombre de lignes du plateau
+    ///  * `win_length` : Le nombre de jetons à aligner pour gagner
+    ///
+    /// # Retour
+    ///
+    /// La configuration, dont les colonnes ne sont pas reliées, ou une erreur si l’une des valeurs
+    /// est nulle ou si la zone de jeu est trop grande.
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    pub fn new(cols : usize, rows : usize, win_length : usize) -> Result<Self> {
+        let config = Self { rows, cols, win_length, ..Self::default() };
+
+        config.validate()?;
+
+        return Ok(config);
+    }
+
+    /// Vérifie que la configuration permet de construire une zone de jeu
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - L’une des dimensions ou le nombre de jetons à aligner est nul, la zone
+    ///    de jeu est trop grande, le plateau est cylindrique et le nombre de jetons à aligner
+    ///    dépasse le nombre de colonnes ou les cases bloquées sortent de la zone de jeu ou la
+    ///    recouvrent entièrement.
+    pub fn validate(&self) -> Result<()> {
+        if self.rows == 0 || self.cols == 0 || self.win_length == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        if self.wrap_columns && self.win_length > self.cols {
+            return Err(Error::InvalidConfig);
+        }
+
+        match self.cols.checked_mul(self.rows + 1) {
+            Some(cells) if cells <= 64 => {},
+            _ => return Err(Error::InvalidConfig),
+        }
+
+        let board = self.board_cells();
+
+        if self.blocked & !board != 0 || self.blocked_cells() == board {
+            return Err(Error::InvalidConfig);
+        }
+
+        return Ok(());
+    }
+
+    /// Bloque une case de la zone de jeu
+    ///
+    /// # Arguments
+    ///
+    ///  * `col` : La colonne de la case, comprise entre 0 et le nombre de colonnes moins un
+    ///  * `row` : La ligne de la case en partant du bas, comprise entre 0 et le nombre de lignes
+    ///    moins un
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    ///  * `InvalidRow` - L’identifiant de la ligne est invalide.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, AreaConfig, State};
+    ///
+    /// let mut config = AreaConfig::default();
+    ///
+    /// config.block(3, 1).unwrap();
+    ///
+    /// let mut area = Area::with_config(config).unwrap();
+    ///
+    /// // Le second jeton passe à côté de la case bloquée
+    /// area.set_token(State::YellowToken, 3).unwrap();
+    /// assert_eq!(area.column_height(3).unwrap(), 2);
+    ///
+    /// // Avec `rest_on_blocked`, le premier jeton repose sur la case bloquée
+    /// let config = AreaConfig { rest_on_blocked: true, ..config };
+    /// let mut area = Area::with_config(config).unwrap();
+    ///
+    /// area.set_token(State::YellowToken, 3).unwrap();
+    /// assert_eq!(area.last_move(), Some((3, 2, State::YellowToken)));
+    /// assert!(config.is_blocked(3, 0));
+    /// ```
+    pub fn block(&mut self, col : usize, row : usize) -> Result<()> {
+        if col >= self.cols {
+            return Err(Error::InvalidColumn);
+        }
+
+        if row >= self.rows {
+            return Err(Error::InvalidRow);
+        }
+
+        self.blocked |= 1 << (col * (self.rows + 1) + row);
+
+        return Ok(());
+    }
+
+    /// Indique si une case est bloquée, y compris sous une case bloquée lorsque les jetons
+    /// reposent sur ces dernières (voir [`AreaConfig::rest_on_blocked`])
+    ///
+    /// # Arguments
+    ///
+    ///  * `col` : La colonne de la case
+    ///  * `row` : La ligne de la case en partant du bas
+    pub fn is_blocked(&self, col : usize, row : usize) -> bool {
+        return col < self.cols && row < self.rows && self.blocked_cells() & 1 << (col * (self.rows + 1) + row) != 0;
+    }
+
+    /// Fourni la taille des vecteurs de caractéristiques des zones de jeu de cette configuration
+    /// (voir [`Area::features`])
+    pub fn feature_count(&self) -> usize {
+        return 2 * self.cols * self.rows + 7;
+    }
+
+    /// Fourni l’ensemble des cases bloquées, complété des cases situées sous une case bloquée
+    /// lorsque les jetons reposent sur ces dernières
+    pub(crate) fn blocked_cells(&self) -> u64 {
+        if !self.rest_on_blocked {
+            return self.blocked;
+        }
+
+        let height = self.rows + 1;
+
+        return (0..self.cols).fold(0, |acc, col| {
+            let column = (self.blocked >> (col * height)) & (u64::MAX >> (64 - height));
+
+            return match column {
+                0 => acc,
+                _ => acc | (u64::MAX >> column.leading_zeros()) << (col * height),
+            };
+        });
+    }
+
+    /// Fourni l’ensemble des cases de la zone de jeu, hors ligne supplémentaire de chaque colonne
+    fn board_cells(&self) -> u64 {
+        let column = u64::MAX >> (64 - self.rows);
+
+        return (0..self.cols).fold(0, |acc, col| acc | column << (col * (self.rows + 1)));
+    }
+
+    /// Décale un bitboard de `offset` bits vers les bits de poids faible (ou fort si `offset` est
+    /// négatif). Sur un plateau cylindrique, le décalage est circulaire afin que les cases de la
+    /// dernière colonne précèdent celles de la première.
+    pub(crate) fn shift(&self, board : u64, offset : isize) -> u64 {
+        if !self.wrap_columns {
+            return if offset.unsigned_abs() >= 64 {
+                0
+            } else if offset > 0 {
+                board >> offset
+            } else {
+                board << -offset
+            };
+        }
+
+        let cells = self.cols * (self.rows + 1);
+        let offset = offset.rem_euclid(cells as isize) as usize;
+
+        if offset == 0 {
+            return board;
+        }
+
+        return (board >> offset | board << (cells - offset)) & (u64::MAX >> (64 - cells));
+    }
+}
+
+impl Default for Area {
+    fn default() -> Self {
+        Self::with_config(AreaConfig::default()).unwrap()
+    }
+}
+
+impl Area {
+    /// Crée une zone de jeu vide aux dimensions classiques (voir [`AreaConfig::default`])
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crée une zone de jeu vide suivant la configuration donnée
+    ///
+    /// # Arguments
+    ///
+    ///  * `config` : Les dimensions du plateau et le nombre de jetons à aligner
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - L’une des dimensions ou le nombre de jetons à aligner est nul ou la zone de jeu est trop grande.
+    pub fn with_config(config : AreaConfig) -> Result<Self> {
+        config.validate()?;
+
+        Ok(Self {
+            config,
+            red: 0,
+            yellow: 0,
+            green: 0,
+            blue: 0,
+            winning_cells: Vec::new(),
+            last_move: None,
+            zobrist: 0,
+            handicap: None,
+        })
+    }
+
+    /// Fourni la configuration de la zone de jeu
+    pub fn config(&self) -> &AreaConfig {
+        return &self.config;
+    }
+
+    /// Modifie le nombre de jetons à aligner afin de gagner
+    ///
+    /// Seules les vérifications des coups suivants tiennent compte de la nouvelle valeur.
+    ///
+    /// # Arguments
+    ///
+    ///  * `win_length` : Le nombre de jetons à aligner
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - Le nombre de jetons à aligner est nul.
+    pub fn set_win_length(&mut self, win_length : usize) -> Result<()> {
+        let config = AreaConfig { win_length, ..self.config };
+
+        config.validate()?;
+        self.config = config;
+
+        return Ok(());
+    }
+
+    /// Parcourt l’ensemble des cases de la zone de jeu
+    ///
+    /// Les cases sont parcourues colonne par colonne, de gauche à droite, puis de bas en haut.
+    ///
+    /// # Retour
+    ///
+    /// Un itérateur donnant la position `(colonne, ligne)` de chaque case ainsi que son état
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), State)> + '_ {
+        let (cols, rows) = (self.config.cols, self.config.rows);
+
+        return (0..cols).flat_map(move |col| (0..rows).map(move |row| ((col, row), self.cell(col, row))));
+    }
+
+    /// Parcourt les cases d’une colonne, de bas en haut
+    ///
+    /// # Arguments
+    ///
+    ///  * `column` : La colonne à parcourir, doit être comprise entre 0 et le nombre de colonnes
+    ///    moins un
+    ///
+    /// # Retour
+    ///
+    /// Un itérateur donnant la position `(colonne, ligne)` de chaque case de la colonne ainsi que
+    /// son état
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    pub fn iter_column(&self, column : usize) -> Result<impl Iterator<Item = ((usize, usize), State)> + '_> {
+        if column >= self.config.cols {
+            return Err(Error::InvalidColumn);
+        }
+
+        return Ok((0..self.config.rows).map(move |row| ((column, row), self.cell(column, row))));
+    }
+
+    /// Parcourt les cases d’une ligne, de gauche à droite
+    ///
+    /// # Arguments
+    ///
+    ///  * `row` : La ligne à parcourir, la ligne 0 étant celle du bas. Doit être comprise entre 0
+    ///    et le nombre de lignes moins un
+    ///
+    /// # Retour
+    ///
+    /// Un itérateur donnant la position `(colonne, ligne)` de chaque case de la ligne ainsi que
+    /// son état
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidRow` - L’identifiant de la ligne est invalide.
+    pub fn iter_row(&self, row : usize) -> Result<impl Iterator<Item = ((usize, usize), State)> + '_> {
+        if row >= self.config.rows {
+            return Err(Error::InvalidRow);
+        }
+
+        return Ok((0..self.config.cols).map(move |col| ((col, row), self.cell(col, row))));
+    }
+
+    /// Retire tous les jetons de la zone de jeu
+    pub fn clear(&mut self) {
+        self.red = 0;
+        self.yellow = 0;
+        self.green = 0;
+        self.blue = 0;
+        self.zobrist = 0;
+        self.handicap = None;
+        self.winning_cells.clear();
+        self.last_move = None;
+    }
+
+    /// Énumère les coups possibles ainsi que la case où tombera chaque jeton
+    ///
+    /// Les cases bloquées sont prises en compte: le jeton tombe sur la première case libre au-dessus
+    /// du dernier jeton ou de la dernière case bloquée (voir [`Area::column_height`]).
+    ///
+    /// # Retour
+    ///
+    /// Un itérateur sur les couples `(colonne, ligne)` des colonnes non remplies, par ordre
+    /// croissant de colonne, la ligne partant du bas
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, AreaConfig, State};
+    ///
+    /// let mut area = Area::with_config(AreaConfig::new(3, 2, 2).unwrap()).unwrap();
+    ///
+    /// area.set_token(State::YellowToken, 0).unwrap();
+    /// area.set_token(State::RedToken, 0).unwrap();
+    /// area.set_token(State::YellowToken, 2).unwrap();
+    ///
+    /// assert_eq!(area.legal_moves().collect::<Vec<_>>(), vec![(1, 0), (2, 1)]);
+    /// ```
+    pub fn legal_moves(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let rows = self.config.rows;
+
+        return (0..self.config.cols)
+            .map(move |col| (col, self.column_height(col).unwrap()))
+            .filter(move |(_, row)| *row < rows);
+    }
+
+    /// Récupère la liste des colonnes où on peut ajouter des jetons
+    ///
+    /// Les cases où tombent les jetons sont fournies par [`Area::legal_moves`].
+    ///
+    /// # Retour
+    ///
+    /// La liste des colonnes non remplies
+    pub fn get_available_columns(&self) -> Vec<usize> {
+        return self.legal_moves().map(|(col, _)| col).collect();
+    }
+
+    /// Vérifie si la colonne demandée est remplie
+    ///
+    /// # Arguments
+    ///
+    ///  * `column` : La colonne à vérifier, doit être comprise entre 0 et le nombre de colonnes
+    ///    moins un
+    ///
+    /// # Retour
+    ///
+    /// Un booléen valant `true` si la colonne est remplie sinon `false` ou une erreur si la valeur
+    /// donnée dans l’argument `column` est mauvaise.
+    ///
+    /// # Liste des erreurs possibles
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise
+    ///    entre 0 et le nombre de colonnes moins un.
+    pub fn is_filled_column(&self, column : usize) -> Result<bool> {
+        if column >= self.config.cols {
+            return Err(Error::InvalidColumn);
+        }
+
+        Ok(self.column_height(column)? >= self.config.rows)
+    }
+
+    /// Ajoute, si possible, un jeton dans l’aire de jeu
+    ///
+    /// # Arguments
+    ///
+    ///  * `token` : Le jeton. Doit avoir pour valeur `State::RedToken` ou `State::YellowToken`
+    ///  * `column` : La colonne où ajouter le jeton, doit être comprise entre 0 et le nombre de
+    ///    colonnes moins un.
+    ///
+    /// # Retour
     ///
     /// Un booléen valant `true` si le jeton ajouté permet la victoire sinon `false` ou une erreur
     /// si la valeur donné par `token` ne corresponds pas à celui d’un jeton ou si la valeur donnée
-    /// par `column` est mauvaise.
+    /// par `column` est mauvaise. Les cases alignées sont ensuite accessibles via
+    /// [`Area::winning_cells`].
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise
+    ///    entre 0 et le nombre de colonnes moins un.
+    ///  * `NotAToken` - L’état demandé ne correspond pas à celui d’un jeton.
+    ///  * `FilledColumn` - La colonne dont il est demandé une modification est déjà remplie.
+    pub fn set_token(&mut self, token : State, column : usize) -> Result<bool> {
+        self.place_token(token, column)?;
+
+        return Ok(!self.winning_cells.is_empty());
+    }
+
+    /// Fourni les cases alignées par le dernier jeton ajouté
+    ///
+    /// Permet aux interfaces de mettre en évidence l’alignement gagnant. Si le jeton forme
+    /// plusieurs alignements à la fois (par exemple horizontal et diagonal), toutes les cases
+    /// concernées sont données.
+    ///
+    /// # Retour
+    ///
+    /// La liste des cases `(colonne, ligne)`, la ligne partant du bas, ou une liste vide si le
+    /// dernier jeton ajouté ne permet pas la victoire.
+    pub fn winning_cells(&self) -> &[(usize, usize)] {
+        return &self.winning_cells;
+    }
+
+    /// Retire le jeton au sommet d’une colonne
+    ///
+    /// Permet d’annuler un coup joué via [`Area::set_token`] sans copier la zone de jeu, notamment
+    /// lors de l’exploration des coups par les algorithmes de recherche. L’alignement formé par le
+    /// jeton retiré est oublié et le dernier coup n’est plus connu (voir [`Area::last_move`]).
+    ///
+    /// # Arguments
+    ///
+    ///  * `column` : La colonne où retirer le jeton, doit être comprise entre 0 et le nombre de
+    ///    colonnes moins un.
+    ///
+    /// # Retour
+    ///
+    /// Le jeton retiré
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    ///  * `EmptyColumn` - La colonne ne contient aucun jeton.
+    pub fn unset_token(&mut self, column : usize) -> Result<State> {
+        let row = match self.free_rows(column)?.into_iter().rev().find(|row| self.cell(column, *row) != State::NoToken) {
+            Some(row) => row,
+            None => return Err(Error::EmptyColumn),
+        };
+
+        let token = self.cell(column, row);
+
+        self.write_cell(column, row, State::NoToken);
+        self.last_move = None;
+
+        if self.winning_cells.contains(&(column, row)) {
+            self.winning_cells.clear();
+        }
+
+        return Ok(token);
+    }
+
+    /// Retire le jeton du bas d’une colonne, les jetons situés au-dessus descendant d’une case
+    ///
+    /// Correspond au retrait de la variante Pop Out. Les alignements des deux couleurs sont
+    /// ensuite recherchés, ceux de la couleur du jeton retiré étant prioritaires, et sont
+    /// accessibles via [`Area::winning_cells`]. Le dernier coup n’est plus connu (voir
+    /// [`Area::last_move`]). Les jetons descendent en passant à côté des cases bloquées.
+    ///
+    /// # Arguments
+    ///
+    ///  * `column` : La colonne où retirer le jeton, doit être comprise entre 0 et le nombre de
+    ///    colonnes moins un.
+    ///
+    /// # Retour
+    ///
+    /// Le jeton retiré
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    ///  * `EmptyColumn` - La colonne ne contient aucun jeton.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State};
+    ///
+    /// let mut area : Area = "
+    ///     .......
+    ///     .......
+    ///     .......
+    ///     ...R...
+    ///     ...Y...
+    ///     YYYR.RR
+    /// ".parse().unwrap();
+    ///
+    /// // Le jeton jaune descend et complète l’alignement du bas
+    /// assert_eq!(area.pop_bottom(3).unwrap(), State::RedToken);
+    /// assert_eq!(area.winning_cells(), &[(0, 0), (1, 0), (2, 0), (3, 0)]);
+    /// assert_eq!(area.column_height(3).unwrap(), 2);
+    /// ```
+    pub fn pop_bottom(&mut self, column : usize) -> Result<State> {
+        let rows = self.stacked_rows(column)?;
+
+        if rows.is_empty() {
+            return Err(Error::EmptyColumn);
+        }
+
+        let token = self.cell(column, rows[0]);
+
+        for i in 1..rows.len() {
+            let state = self.cell(column, rows[i]);
+
+            self.write_cell(column, rows[i - 1], state);
+        }
+
+        self.write_cell(column, rows[rows.len() - 1], State::NoToken);
+        self.last_move = None;
+
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+        let cells = self.alignments(token);
+
+        self.winning_cells = if cells.is_empty() { self.alignments(opponent) } else { cells };
+
+        return Ok(token);
+    }
+
+    /// Vérifie si ajouter un jeton dans une colonne permet la victoire, sans modifier la zone de
+    /// jeu
+    ///
+    /// # Arguments
+    ///
+    ///  * `token` : Le jeton. Doit avoir pour valeur `State::RedToken` ou `State::YellowToken`
+    ///  * `column` : La colonne où ajouter le jeton, doit être comprise entre 0 et le nombre de
+    ///    colonnes moins un.
+    ///
+    /// # Retour
+    ///
+    /// Un booléen valant `true` si le jeton ajouté formerait un alignement sinon `false`
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    ///  * `NotAToken` - L’état demandé ne correspond pas à celui d’un jeton.
+    ///  * `FilledColumn` - La colonne est déjà remplie.
+    pub fn is_winning_move(&self, token : State, column : usize) -> Result<bool> {
+        if token == State::NoToken {
+            return Err(Error::NotAToken);
+        }
+
+        let row = self.column_height(column)?;
+
+        if row >= self.config.rows {
+            return Err(Error::FilledColumn);
+        }
+
+        return Ok(!self.check_victory_from(token, column, row).is_empty());
+    }
+
+    /// Fourni les colonnes où ajouter un jeton donné formerait un alignement
+    ///
+    /// Il s’agit des menaces immédiates du joueur de ce jeton: son adversaire doit jouer dans
+    /// l’une de ces colonnes pour l’empêcher de gagner au coup suivant, ce qui est impossible s’il
+    /// en existe plusieurs.
+    ///
+    /// # Arguments
+    ///
+    ///  * `token` : Le jeton du joueur menaçant
+    ///
+    /// # Retour
+    ///
+    /// Les colonnes concernées, dans l’ordre croissant. La liste est vide si `token` n’est pas un
+    /// jeton.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State};
+    ///
+    /// let area : Area = "
+    ///     .......
+    ///     .......
+    ///     .......
+    ///     .......
+    ///     R......
+    ///     RYYY.R.
+    /// ".parse().unwrap();
+    ///
+    /// assert_eq!(area.immediate_threats(State::YellowToken), vec![4]);
+    /// assert!(area.immediate_threats(State::RedToken).is_empty());
+    /// ```
+    pub fn immediate_threats(&self, token : State) -> Vec<usize> {
+        if token == State::NoToken {
+            return Vec::new();
+        }
+
+        return self.legal_moves()
+            .filter(|(col, row)| !self.check_victory_from(token, *col, *row).is_empty())
+            .map(|(col, _)| col)
+            .collect();
+    }
+
+    /// Vérifie si ajouter un jeton dans une case vide formerait un alignement, sans tenir compte
+    /// de la gravité ni modifier la zone de jeu
+    ///
+    /// Permet de repérer les menaces situées au-dessus des colonnes, qui ne pourront être jouées
+    /// qu’une fois les cases inférieures remplies.
+    ///
+    /// # Arguments
+    ///
+    ///  * `token` : Le jeton. Doit avoir pour valeur `State::RedToken` ou `State::YellowToken`
+    ///  * `col` : La colonne de la case
+    ///  * `row` : La ligne de la case en partant du bas
+    ///
+    /// # Retour
+    ///
+    /// `true` si la case est vide et si le jeton y formerait un alignement, sinon `false`
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    ///  * `InvalidRow` - L’identifiant de la ligne est invalide.
+    ///  * `NotAToken` - L’état demandé ne correspond pas à celui d’un jeton.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State};
+    ///
+    /// let area : Area = "
+    ///     .......
+    ///     .......
+    ///     .......
+    ///     ..Y....
+    ///     .YRR...
+    ///     YRRY...
+    /// ".parse().unwrap();
+    ///
+    /// // La diagonale jaune se complète en (3, 3), case encore inaccessible
+    /// assert!(area.is_winning_cell(State::YellowToken, 3, 3).unwrap());
+    /// assert!(!area.is_winning_move(State::YellowToken, 3).unwrap());
+    /// ```
+    pub fn is_winning_cell(&self, token : State, col : usize, row : usize) -> Result<bool> {
+        if token == State::NoToken {
+            return Err(Error::NotAToken);
+        }
+
+        if col >= self.config.cols {
+            return Err(Error::InvalidColumn);
+        }
+
+        if row >= self.config.rows {
+            return Err(Error::InvalidRow);
+        }
+
+        if self.cell(col, row) != State::NoToken || self.config.is_blocked(col, row) {
+            return Ok(false);
+        }
+
+        return Ok(!self.check_victory_from(token, col, row).is_empty());
+    }
+
+    /// Fourni le nombre de jetons empilés dans une colonne
+    ///
+    /// Correspond également à la ligne où tombera le prochain jeton joué dans cette colonne. Les
+    /// cases bloquées situées sous cette ligne sont comptées comme des jetons (voir
+    /// [`AreaConfig::block`]).
+    ///
+    /// # Arguments
+    ///
+    ///  * `column` : La colonne à mesurer, doit être comprise entre 0 et le nombre de colonnes
+    ///    moins un
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    pub fn column_height(&self, column : usize) -> Result<usize> {
+        if column >= self.config.cols {
+            return Err(Error::InvalidColumn);
+        }
+
+        let height = self.config.rows + 1;
+        let filled = (self.occupied() | self.config.blocked_cells()) >> (column * height);
+
+        return Ok(filled.trailing_ones() as usize);
+    }
+
+    /// Modifie directement le contenu d’une case
+    ///
+    /// Remplace l’écriture `area[(colonne, ligne)] = état`, l’indexation mutable n’étant plus
+    /// possible depuis que la zone de jeu est stockée sous forme de bitboards. Comme cette
+    /// dernière, la modification ne tient pas compte de la gravité: la position obtenue peut être
+    /// vérifiée via [`Area::validate`]. Les alignements gagnants (voir [`Area::winning_cells`])
+    /// sont recalculés sur l’ensemble de la zone de jeu.
+    ///
+    /// # Arguments
+    ///
+    ///  * `col` : La colonne de la case
+    ///  * `row` : La ligne de la case en partant du bas
+    ///  * `state` : Le nouveau contenu de la case, un jeton ou une case vide
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide.
+    ///  * `InvalidRow` - L’identifiant de la ligne est invalide.
+    ///  * `InvalidPosition` - La case est bloquée (voir [`AreaConfig::block`]).
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State};
+    ///
+    /// let mut area = Area::new();
+    ///
+    /// area.set_cell(3, 0, State::RedToken).unwrap();
+    /// assert_eq!(area[(3, 0)], State::RedToken);
+    ///
+    /// area.set_cell(3, 0, State::NoToken).unwrap();
+    /// assert!(area.is_empty());
+    /// ```
+    pub fn set_cell(&mut self, col : usize, row : usize, state : State) -> Result<()> {
+        if col >= self.config.cols {
+            return Err(Error::InvalidColumn);
+        }
+
+        if row >= self.config.rows {
+            return Err(Error::InvalidRow);
+        }
+
+        if self.config.is_blocked(col, row) {
+            return Err(Error::InvalidPosition);
+        }
+
+        self.write_cell(col, row, state);
+        self.winning_cells = self.find_winner().map(|(_, cells)| cells).unwrap_or_default();
+
+        return Ok(());
+    }
+
+    /// Fourni le nombre de jetons présents dans la zone de jeu
+    ///
+    /// Correspond au nombre de coups joués depuis une zone de jeu vide.
+    pub fn token_count(&self) -> usize {
+        return self.occupied().count_ones() as usize;
+    }
+
+    /// Décrit la position par un vecteur numérique de taille fixe, destiné à l’apprentissage
+    /// automatique de fonctions d’évaluation
+    ///
+    /// Le vecteur contient, dans l’ordre:
+    ///  * le plan des jetons jaunes: pour chaque case, `1` si elle contient un jeton jaune, sinon
+    ///    `0`. La case `(colonne, ligne)` est à l’indice `colonne * lignes + ligne`.
+    ///  * le plan des jetons rouges, organisé de la même façon
+    ///  * le nombre de menaces immédiates des jetons jaunes puis rouges (voir
+    ///    [`Area::immediate_threats`])
+    ///  * le nombre de cases vides gagnantes des jetons jaunes puis rouges, jouables ou non (voir
+    ///    [`Area::is_winning_cell`])
+    ///  * le contrôle du centre des jetons jaunes puis rouges: la somme, pour chacun de leurs
+    ///    jetons, de la proximité de sa colonne au centre (nulle pour les colonnes extrêmes)
+    ///  * le numéro du prochain coup, à partir de 1
+    ///
+    /// Seuls les jetons jaunes et rouges sont décrits, les jetons verts et bleus n’étant
+    /// considérés que comme des cases occupées.
+    ///
+    /// # Retour
+    ///
+    /// Le vecteur, de taille [`AreaConfig::feature_count`]
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State};
+    ///
+    /// let mut area = Area::new();
+    ///
+    /// area.set_token(State::YellowToken, 3).unwrap();
+    /// area.set_token(State::RedToken, 0).unwrap();
+    ///
+    /// let features = area.features();
+    ///
+    /// assert_eq!(features.len(), area.config().feature_count());
+    /// assert_eq!(features[3 * 6], 1.0);
+    /// assert_eq!(features[42], 1.0);
+    /// assert_eq!(features[84..], [0.0, 0.0, 0.0, 0.0, 6.0, 0.0, 3.0]);
+    /// ```
+    pub fn features(&self) -> Vec<f32> {
+        let config = &self.config;
+        let tokens = [State::YellowToken, State::RedToken];
+        let mut features = Vec::with_capacity(config.feature_count());
+
+        for token in tokens.iter() {
+            for col in 0..config.cols {
+                features.extend((0..config.rows).map(|row| if self.cell(col, row) == *token { 1.0 } else { 0.0 }));
+            }
+        }
+
+        for token in tokens.iter() {
+            features.push(self.immediate_threats(*token).len() as f32);
+        }
+
+        for token in tokens.iter() {
+            let cells = self.iter().filter(|((col, row), _)| matches!(self.is_winning_cell(*token, *col, *row), Ok(true))).count();
+
+            features.push(cells as f32);
+        }
+
+        for token in tokens.iter() {
+            let center : usize = self.iter()
+                .filter(|(_, state)| state == token)
+                .map(|((col, _), _)| config.cols - 1 - (2 * col).abs_diff(config.cols - 1))
+                .sum();
+
+            features.push(center as f32);
+        }
+
+        features.push((self.token_count() + 1) as f32);
+
+        return features;
+    }
+
+    /// Déduit le jeton devant jouer le prochain coup à partir du nombre de jetons de chaque
+    /// couleur
+    ///
+    /// À nombre de jetons égal, les jetons jaunes sont considérés comme ayant commencé la partie.
+    /// Lorsque des jetons verts ou bleus sont présents, les couleurs jouent à tour de rôle dans
+    /// l’ordre de [`State::TOKENS`] à partir de la couleur ayant commencé.
+    ///
+    /// # Retour
+    ///
+    /// Le jeton devant jouer le prochain coup ou `None` si la partie est terminée (alignement
+    /// formé ou zone de jeu remplie)
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPosition` - L’écart entre le nombre de jetons de chaque couleur est supérieur à
+    ///    un.
+    pub fn next_token(&self) -> Result<Option<State>> {
+        let counts = self.color_counts()?;
+
+        if !self.winning_cells.is_empty() || self.get_available_columns().is_empty() {
+            return Ok(None);
+        }
+
+        let fewest = counts.iter().min().copied().unwrap_or_default();
+
+        return Ok(counts.iter().position(|count| *count == fewest).map(|i| State::TOKENS[i]));
+    }
+
+    /// Encode la position et le jeton devant jouer en un code court, utilisable dans une URL
+    ///
+    /// Le code contient la configuration de la zone de jeu, le jeton devant jouer et le contenu de
+    /// chaque colonne, encodés en base64 sûr pour les URL (RFC 4648, section 5, sans remplissage).
+    ///
+    /// # Arguments
+    ///
+    ///  * `to_move` : Le jeton devant jouer le prochain coup
+    ///
+    /// # Retour
+    ///
+    /// Le code de la position (voir [`Area::from_code`])
     ///
     /// # Liste des erreurs possibles
-    /// 
-    ///  * `InvalidColumn` - L’identifiant de la colonne est invalide. Cette dernière doit être comprise
-    /// entre 0 et `AREA_COLS-1`.
-    ///  * `NotAToken` - L’état demandé ne correspond pas à celui d’un jeton.
-    ///  * `FilledColumn` - La colonne dont il est demandé une modification est déjà remplie.
-    pub fn set_token(&mut self, token : State, column : usize) -> Result<bool> {
-        if let State::NoToken = token {
+    ///
+    ///  * `NotAToken` - L’état donné ne correspond pas à celui d’un jeton jaune ou rouge.
+    ///  * `InvalidPosition` - La zone de jeu contient des jetons verts ou bleus, des jetons de
+    ///    handicap ou des cases bloquées, les codes ne décrivant que des parties à deux joueurs sans
+    ///    handicap sur un plateau sans obstacle.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State};
+    ///
+    /// let mut area = Area::new();
+    ///
+    /// area.set_token(State::YellowToken, 3).unwrap();
+    ///
+    /// let code = area.to_code(State::RedToken).unwrap();
+    ///
+    /// assert_eq!(Area::from_code(&code).unwrap(), (area, State::RedToken));
+    /// ```
+    pub fn to_code(&self, to_move : State) -> Result<String> {
+        let side = match to_move {
+            State::YellowToken => 1,
+            State::RedToken => 2,
+            _ => return Err(Error::NotAToken),
+        };
+
+        if self.green | self.blue != 0 || self.handicap.is_some() || self.config.blocked != 0 {
+            return Err(Error::InvalidPosition);
+        }
+
+        let height = self.config.rows + 1;
+        let bottom = (0..self.config.cols).fold(0u64, |acc, col| acc | 1 << (col * height));
+        // Chaque colonne contient ses jetons jaunes suivis d’un bit marquant sa hauteur
+        let value = self.yellow | ((self.red | self.yellow) + bottom);
+
+        // Le bit de poids fort du nombre de jetons à aligner indique un plateau cylindrique
+        let win_length = self.config.win_length as u8 | (self.config.wrap_columns as u8) << 7;
+        let mut bytes = vec![self.config.cols as u8, self.config.rows as u8, win_length, side];
+
+        bytes.extend_from_slice(&value.to_le_bytes()[..(self.config.cols * height).div_ceil(8)]);
+
+        return Ok(base64url_encode(&bytes));
+    }
+
+    /// Décode une position encodée par [`Area::to_code`]
+    ///
+    /// # Arguments
+    ///
+    ///  * `code` : Le code de la position
+    ///
+    /// # Retour
+    ///
+    /// La zone de jeu et le jeton devant jouer le prochain coup
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - La configuration encodée est invalide.
+    ///  * `InvalidPosition` - Le code est mal formé ou la position ne peut pas être atteinte en
+    ///    cours de partie. Le code ne précisant pas la variante, la vérification est celle de la
+    ///    variante Pop Out (voir [`Area::validate_variant`]).
+    pub fn from_code(code : &str) -> Result<(Self, State)> {
+        let bytes = base64url_decode(code).ok_or(Error::InvalidPosition)?;
+
+        if bytes.len() < 4 {
+            return Err(Error::InvalidPosition);
+        }
+
+        let mut config = AreaConfig::new(bytes[0] as usize, bytes[1] as usize, (bytes[2] & 0x7f) as usize)?;
+
+        config.wrap_columns = bytes[2] & 0x80 != 0;
+        config.validate()?;
+
+        let to_move = match bytes[3] {
+            1 => State::YellowToken,
+            2 => State::RedToken,
+            _ => return Err(Error::InvalidPosition),
+        };
+
+        let height = config.rows + 1;
+
+        if bytes.len() != 4 + (config.cols * height).div_ceil(8) {
+            return Err(Error::InvalidPosition);
+        }
+
+        let mut value = [0u8; 8];
+
+        value[..bytes.len() - 4].copy_from_slice(&bytes[4..]);
+
+        let value = u64::from_le_bytes(value);
+        let mut area = Area::with_config(config)?;
+
+        if config.cols * height < 64 && value >> (config.cols * height) != 0 {
+            return Err(Error::InvalidPosition);
+        }
+
+        for col in 0..config.cols {
+            let column = (value >> (col * height)) & (u64::MAX >> (64 - height));
+
+            if column == 0 {
+                return Err(Error::InvalidPosition);
+            }
+
+            let tokens = 63 - column.leading_zeros() as usize;
+
+            for row in 0..tokens {
+                area.write_cell(col, row, if column & 1 << row != 0 { State::YellowToken } else { State::RedToken });
+            }
+        }
+
+        area.validate_variant(Variant::PopOut)?;
+        area.winning_cells = area.find_winner().map(|(_, cells)| cells).unwrap_or_default();
+
+        return Ok((area, to_move));
+    }
+
+    /// Crée le symétrique horizontal de la zone de jeu
+    ///
+    /// La première colonne devient la dernière et inversement. L’alignement gagnant, le dernier
+    /// coup et les cases bloquées sont également reportés.
+    pub fn mirrored(&self) -> Self {
+        let cols = self.config.cols;
+        let height = self.config.rows + 1;
+        let mask = u64::MAX >> (64 - height);
+        let mirror = |board : u64| {
+            (0..cols).fold(0u64, |acc, col| acc | ((board >> (col * height)) & mask) << ((cols - 1 - col) * height))
+        };
+
+        let (red, yellow, green, blue) = (mirror(self.red), mirror(self.yellow), mirror(self.green), mirror(self.blue));
+
+        return Self {
+            config: AreaConfig { blocked: mirror(self.config.blocked), ..self.config },
+            red,
+            yellow,
+            green,
+            blue,
+            winning_cells: self.winning_cells.iter().map(|&(col, row)| (cols - 1 - col, row)).collect(),
+            last_move: self.last_move.map(|(col, row, token)| (cols - 1 - col, row, token)),
+            handicap: self.handicap,
+            zobrist: zobrist_key(red, 0) ^ zobrist_key(yellow, 1) ^ zobrist_key(green, 2) ^ zobrist_key(blue, 3),
+        };
+    }
+
+    /// Fourni la forme canonique de la zone de jeu
+    ///
+    /// La forme canonique est la plus petite, selon un ordre arbitraire mais stable, de la zone de
+    /// jeu et de son symétrique horizontal (voir [`Area::mirrored`]). Deux positions symétriques
+    /// ont ainsi la même forme canonique, ce qui permet aux solveurs et aux bibliothèques
+    /// d’ouvertures de ne stocker qu’une seule d’entre elles. Lorsque les cases bloquées ne sont
+    /// pas symétriques, la zone de jeu est sa propre forme canonique.
+    pub fn canonical(&self) -> Self {
+        let mirrored = self.mirrored();
+
+        if mirrored.config == self.config && (mirrored.yellow, mirrored.red) < (self.yellow, self.red) {
+            return mirrored;
+        }
+
+        return self.clone();
+    }
+
+    /// Place des jetons de handicap dans une zone de jeu vide
+    ///
+    /// Le joueur le plus faible peut ainsi disposer de plusieurs jetons avant le début de la partie.
+    /// Les jetons sont ajoutés dans l’ordre des colonnes données, une colonne pouvant être donnée
+    /// plusieurs fois. La disposition obtenue est vérifiée par [`Area::validate`]: les jetons de
+    /// handicap ne doivent pas former d’alignement.
+    ///
+    /// # Arguments
+    ///
+    ///  * `token` : Le jeton du joueur recevant le handicap
+    ///  * `columns` : Les colonnes où placer les jetons
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `NotAToken` - L’état donné ne correspond pas à celui d’un jeton.
+    ///  * `InvalidPosition` - La zone de jeu n’est pas vide ou les jetons forment un alignement.
+    ///  * `InvalidColumn` - L’une des colonnes n’existe pas.
+    ///  * `FilledColumn` - Une colonne reçoit plus de jetons qu’elle ne peut en contenir.
+    ///
+    /// En cas d’erreur, la zone de jeu reste vide.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State};
+    ///
+    /// let mut area = Area::new();
+    ///
+    /// area.set_handicap(State::RedToken, &[3, 3]).unwrap();
+    ///
+    /// assert_eq!(area.token_count(), 2);
+    /// assert_eq!(area.handicap(), Some((State::RedToken, 2)));
+    /// // Les jetons de handicap ne comptent pas pour déterminer le joueur suivant
+    /// assert_eq!(area.next_token().unwrap(), Some(State::YellowToken));
+    ///
+    /// // Quatre jetons de handicap alignés sont refusés
+    /// let mut area = Area::new();
+    ///
+    /// assert!(area.set_handicap(State::RedToken, &[0, 0, 0, 0]).is_err());
+    /// assert_eq!(area.token_count(), 0);
+    /// ```
+    pub fn set_handicap(&mut self, token : State, columns : &[usize]) -> Result<()> {
+        if token == State::NoToken {
             return Err(Error::NotAToken);
         }
 
-        if column >= AREA_COLS {
-            return Err(Error::InvalidColumn);
+        if self.token_count() != 0 {
+            return Err(Error::InvalidPosition);
         }
 
-        if let Some(row) = self.find_available_row(column) {
-            self.area[column][row] = token;
+        for col in columns.iter() {
+            let row = self.column_height(*col).and_then(|row| if row < self.config.rows { Ok(row) } else { Err(Error::FilledColumn) });
 
-            Ok(self.check_victory_from(column, row))
-        } else {
-            Err(Error::FilledColumn)
+            match row {
+                Ok(row) => self.write_cell(*col, row, token),
+                Err(e) => {
+                    self.clear();
+
+                    return Err(e);
+                }
+            }
+        }
+
+        self.handicap = Some((token, columns.len()));
+
+        if let Err(e) = self.validate() {
+            self.clear();
+
+            return Err(e);
+        }
+
+        return Ok(());
+    }
+
+    /// Fourni le jeton et le nombre des jetons de handicap placés avant la partie, s’il y en a
+    pub fn handicap(&self) -> Option<(State, usize)> {
+        return self.handicap;
+    }
+
+    /// Vérifie que la position peut être atteinte en cours de partie
+    ///
+    /// Une position est valide si aucun jeton ne repose sur une case vide ni n’occupe une case
+    /// bloquée, si l’écart entre le
+    /// nombre de jetons de chaque couleur vaut au plus un et si au plus un coup a formé des
+    /// alignements: seule une couleur peut être alignée, tous ses alignements passant par une même
+    /// case, et cette couleur doit être la dernière à avoir joué.
+    ///
+    /// Les jetons de handicap (voir [`Area::set_handicap`]) ne sont pas pris en compte dans l’écart
+    /// entre les nombres de jetons, mais ils ne peuvent pas à eux seuls former un alignement.
+    ///
+    /// Les positions de la variante Pop Out sont vérifiées par [`Area::validate_variant`].
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPosition` - La position ne peut pas être atteinte en cours de partie.
+    pub fn validate(&self) -> Result<()> {
+        return self.validate_variant(Variant::Standard);
+    }
+
+    /// Vérifie que la position peut être atteinte en cours de partie dans la variante donnée
+    ///
+    /// Dans la variante classique, la vérification est celle de [`Area::validate`]. Dans la
+    /// variante Pop Out, les retraits modifient librement le nombre de jetons de chaque couleur et
+    /// peuvent former des alignements pour les deux couleurs à la fois: seule la disposition des
+    /// jetons est alors vérifiée, aucun jeton ne devant reposer sur une case vide ni occuper une
+    /// case bloquée.
+    ///
+    /// # Arguments
+    ///
+    ///  * `variant` : La variante dans laquelle la position a été jouée
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPosition` - La position ne peut pas être atteinte en cours de partie.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, State, Variant};
+    ///
+    /// let mut area = Area::new();
+    ///
+    /// for (col, token) in [(0, State::YellowToken), (1, State::RedToken), (2, State::YellowToken), (3, State::RedToken)].iter() {
+    ///     area.set_token(*token, *col).unwrap();
+    /// }
+    ///
+    /// // Le jaune retire son jeton puis le rouge rejoue: le rouge a deux jetons de plus
+    /// area.pop_bottom(0).unwrap();
+    /// area.set_token(State::RedToken, 4).unwrap();
+    ///
+    /// assert!(area.validate().is_err());
+    /// assert!(area.validate_variant(Variant::PopOut).is_ok());
+    ///
+    /// // Les codes ne précisant pas la variante, la position peut être relue
+    /// let code = area.to_code(State::YellowToken).unwrap();
+    ///
+    /// assert_eq!(Area::from_code(&code).unwrap(), (area, State::YellowToken));
+    /// ```
+    pub fn validate_variant(&self, variant : Variant) -> Result<()> {
+        let height = self.config.rows + 1;
+        let occupied = self.occupied();
+        let blocked = self.config.blocked_cells();
+
+        if occupied & blocked != 0 {
+            return Err(Error::InvalidPosition);
         }
+
+        for col in 0..self.config.cols {
+            let column = (occupied >> (col * height)) & (u64::MAX >> (64 - height));
+            let filled = ((occupied | blocked) >> (col * height)) & (u64::MAX >> (64 - height));
+
+            // Les jetons d’une colonne doivent former une suite continue depuis le bas, les cases
+            // bloquées comptant comme occupées
+            if column >> filled.trailing_ones() != 0 {
+                return Err(Error::InvalidPosition);
+            }
+        }
+
+        if variant == Variant::PopOut {
+            return Ok(());
+        }
+
+        let counts = self.color_counts()?;
+        let mut winner = None;
+
+        for token in State::TOKENS.iter() {
+            let board = self.bits(*token);
+            let mut common : Option<Vec<(usize, usize)>> = None;
+
+            for shift in self.directions().iter() {
+                let mut starts = self.line_starts(board, *shift);
+
+                while starts != 0 {
+                    let line = self.line_through(board, starts.trailing_zeros() as usize, *shift);
+
+                    common = Some(match common {
+                        Some(cells) => cells.into_iter().filter(|c| line.contains(c)).collect(),
+                        None => line,
+                    });
+
+                    starts &= starts - 1;
+                }
+            }
+
+            match common {
+                Some(cells) if cells.is_empty() => return Err(Error::InvalidPosition),
+                Some(_) if winner.is_some() => return Err(Error::InvalidPosition),
+                Some(_) => winner = Some(*token),
+                None => {},
+            }
+        }
+
+        // Un alignement ne peut être formé que par un coup de la partie
+        if winner.is_some() && counts.iter().all(|count| *count == 0) {
+            return Err(Error::InvalidPosition);
+        }
+
+        // La couleur alignée doit être la dernière à avoir joué et donc avoir le plus de jetons
+        return match winner.and_then(State::color_rank) {
+            Some(rank) if counts.iter().any(|count| *count > counts[rank]) => Err(Error::InvalidPosition),
+            _ => Ok(()),
+        };
+    }
+
+    /// Recherche un alignement gagnant sur l’ensemble de la zone de jeu
+    ///
+    /// Contrairement à la vérification faite à chaque coup, toutes les cases sont examinées. Cette
+    /// recherche est donc adaptée aux positions chargées depuis un fichier ou reçues d’un tiers.
+    ///
+    /// # Retour
+    ///
+    /// Le jeton gagnant ainsi que l’ensemble des cases de ses alignements ou `None` si aucun
+    /// alignement n’est formé. Si plusieurs couleurs forment un alignement, seul celui des jetons
+    /// rouges, ou à défaut de la première couleur alignée parmi les jaunes, verts et bleus, est
+    /// donné.
+    pub fn find_winner(&self) -> Option<(State, Vec<(usize, usize)>)> {
+        for token in [State::RedToken, State::YellowToken, State::GreenToken, State::BlueToken].iter() {
+            let cells = self.alignments(*token);
+
+            if !cells.is_empty() {
+                return Some((*token, cells));
+            }
+        }
+
+        return None;
+    }
+
+    /// Fourni la clé de Zobrist de la position
+    ///
+    /// Cette clé est mise à jour à chaque ajout ou retrait de jeton sans parcourir la zone de jeu.
+    /// Deux positions identiques ont toujours la même clé, ce qui permet aux algorithmes de
+    /// recherche d’indexer les positions déjà évaluées (voir
+    /// [`TranspositionTable`](crate::transposition::TranspositionTable)).
+    pub fn zobrist_key(&self) -> u64 {
+        return self.zobrist;
+    }
+
+    /// Fourni le dernier jeton ajouté dans la zone de jeu
+    ///
+    /// # Retour
+    ///
+    /// La colonne, la ligne et le jeton du dernier coup joué ou `None` si aucun jeton n’a été
+    /// ajouté depuis la création ou le dernier vidage de la zone de jeu. Les zones de jeu lues
+    /// depuis un schéma ou désérialisées n’ont pas de dernier coup.
+    pub fn last_move(&self) -> Option<(usize, usize, State)> {
+        return self.last_move;
     }
 
     /// Vérifie si la zone de jeu est vide (premier coup)
@@ -442,102 +4828,293 @@ impl Area {
     ///
     /// Retourne `true` si la zone de jeu est vide sinon `false`
     pub fn is_empty(&self) -> bool {
-        for col in 0..AREA_COLS {
-            if self.area[col][0] != State::NoToken {
-                return false;
-            }
+        return self.occupied() == 0;
+    }
+
+    /// Joue un coup pour `token` suivant les règles données et retourne la ligne concernée
+    fn apply_action(&mut self, action : Action, token : State, variant : Variant) -> Result<usize> {
+        let column = match action {
+            Action::Drop(column) => return self.place_token(token, column),
+            Action::Pop(column) => column,
+            Action::Swap => return Err(Error::SwapNotAllowed),
+        };
+
+        if variant != Variant::PopOut {
+            return Err(Error::PopNotAllowed);
+        }
+
+        let bottom = match self.stacked_rows(column)?.first() {
+            Some(bottom) => *bottom,
+            None => return Err(Error::EmptyColumn),
+        };
+
+        if self.cell(column, bottom) != token {
+            return Err(Error::NotOwnToken);
         }
 
-        return true;
+        self.pop_bottom(column)?;
+
+        return Ok(bottom);
     }
 
-    fn check_victory_from(&self, col : usize, row : usize) -> bool {
-        let token = self.area[col][row];
+    /// Replace un jeton retiré via [`Area::pop_bottom`] en bas de sa colonne, les jetons de la
+    /// colonne remontant d’une case. La position précédant un retrait n’ayant aucun alignement,
+    /// les cases alignées sont oubliées.
+    fn push_bottom(&mut self, token : State, column : usize) {
+        let rows = self.free_rows(column).unwrap();
+        let stacked = self.stacked_rows(column).unwrap().len();
 
-        let a = self.check_linear(token, col, true);
-        let b = self.check_linear(token, row, false);
-        let c = self.check_diagonal(token, col, row, true);
-        let d = self.check_diagonal(token, col, row, false);
+        for i in (0..stacked).rev() {
+            let state = self.cell(column, rows[i]);
+
+            self.write_cell(column, rows[i + 1], state);
+        }
 
-        return a || b || c || d
+        self.write_cell(column, rows[0], token);
+        self.winning_cells.clear();
     }
 
-    fn find_available_row(&self, column : usize) -> Option<usize> {
-        for i in 0..AREA_ROWS {
-            if let State::NoToken = self.area[column][i] {
-                return Some(i);
-            }
+    /// Fourni les lignes non bloquées d’une colonne, de bas en haut
+    fn free_rows(&self, column : usize) -> Result<Vec<usize>> {
+        if column >= self.config.cols {
+            return Err(Error::InvalidColumn);
         }
-        return None;
+
+        return Ok((0..self.config.rows).filter(|row| !self.config.is_blocked(column, *row)).collect());
     }
 
-    fn check_linear(&self, token : State, pos : usize, col : bool) -> bool {
-        fn get(area : &[[State; AREA_ROWS]; AREA_COLS], pos : usize, i : usize, col : bool) -> State {
-            if col {
-                area[pos][i]
-            } else {
-                area[i][pos]
+    /// Fourni les lignes des jetons d’une colonne, de bas en haut
+    fn stacked_rows(&self, column : usize) -> Result<Vec<usize>> {
+        let rows = self.free_rows(column)?;
+
+        return Ok(rows.into_iter().take_while(|row| self.cell(column, *row) != State::NoToken).collect());
+    }
+
+    /// Fourni l’ensemble des cases des alignements de `token`
+    fn alignments(&self, token : State) -> Vec<(usize, usize)> {
+        let board = self.bits(token);
+        let mut cells = Vec::new();
+
+        for shift in self.directions().iter() {
+            let mut starts = self.line_starts(board, *shift);
+
+            while starts != 0 {
+                for cell in self.line_through(board, starts.trailing_zeros() as usize, *shift) {
+                    if !cells.contains(&cell) {
+                        cells.push(cell);
+                    }
+                }
+
+                starts &= starts - 1;
             }
         }
 
-        let mut n = 0;
+        return cells;
+    }
+
+    /// Ajoute un jeton dans la colonne donnée et retourne la ligne où il est tombé. Les cases
+    /// alignées par ce jeton sont conservées dans `winning_cells`.
+    fn place_token(&mut self, token : State, column : usize) -> Result<usize> {
+        if let State::NoToken = token {
+            return Err(Error::NotAToken);
+        }
 
-        let max = if col { AREA_ROWS } else { AREA_COLS };
+        if column >= self.config.cols {
+            return Err(Error::InvalidColumn);
+        }
 
-        for i in 0..max {
-            if token == get(&self.area, pos, i, col) {
-                n += 1;
-            } else {
-                n = 0;
+        let row = self.column_height(column)?;
+
+        if row < self.config.rows {
+            self.write_cell(column, row, token);
+            self.winning_cells = self.check_victory_from(token, column, row);
+            self.last_move = Some((column, row, token));
+
+            Ok(row)
+        } else {
+            Err(Error::FilledColumn)
+        }
+    }
+
+    fn offset(&self, col : usize, row : usize) -> usize {
+        assert!(col < self.config.cols && row < self.config.rows, "Case ({}, {}) hors de la zone de jeu", col, row);
+
+        return col * (self.config.rows + 1) + row;
+    }
+
+    fn cell(&self, col : usize, row : usize) -> State {
+        let bit = 1u64 << self.offset(col, row);
+
+        if self.red & bit != 0 {
+            State::RedToken
+        } else if self.yellow & bit != 0 {
+            State::YellowToken
+        } else if self.green & bit != 0 {
+            State::GreenToken
+        } else if self.blue & bit != 0 {
+            State::BlueToken
+        } else {
+            State::NoToken
+        }
+    }
+
+    fn write_cell(&mut self, col : usize, row : usize, state : State) {
+        let offset = self.offset(col, row);
+        let bit = 1u64 << offset;
+
+        let previous = self.cell(col, row);
+
+        if let Some(color) = previous.color_index() {
+            self.zobrist ^= ZOBRIST_KEYS[color][offset];
+            *self.bits_mut(previous) &= !bit;
+        }
+
+        if let Some(color) = state.color_index() {
+            self.zobrist ^= ZOBRIST_KEYS[color][offset];
+            *self.bits_mut(state) |= bit;
+        }
+    }
+
+    fn bits(&self, token : State) -> u64 {
+        match token {
+            State::RedToken => self.red,
+            State::YellowToken => self.yellow,
+            State::GreenToken => self.green,
+            State::BlueToken => self.blue,
+            State::NoToken => 0,
+        }
+    }
+
+    fn bits_mut(&mut self, token : State) -> &mut u64 {
+        match token {
+            State::RedToken => &mut self.red,
+            State::YellowToken => &mut self.yellow,
+            State::GreenToken => &mut self.green,
+            State::BlueToken => &mut self.blue,
+            State::NoToken => unreachable!("Une case vide n’a pas de bitboard"),
+        }
+    }
+
+    /// Fourni le nombre de jetons de chaque couleur en jeu, dans l’ordre de [`State::TOKENS`]
+    ///
+    /// Les jetons jaunes et rouges sont toujours en jeu, les jetons verts et bleus ne l’étant que
+    /// s’ils sont présents dans la zone de jeu (ou, pour les verts, si des jetons bleus le sont).
+    /// Les joueurs jouant à tour de rôle, les nombres de jetons ne peuvent pas différer de plus
+    /// d’un. Les jetons placés avant la partie (voir [`Area::set_handicap`]) ne sont pas comptés.
+    fn color_counts(&self) -> Result<Vec<usize>> {
+        let counts : Vec<usize> = State::TOKENS.iter().map(|token| match self.handicap {
+            Some((handicap, placed)) if handicap == *token => (self.bits(*token).count_ones() as usize).saturating_sub(placed),
+            _ => self.bits(*token).count_ones() as usize,
+        }).collect();
+        let colors = counts.iter().rposition(|count| *count != 0).map_or(2, |last| (last + 1).max(2));
+        let counts = counts[..colors].to_vec();
+
+        if counts.iter().max().unwrap_or(&0) - counts.iter().min().unwrap_or(&0) > 1 {
+            return Err(Error::InvalidPosition);
+        }
+
+        return Ok(counts);
+    }
+
+    /// Fourni l’ensemble des cases occupées, toutes couleurs confondues
+    fn occupied(&self) -> u64 {
+        return self.red | self.yellow | self.green | self.blue;
+    }
+
+    /// Recherche les alignements de `token` passant par la case donnée, cette dernière étant
+    /// considérée comme contenant `token` quel que soit son état.
+    ///
+    /// Pour chaque direction, la présence d’un alignement est d’abord détectée par décalages
+    /// successifs du bitboard puis, le cas échéant, les cases de l’alignement passant par la case
+    /// donnée sont parcourues.
+    fn check_victory_from(&self, token : State, col : usize, row : usize) -> Vec<(usize, usize)> {
+        let played = self.offset(col, row);
+        let board = self.bits(token) | 1u64 << played;
+
+        let mut cells = Vec::new();
+
+        for shift in self.directions().iter() {
+            if self.line_starts(board, *shift) == 0 {
+                continue;
             }
 
-            if n >= VICTORY_NUMBER {
-                return true;
+            let line = self.line_through(board, played, *shift);
+
+            if line.len() >= self.config.win_length {
+                for cell in line {
+                    if !cells.contains(&cell) {
+                        cells.push(cell);
+                    }
+                }
             }
         }
 
-        return false;
+        return cells;
     }
 
-    fn check_diagonal(&self, token : State, col : usize, row : usize, decr : bool) -> bool {
-        fn get(area : &[[State; AREA_ROWS]; AREA_COLS], origin : (usize, usize), i : usize, decr : bool) -> State {
-            if decr {
-                area[origin.0 + i][origin.1 + i]
-            } else {
-                area[origin.0 + i][origin.1 - i]
+    /// Décalages correspondant aux directions verticale, horizontale puis aux deux diagonales
+    fn directions(&self) -> [usize; 4] {
+        let height = self.config.rows + 1;
+
+        return [1, height, height + 1, height - 1];
+    }
+
+    /// Fourni les bits de `board` débutant une suite d’au moins `win_length` bits espacés de
+    /// `shift`
+    fn line_starts(&self, board : u64, shift : usize) -> u64 {
+        let mut line = board;
+
+        for i in 1..self.config.win_length {
+            line &= self.config.shift(board, (i * shift) as isize);
+
+            if line == 0 {
+                return 0;
             }
         }
 
-        let (origin, n_max) = if decr {
-            let diff1 = usize::min(col, row);
-            let diff2 = usize::min(AREA_COLS - col - 1, AREA_ROWS - row - 1);
+        return line;
+    }
 
-            ((col - diff1, row - diff1), diff1 + diff2 + 1)
-        } else {
-            let diff1 = usize::min(col, AREA_ROWS - row - 1);
-            let diff2 = usize::min(AREA_COLS - col - 1, row);
+    /// Fourni les cases de la suite de bits de `board` espacés de `shift` contenant le bit `played`
+    ///
+    /// Sur un plateau cylindrique, la suite peut faire le tour du plateau. Elle est alors limitée
+    /// à une case par colonne.
+    fn line_through(&self, board : u64, played : usize, shift : usize) -> Vec<(usize, usize)> {
+        let height = self.config.rows + 1;
+        let cells = self.config.cols * height;
+        let max_length = if shift == 1 { self.config.rows } else { self.config.cols };
+        let next = |offset : usize, forward : bool| -> Option<usize> {
+            let next = match (self.config.wrap_columns, forward) {
+                (true, true) => Some((offset + shift) % cells),
+                (true, false) => Some((offset + cells - shift) % cells),
+                (false, true) => Some(offset + shift).filter(|next| *next < 64),
+                (false, false) => offset.checked_sub(shift),
+            };
 
-            ((col - diff1, row + diff1), diff1 + diff2 + 1)
+            return next.filter(|next| board & 1u64 << next != 0);
         };
 
-        if n_max < VICTORY_NUMBER {
-            return false;
+        let mut first = played;
+
+        for _ in 1..max_length {
+            match next(first, false) {
+                Some(previous) => first = previous,
+                None => break,
+            }
         }
 
-        let mut n = 0;
+        let mut line = vec![(first / height, first % height)];
+        let mut offset = first;
 
-        for i in 0..n_max {
-            if token == get(&self.area, origin, i, decr) {
-                n += 1;
-            } else {
-                n = 0;
+        while line.len() < max_length {
+            match next(offset, true) {
+                Some(following) => offset = following,
+                None => break,
             }
 
-            if n >= VICTORY_NUMBER {
-                return true;
-            }
+            line.push((offset / height, offset % height));
         }
 
-        return false;
+        return line;
     }
-}
\ No newline at end of file
+}
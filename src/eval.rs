@@ -0,0 +1,167 @@
+//! Fonctions d’évaluation des positions non terminales
+//!
+//! Lorsque l’exploration d’un algorithme de recherche s’arrête avant la fin de la partie, la
+//! position atteinte doit être estimée. Les fonctions d’évaluation implémentent le trait
+//! [`Evaluator`] afin de pouvoir être partagées entre plusieurs intelligences artificielles et
+//! remplacées à volonté.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, State};
+//! use connect_four::eval::{Evaluator, HeuristicEvaluator};
+//!
+//! let mut area = Area::new();
+//!
+//! area.set_token(State::YellowToken, 3).unwrap();
+//! area.set_token(State::RedToken, 0).unwrap();
+//!
+//! // Le jeton jaune occupe la colonne centrale
+//! assert!(HeuristicEvaluator.evaluate(&area, State::YellowToken) > 0);
+//! assert!(HeuristicEvaluator.evaluate(&area, State::RedToken) < 0);
+//! ```
+
+use super::analysis::{has_double_threat, ThreatReport};
+use super::connect_four::{Area, Objective, State};
+
+/// Score d’un alignement auquel ne manque qu’un jeton
+const OPEN_THREE_SCORE : i32 = 40;
+
+/// Score d’un alignement auquel manquent deux jetons
+const OPEN_TWO_SCORE : i32 = 4;
+
+/// Score d’une menace placée sur une ligne favorable à son auteur
+const THREAT_PARITY_SCORE : i32 = 60;
+
+/// Score d’une menace double, que l’adversaire ne peut pas parer
+const DOUBLE_THREAT_SCORE : i32 = 500;
+
+/// Poids de l’occupation des colonnes centrales
+const CENTER_WEIGHT : i32 = 2;
+
+/// Fonction d’évaluation d’une position
+pub trait Evaluator : Send + Sync {
+    /// Estime la valeur d’une position
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position à évaluer, dans laquelle aucun joueur n’a encore gagné
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///
+    /// # Retour
+    ///
+    /// Le score de la position du point de vue du joueur devant jouer: positif si la position lui
+    /// est favorable, négatif sinon. Le score doit rester très inférieur en valeur absolue à celui
+    /// d’une victoire (un million) et être opposé lorsque le joueur change.
+    fn evaluate(&self, area : &Area, token : State) -> i32;
+
+    /// Estime la valeur d’une position selon l’objectif des joueurs
+    ///
+    /// L’implémentation par défaut oppose le score de [`Evaluator::evaluate`] en misère, les
+    /// alignements presque formés y menaçant leur auteur plutôt que son adversaire.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position à évaluer, dans laquelle aucun joueur n’a encore formé d’alignement
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///  * `objective` : L’objectif des joueurs
+    fn evaluate_for(&self, area : &Area, token : State, objective : Objective) -> i32 {
+        return match objective {
+            Objective::Connect => self.evaluate(area, token),
+            Objective::Misere => -self.evaluate(area, token),
+        };
+    }
+}
+
+/// Évaluation considérant toute position non terminale comme neutre
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NeutralEvaluator;
+
+impl Evaluator for NeutralEvaluator {
+    fn evaluate(&self, _area : &Area, _token : State) -> i32 {
+        return 0;
+    }
+}
+
+/// Évaluation heuristique
+///
+/// Le score tient compte:
+///  * des alignements ouverts, auxquels ne manquent qu’un ou deux jetons
+///  * de l’occupation des colonnes centrales, appartenant à davantage d’alignements
+///  * de la parité des menaces (voir [`ThreatReport`]): une case complétant un alignement sur une
+///    ligne impaire (en partant du bas) profite au joueur ayant commencé la partie, une case sur
+///    une ligne paire au second joueur, ces derniers étant généralement contraints de laisser
+///    l’adversaire jouer sous la case en fin de partie.
+///  * des menaces doubles (voir [`has_double_threat`]), qui décident généralement de la partie.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct HeuristicEvaluator;
+
+impl Evaluator for HeuristicEvaluator {
+    fn evaluate(&self, area : &Area, token : State) -> i32 {
+        let config = *area.config();
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+        let mut score = 0;
+
+        for ((col, _), state) in area.iter() {
+            let weight = CENTER_WEIGHT * (config.cols - 1 - (2 * col).abs_diff(config.cols - 1)) as i32;
+
+            if state == token {
+                score += weight;
+            } else if state == opponent {
+                score -= weight;
+            }
+        }
+
+        for (dc, dr) in [(1, 0), (0, 1), (1, 1), (1, -1)].iter() {
+            for ((col, row), _) in area.iter() {
+                let cells : Option<Vec<(usize, usize)>> = (0..config.win_length as isize).map(|i| {
+                    let (mut c, r) = (col as isize + i * dc, row as isize + i * dr);
+
+                    if config.wrap_columns {
+                        c = c.rem_euclid(config.cols as isize);
+                    }
+
+                    if c < 0 || r < 0 || c as usize >= config.cols || r as usize >= config.rows {
+                        None
+                    } else {
+                        Some((c as usize, r as usize))
+                    }
+                }).collect();
+
+                // Les cases bloquées interrompent les alignements
+                let cells = match cells {
+                    Some(cells) if !cells.iter().any(|(c, r)| config.is_blocked(*c, *r)) => cells,
+                    _ => continue,
+                };
+
+                let own = cells.iter().filter(|cell| area[**cell] == token).count();
+                let other = cells.iter().filter(|cell| area[**cell] == opponent).count();
+
+                let (count, sign) = match (own, other) {
+                    (0, 0) => continue,
+                    (n, 0) => (n, 1),
+                    (0, n) => (n, -1),
+                    _ => continue,
+                };
+
+                if count + 1 == config.win_length {
+                    score += sign * OPEN_THREE_SCORE;
+                } else if count + 2 == config.win_length {
+                    score += sign * OPEN_TWO_SCORE;
+                }
+            }
+        }
+
+        let report = ThreatReport::new(area, token);
+
+        score += THREAT_PARITY_SCORE * (report.favorable(token) as i32 - report.favorable(opponent) as i32);
+
+        for (owner, sign) in [(token, 1), (opponent, -1)].iter() {
+            if has_double_threat(area, *owner) {
+                score += sign * DOUBLE_THREAT_SCORE;
+            }
+        }
+
+        return score;
+    }
+}
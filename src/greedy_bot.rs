@@ -0,0 +1,120 @@
+//! Implémentation d’une intelligence artificielle ne regardant qu’un coup à l’avance
+//!
+//! À chaque tour, l’intelligence artificielle:
+//!  1. joue le coup gagnant s’il en existe un
+//!  2. sinon, bloque le coup gagnant d’un adversaire s’il en existe un
+//!  3. sinon, joue dans la colonne disponible la plus centrale
+//!
+//! En misère (voir [`GreedyBot::set_objective`]), elle évite au contraire les colonnes où son jeton
+//! formerait un alignement et joue dans la plus centrale des autres.
+//!
+//! Dans la variante en trois dimensions (voir [`score_four`](super::score_four)), la tige la plus
+//! centrale est préférée de la même manière.
+//!
+//! Elle constitue un adversaire plus instructif que [`RandomBot`](super::random_bot::RandomBot)
+//! tout en restant facile à battre.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, Interface, State};
+//! use connect_four::greedy_bot::GreedyBot;
+//!
+//! let area : Area = "
+//!     .......
+//!     .......
+//!     .......
+//!     .......
+//!     .......
+//!     YYY.RR.
+//! ".parse().unwrap();
+//!
+//! let mut bot = GreedyBot::new("Robot glouton");
+//!
+//! // Les jetons rouges bloquent l’alignement jaune
+//! assert_eq!(bot.play(&area, State::RedToken).unwrap(), 3);
+//! ```
+
+use super::connect_four::{Interface, Area, Objective, State, PlayError};
+use super::score_four::{self, Area3, Interface3};
+
+/// Intelligence artificielle gloutonne
+pub struct GreedyBot {
+    #[doc(hidden)]
+    name : String,
+    #[doc(hidden)]
+    objective : Objective,
+}
+
+impl GreedyBot {
+    /// Initialise l’intelligence artificielle
+    ///
+    /// # Arguments
+    ///
+    ///  * `name` : Le nom donné à l’intelligence artificielle.
+    ///
+    /// # Retour
+    ///
+    /// Une instance de l’intelligence artificielle
+    pub fn new(name : &str) -> Self {
+        Self {
+            name: String::from(name),
+            objective: Objective::Connect,
+        }
+    }
+
+    /// Modifie l’objectif poursuivi par l’intelligence artificielle
+    ///
+    /// # Arguments
+    ///
+    ///  * `objective` : L’objectif des parties jouées
+    pub fn set_objective(&mut self, objective : Objective) {
+        self.objective = objective;
+    }
+}
+
+impl Interface for GreedyBot {
+    fn play(&mut self, area : &Area, token : State) -> Result<usize, PlayError> {
+        let cols = area.config().cols;
+        let mut columns = area.get_available_columns();
+
+        columns.sort_by_key(|col| (2 * col).abs_diff(cols - 1));
+
+        if self.objective == Objective::Misere {
+            let safe = columns.iter().find(|col| !matches!(area.is_winning_move(token, **col), Ok(true)));
+
+            return safe.or(columns.first()).copied().ok_or_else(|| PlayError::Other(String::from("Aucune colonne disponible")));
+        }
+
+        // Lors des parties à plus de deux joueurs, l’alignement de chacun des adversaires est bloqué
+        let opponents : Vec<State> = State::TOKENS.iter().copied().filter(|other| *other != token).collect();
+
+        let threats = area.immediate_threats(token);
+        let opponent_threats : Vec<usize> = opponents.iter().flat_map(|opponent| area.immediate_threats(*opponent)).collect();
+
+        let winning = columns.iter().find(|col| threats.contains(col));
+        let blocking = columns.iter().find(|col| opponent_threats.contains(col));
+
+        return winning.or(blocking).or(columns.first()).copied().ok_or_else(|| PlayError::Other(String::from("Aucune colonne disponible")));
+    }
+
+    fn name(&self) -> String {
+        return self.name.clone();
+    }
+}
+
+impl Interface3 for GreedyBot {
+    fn play_3d(&mut self, area : &Area3, token : State) -> Result<usize, PlayError> {
+        let side = score_four::SIDE;
+        let mut pins = area.available_pins();
+
+        pins.sort_by_key(|pin| (2 * (pin % side)).abs_diff(side - 1) + (2 * (pin / side)).abs_diff(side - 1));
+
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+
+        let winning = pins.iter().find(|pin| matches!(area.is_winning_move(token, **pin), Ok(true)));
+        let blocking = pins.iter().find(|pin| matches!(area.is_winning_move(opponent, **pin), Ok(true)));
+
+        return winning.or(blocking).or(pins.first()).copied().ok_or_else(|| PlayError::Other(String::from("Aucune tige disponible")));
+    }
+}
@@ -0,0 +1,278 @@
+//! Serveur HTTP exposant les parties via une API REST
+//!
+//! Les parties sont conservées par un [`GameServer`] et identifiées par un identifiant aléatoire
+//! (voir [`GameId`](super::server::GameId)), de sorte que les clients web ou mobiles peuvent
+//! jouer sans maintenir de connexion:
+//!  * `POST /games` : Crée une partie et la fourni (code `201`). Le corps, facultatif, indique la
+//!    configuration de la zone de jeu et la profondeur d’exploration d’un robot minimax adverse:
+//!    `{ "config": { "cols": 7, "rows": 6, "win_length": 4 }, "bot": 6, "bot_first": false }`,
+//!    la profondeur ne pouvant dépasser [`MAX_BOT_DEPTH`].
+//!    Sans robot, les coups des deux couleurs sont joués par les clients. Au plus [`MAX_GAMES`]
+//!    parties sont conservées à la fois.
+//!  * `POST /games/{id}/moves` : Joue le coup `{ "column": 3 }` avec le jeton devant jouer puis,
+//!    si la partie n’est pas terminée, le coup du robot adverse. Fourni la partie après ces coups.
+//!  * `GET /games/{id}` : Fourni la partie.
+//!  * `DELETE /games/{id}` : Retire la partie du serveur (code `204`), libérant sa place.
+//!
+//! Une partie est décrite par le document suivant:
+//!
+//! ```json
+//! {
+//!     "id": "…",
+//!     "area": { "config": { … }, "columns": [[…], …] },
+//!     "code": "…",
+//!     "moves": [3, 3, 4],
+//!     "to_move": "RedToken",
+//!     "winner": null,
+//!     "winning_cells": [],
+//!     "finished": false,
+//!     "bot": 6
+//! }
+//! ```
+//!
+//! La zone de jeu suit le schéma `connect_four/area` du module [`json`](super::json) et `code`
+//! est le code de la position (voir [`Area::to_code`]). `to_move` vaut `null` une fois la partie
+//! terminée, `winner` désigne le jeton ayant formé un alignement.
+//!
+//! En cas d’erreur, le serveur répond `{ "error": "<description>" }` avec le code `404` pour une
+//! partie inconnue, `409` pour un coup joué dans une partie terminée, `422` pour une colonne
+//! invalide ou remplie, `400` pour une configuration ou une profondeur invalide et `503` lorsque le
+//! serveur conserve déjà [`MAX_GAMES`] parties. Un corps de
+//! requête mal formé est refusé par `axum` avec un message textuel.
+//!
+//! Ce module n’est disponible qu’avec la fonctionnalité `http`.
+//!
+//! # Exemple
+//!
+//! ```rust,no_run
+//! let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+//!
+//! runtime.block_on(async {
+//!     let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await.unwrap();
+//!
+//!     connect_four::http::serve(listener).await.unwrap();
+//! });
+//! ```
+
+use super::connect_four::{Area, AreaConfig, Error, Interface, State};
+use super::minimax_bot::MinimaxBot;
+use super::server::{GameId, GameServer};
+use axum::extract::{Path, State as Shared};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::sync::{Arc, Mutex};
+
+/// Profondeur d’exploration maximale du robot adverse, afin de borner le temps de réponse
+pub const MAX_BOT_DEPTH : usize = 12;
+
+/// Nombre maximal de parties conservées, afin de borner la mémoire occupée par le serveur
+pub const MAX_GAMES : usize = 10_000;
+
+/// Partie conservée par le serveur
+struct Game {
+    area : Area,
+    moves : Vec<usize>,
+    bot : Option<usize>,
+}
+
+impl Game {
+    /// Joue le coup du robot adverse si la partie n’est pas terminée
+    fn play_bot(&mut self) -> Result<(), ApiError> {
+        let (Some(depth), Some(token)) = (self.bot, self.area.next_token()?) else {
+            return Ok(());
+        };
+
+        let column = MinimaxBot::new("Robot", depth).play(&self.area, token)
+            .map_err(|e| ApiError::from(Error::InterfaceFailure(e)))?;
+
+        return self.play(token, column);
+    }
+
+    /// Joue un coup et l’ajoute à la liste des coups
+    fn play(&mut self, token : State, column : usize) -> Result<(), ApiError> {
+        self.area.set_token(token, column)?;
+        self.moves.push(column);
+
+        return Ok(());
+    }
+
+    /// Décrit la partie
+    fn view(&self, id : GameId) -> Result<GameView, ApiError> {
+        let to_move = self.area.next_token()?;
+
+        return Ok(GameView {
+            id: id.to_string(),
+            area: self.area.clone(),
+            code: self.area.to_code(to_move.unwrap_or(State::YellowToken))?,
+            moves: self.moves.clone(),
+            to_move,
+            winner: self.area.winning_cells().first().map(|cell| self.area[*cell]),
+            winning_cells: self.area.winning_cells().to_vec(),
+            finished: to_move.is_none(),
+            bot: self.bot,
+        });
+    }
+}
+
+/// Parties en cours, chacune verrouillée indépendamment afin que la réflexion d’un robot ne
+/// bloque pas les autres parties
+type Games = Arc<GameServer<Game>>;
+
+/// Document décrivant une partie
+#[derive(Serialize)]
+struct GameView {
+    id : String,
+    area : Area,
+    code : String,
+    moves : Vec<usize>,
+    to_move : Option<State>,
+    winner : Option<State>,
+    winning_cells : Vec<(usize, usize)>,
+    finished : bool,
+    bot : Option<usize>,
+}
+
+/// Corps de la requête de création d’une partie
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct NewGame {
+    config : AreaConfig,
+    bot : Option<usize>,
+    bot_first : bool,
+}
+
+/// Corps de la requête jouant un coup
+#[derive(Deserialize)]
+struct NewMove {
+    column : usize,
+}
+
+/// Erreur renvoyée au client
+struct ApiError {
+    status : StatusCode,
+    message : String,
+}
+
+impl ApiError {
+    fn not_found() -> Self {
+        return Self { status: StatusCode::NOT_FOUND, message: String::from("Partie inconnue") };
+    }
+
+    fn internal<E : ToString>(e : E) -> Self {
+        return Self { status: StatusCode::INTERNAL_SERVER_ERROR, message: e.to_string() };
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(e : Error) -> Self {
+        let status = match e {
+            Error::UnknownGame(_) => StatusCode::NOT_FOUND,
+            Error::GameAlreadyFinished => StatusCode::CONFLICT,
+            Error::InvalidColumn | Error::FilledColumn => StatusCode::UNPROCESSABLE_ENTITY,
+            Error::InterfaceFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        };
+
+        return Self { status, message: e.to_string() };
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        return (self.status, Json(serde_json::json!({ "error": self.message }))).into_response();
+    }
+}
+
+/// Recherche une partie, un identifiant mal formé désignant une partie inconnue
+fn find(games : &Games, id : &str) -> Result<(GameId, Arc<Mutex<Game>>), ApiError> {
+    let id : GameId = id.parse().map_err(|_| ApiError::not_found())?;
+
+    return Ok((id, games.get(id)?));
+}
+
+/// Exécute une opération sur une partie hors de l’exécuteur, la réflexion du robot pouvant être
+/// longue et la partie restant verrouillée pendant ce temps
+async fn with_game<F>(game : Arc<Mutex<Game>>, f : F) -> Result<Json<GameView>, ApiError>
+    where F : FnOnce(&mut Game) -> Result<GameView, ApiError> + Send + 'static {
+    return tokio::task::spawn_blocking(move || {
+        let mut game = game.lock().map_err(ApiError::internal)?;
+
+        return f(&mut game).map(Json);
+    }).await.map_err(ApiError::internal)?;
+}
+
+async fn create_game(Shared(games) : Shared<Games>, body : Option<Json<NewGame>>) -> Result<(StatusCode, Json<GameView>), ApiError> {
+    let NewGame { config, bot, bot_first } = body.map(|Json(body)| body).unwrap_or_default();
+    let config = AreaConfig::new(config.cols, config.rows, config.win_length)?;
+
+    if bot.is_some_and(|depth| depth > MAX_BOT_DEPTH) {
+        return Err(ApiError { status: StatusCode::BAD_REQUEST, message: format!("La profondeur du robot ne peut pas dépasser {}", MAX_BOT_DEPTH) });
+    }
+
+    if games.len() >= MAX_GAMES {
+        return Err(ApiError { status: StatusCode::SERVICE_UNAVAILABLE, message: format!("Le serveur ne peut pas conserver plus de {} parties", MAX_GAMES) });
+    }
+
+    let id = games.create(Game { area: Area::with_config(config)?, moves: Vec::new(), bot }).map_err(ApiError::internal)?;
+
+    let view = with_game(games.get(id)?, move |game| {
+        if bot_first {
+            game.play_bot()?;
+        }
+
+        return game.view(id);
+    }).await?;
+
+    return Ok((StatusCode::CREATED, view));
+}
+
+async fn get_game(Shared(games) : Shared<Games>, Path(id) : Path<String>) -> Result<Json<GameView>, ApiError> {
+    let (id, game) = find(&games, &id)?;
+
+    // La partie reste verrouillée pendant la réflexion du robot: l’attendre ne doit pas bloquer
+    // l’exécuteur
+    return with_game(game, move |game| game.view(id)).await;
+}
+
+async fn play_move(Shared(games) : Shared<Games>, Path(id) : Path<String>, Json(body) : Json<NewMove>) -> Result<Json<GameView>, ApiError> {
+    let (id, game) = find(&games, &id)?;
+
+    return with_game(game, move |game| {
+        let token = game.area.next_token()?.ok_or(Error::GameAlreadyFinished)?;
+
+        game.play(token, body.column)?;
+        game.play_bot()?;
+
+        return game.view(id);
+    }).await;
+}
+
+async fn delete_game(Shared(games) : Shared<Games>, Path(id) : Path<String>) -> Result<StatusCode, ApiError> {
+    let id : GameId = id.parse().map_err(|_| ApiError::not_found())?;
+
+    games.remove(id)?;
+
+    return Ok(StatusCode::NO_CONTENT);
+}
+
+/// Crée le routeur de l’API, dont les parties sont conservées en mémoire
+pub fn router() -> Router {
+    return Router::new()
+        .route("/games", post(create_game))
+        .route("/games/{id}", get(get_game).delete(delete_game))
+        .route("/games/{id}/moves", post(play_move))
+        .with_state(Games::default());
+}
+
+/// Sert l’API sur le port d’écoute donné jusqu’à l’arrêt du serveur
+///
+/// # Liste des erreurs possibles
+///
+/// Toute erreur d’entrée/sortie survenant lors de l’acceptation des connexions
+pub async fn serve(listener : tokio::net::TcpListener) -> io::Result<()> {
+    return axum::serve(listener, router()).await;
+}
@@ -0,0 +1,360 @@
+//! Traduction des messages destinés aux utilisateurs
+//!
+//! Les messages sont identifiés par une clé et traduits selon la langue choisie pour l’ensemble
+//! du programme ([`set_lang`]), le français par défaut. Le catalogue des messages regroupe pour
+//! chaque clé ses traductions française et anglaise.
+//!
+//! Les messages contiennent des emplacements `{}` remplacés, dans l’ordre, par les arguments de la
+//! macro [`tr!`](crate::tr). Une clé absente du catalogue est affichée telle quelle.
+//!
+//! Les descriptions des erreurs de la bibliothèque, des évaluations de coups et des résultats sont
+//! traduites de la même manière.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{tr, Error};
+//! use connect_four::i18n::{self, Lang};
+//!
+//! i18n::set_lang(Lang::English);
+//!
+//! assert_eq!(tr!("result.won", "Alice"), "Alice won");
+//! assert_eq!(Error::FilledColumn.to_string(), "The chosen column is already full");
+//!
+//! i18n::set_lang("fr_FR.UTF-8".parse().unwrap());
+//!
+//! assert_eq!(tr!("result.won", "Alice"), "Alice a gagné");
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Langue des messages
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum Lang {
+    /// Français
+    #[default]
+    French,
+    /// Anglais
+    English,
+}
+
+impl Lang {
+    /// Liste des langues supportées
+    pub const ALL : [Lang; 2] = [Lang::French, Lang::English];
+
+    /// Code de la langue (`fr` ou `en`), accepté par [`Lang::from_str`]
+    pub fn code(&self) -> &'static str {
+        return match self {
+            Lang::French => "fr",
+            Lang::English => "en",
+        };
+    }
+
+    /// Choisi la langue selon les variables d’environnement `LC_ALL`, `LC_MESSAGES` puis `LANG`
+    ///
+    /// Le français est choisi si aucune de ces variables ne désigne une langue supportée.
+    pub fn detect() -> Self {
+        return ["LC_ALL", "LC_MESSAGES", "LANG"].iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default();
+    }
+}
+
+impl Display for Lang {
+    fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(self.code());
+    }
+}
+
+/// Lit un code de langue (`fr`, `en`) ou une locale (`en_US.UTF-8`)
+impl FromStr for Lang {
+    type Err = ();
+
+    fn from_str(s : &str) -> Result<Self, Self::Err> {
+        let code = s.split(['_', '-', '.']).next().unwrap_or_default().to_lowercase();
+
+        return Lang::ALL.iter().find(|lang| lang.code() == code).copied().ok_or(());
+    }
+}
+
+/// Langue choisie, indice dans [`Lang::ALL`]
+static LANG : AtomicU8 = AtomicU8::new(0);
+
+/// Choisi la langue de l’ensemble des messages
+pub fn set_lang(lang : Lang) {
+    LANG.store(Lang::ALL.iter().position(|l| *l == lang).unwrap_or(0) as u8, Ordering::Relaxed);
+}
+
+/// Fourni la langue choisie
+pub fn lang() -> Lang {
+    return Lang::ALL.get(LANG.load(Ordering::Relaxed) as usize).copied().unwrap_or_default();
+}
+
+/// Fourni le message de clé donnée dans la langue choisie, ou la clé si elle est inconnue
+pub fn message(key : &str) -> &str {
+    let Some((_, french, english)) = MESSAGES.iter().find(|(k, _, _)| *k == key) else {
+        return key;
+    };
+
+    return match lang() {
+        Lang::French => french,
+        Lang::English => english,
+    };
+}
+
+/// Remplace dans l’ordre les emplacements `{}` d’un message par les arguments donnés
+///
+/// Les emplacements sans argument correspondant sont conservés.
+pub fn format(template : &str, args : &[&dyn Display]) -> String {
+    let mut args = args.iter();
+    let mut parts = template.split("{}");
+    let mut text = String::from(parts.next().unwrap_or_default());
+
+    for part in parts {
+        match args.next() {
+            Some(arg) => text.push_str(&arg.to_string()),
+            None => text.push_str("{}"),
+        }
+
+        text.push_str(part);
+    }
+
+    return text;
+}
+
+/// Traduit un message et remplace ses emplacements `{}` par les arguments donnés
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::tr;
+///
+/// connect_four::i18n::set_lang(connect_four::i18n::Lang::French);
+///
+/// assert_eq!(tr!("result.draw"), "Match nul");
+/// assert_eq!(tr!("play.turn", "Alice", "🟡"), "À Alice de jouer (🟡)");
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($key:expr) => {
+        ::std::string::String::from($crate::i18n::message($key))
+    };
+    ($key:expr, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::format($crate::i18n::message($key), &[$(&$arg as &dyn ::std::fmt::Display),+])
+    };
+}
+
+/// Catalogue des messages: clé, traduction française puis anglaise
+const MESSAGES : &[(&str, &str, &str)] = &[
+    // Erreurs de la bibliothèque
+    ("error.filled_column", "La colonne choisie est déjà pleine", "The chosen column is already full"),
+    ("error.invalid_column", "La colonne choisie est invalide", "The chosen column is invalid"),
+    ("error.not_a_token", "L’élément fourni n’est pas un jeton", "The given element is not a token"),
+    ("error.invalid_player_id", "Le joueur {} ne participe pas à la partie", "Player {} is not part of the game"),
+    ("error.invalid_player_count", "Nombre de joueurs non supporté: {}", "Unsupported number of players: {}"),
+    ("error.invalid_config", "La configuration de la zone de jeu est invalide", "The board configuration is invalid"),
+    ("error.game_already_finished", "La partie est déjà terminée", "The game is already finished"),
+    ("error.game_already_started", "La partie est déjà commencée", "The game has already started"),
+    ("error.interface_failure", "L’interface du joueur a échoué: {}", "The player interface failed: {}"),
+    ("error.invalid_position", "La position décrite est impossible", "The described position is impossible"),
+    ("error.invalid_row", "La ligne choisie est invalide", "The chosen row is invalid"),
+    ("error.empty_column", "La colonne choisie est vide", "The chosen column is empty"),
+    ("error.timeout", "Le joueur {} a dépassé son temps de réflexion", "Player {} ran out of time"),
+    ("error.aborted", "La partie a été interrompue", "The game was aborted"),
+    ("error.pop_not_allowed", "Retirer un jeton n’est possible qu’en Pop Out", "Popping a token is only allowed in Pop Out"),
+    ("error.not_own_token", "Le jeton du bas de la colonne appartient à l’adversaire", "The bottom token of the column belongs to the opponent"),
+    ("error.swap_not_allowed", "L’échange des camps n’est possible qu’en réponse au premier coup", "Swapping sides is only allowed in reply to the first move"),
+    ("error.no_balanced_position", "Aucune position de départ équilibrée n’a été trouvée", "No balanced starting position was found"),
+    ("error.record_mismatch", "La partie rejouée ne correspond pas à l’enregistrement", "The replayed game does not match the record"),
+    ("error.unknown_game", "Aucune partie ne porte l’identifiant {}", "No game has the identifier {}"),
+    ("error.nothing_to_undo", "Aucun coup à annuler", "There is no move to undo"),
+    ("error.io", "Erreur d’entrée/sortie: {}", "Input/output error: {}"),
+    ("error.disconnected", "Le joueur s’est déconnecté", "The player disconnected"),
+    ("error.json_invalid", "Le document JSON est invalide: {}", "The JSON document is invalid: {}"),
+    ("error.json_schema", "Le document décrit un objet {} au lieu de {}", "The document describes a {} object instead of {}"),
+    ("error.json_version", "La version {} du schéma n’est pas supportée (version maximale: {})", "Schema version {} is not supported (latest version: {})"),
+    ("error.handshake_io", "Erreur de communication: {}", "Communication error: {}"),
+    ("error.handshake_version", "Version du protocole incompatible: {} au lieu de {}", "Incompatible protocol version: {} instead of {}"),
+    ("error.handshake_no_version", "Le pair n’utilise pas la version {} du protocole", "The peer does not use version {} of the protocol"),
+    ("error.handshake_config", "Zone de jeu incompatible: {} au lieu de {}", "Incompatible board: {} instead of {}"),
+    ("error.handshake_board", "{}x{} ({} jetons à aligner)", "{}x{} ({} tokens to align)"),
+    ("error.handshake_unauthorized", "Jeton d’authentification invalide", "Invalid authentication token"),
+    ("error.handshake_rejected", "Connexion refusée par l’hôte: {}", "Connection refused by the host: {}"),
+    // Jetons et évaluations
+    ("token.empty", "vide", "empty"),
+    ("token.yellow", "jaune", "yellow"),
+    ("token.red", "rouge", "red"),
+    ("token.green", "vert", "green"),
+    ("token.blue", "bleu", "blue"),
+    ("score.wins_now", "gagne immédiatement", "wins immediately"),
+    ("score.wins_in", "gagne en {} coups", "wins in {} moves"),
+    ("score.loses_next", "perd au coup suivant", "loses on the next move"),
+    ("score.loses_in", "perd en {} coups", "loses in {} moves"),
+    ("score.draw", "match nul", "draw"),
+    // En-têtes des tableaux
+    ("table.player", "Joueur", "Player"),
+    ("table.games", "Parties", "Games"),
+    ("table.wins", "Victoires", "Wins"),
+    ("table.draws", "Nuls", "Draws"),
+    ("table.losses", "Défaites", "Losses"),
+    ("table.length", "Durée (coups)", "Moves (avg.)"),
+    // Résultats
+    ("result.won", "{} a gagné", "{} won"),
+    ("result.tokens_won", "Les {} ont gagné", "{} won"),
+    ("result.timeout", "{} a dépassé son temps de réflexion", "{} ran out of time"),
+    ("result.draw", "Match nul", "Draw"),
+    ("result.interrupted", "Partie interrompue: {}", "Game interrupted: {}"),
+    // Partie en ligne de commande
+    ("play.turn", "À {} de jouer ({})", "{} to move ({})"),
+    ("play.forced", "Une seule possibilité: {}", "Only one possible move: {}"),
+    ("play.prompt", "Choisissez une position [{}] (ou hint, undo, save/load <fichier>)", "Choose a column [{}] (or hint, undo, save/load <file>)"),
+    ("render.level", "Niveau {}", "Level {}"),
+    ("play.prompt_pin", "Choisissez une tige [{}]", "Choose a pin [{}]"),
+    ("play.misere_rules", "Misère: le premier joueur alignant ses jetons perd la partie", "Misère: the first player to connect their tokens loses the game"),
+    ("play.misere_perfect", "Le robot parfait ne sait pas jouer en misère, le robot difficile le remplace", "The perfect bot cannot play misère, the hard bot replaces it"),
+    ("play.swap_offer", "Règle du gâteau: prendre le camp de votre adversaire et son premier coup? [y/n]", "Swap rule: take over your opponent’s side and first move? [y/n]"),
+    ("play.prompt_pop_out", "Choisissez une position [{}] (ou pop <position>, hint, undo, save/load <fichier>)", "Choose a column [{}] (or pop <column>, hint, undo, save/load <file>)"),
+    ("play.prompt_chat", "Choisissez une position [{}] (ou hint, chat <message>)", "Choose a column [{}] (or hint, chat <message>)"),
+    ("play.clocks", "Temps restant: {}", "Time remaining: {}"),
+    ("play.default_name", "Joueur {}", "Player {}"),
+    ("party.too_many_players", "{} joueurs humains pour {} places", "{} human players for {} seats"),
+    ("party.won", "{} ({}) a gagné", "{} ({}) won"),
+    ("play.saved", "Partie sauvegardée dans {}", "Game saved to {}"),
+    ("play.save_failed", "Sauvegarde impossible: {}", "Saving failed: {}"),
+    ("play.loaded", "Partie chargée depuis {}", "Game loaded from {}"),
+    ("play.load_failed", "Chargement impossible: {}", "Loading failed: {}"),
+    ("play.handicap_failed", "Handicap impossible: {}", "Invalid handicap: {}"),
+    ("play.scramble_searching", "Recherche d’une position équilibrée de {} coups…", "Looking for a balanced position of {} moves…"),
+    ("play.scramble_failed", "Position de départ impossible: {}", "Invalid starting position: {}"),
+    ("play.blocked_failed", "Cases bloquées impossibles: {}", "Invalid blocked cells: {}"),
+    ("play.loaded_finished", "La partie chargée est déjà terminée", "The loaded game is already finished"),
+    ("play.book_failed", "Chargement de la bibliothèque d’ouvertures impossible: {}", "Loading the opening book failed: {}"),
+    ("thinking.depth", "{} réfléchit… profondeur {}, {} positions", "{} is thinking… depth {}, {} nodes"),
+    ("thinking.nodes", "{} réfléchit… {} positions", "{} is thinking… {} nodes"),
+    ("interrupt.requested", "Interruption de la partie… (validez la saisie en cours avec Entrée, ou Ctrl-C de nouveau pour quitter immédiatement)", "Stopping the game… (press Enter to submit any pending input, or Ctrl-C again to quit immediately)"),
+    ("interrupt.aborted", "Partie interrompue", "Game aborted"),
+    ("interrupt.handler_failed", "Installation du gestionnaire de Ctrl-C impossible: {}", "Installing the Ctrl-C handler failed: {}"),
+    ("play.tui_failed", "Affichage plein écran impossible: {}", "Full-screen display failed: {}"),
+    ("play.tui_restore_failed", "Restauration du terminal impossible: {}", "Restoring the terminal failed: {}"),
+    ("prompt.players", "Nombre de joueurs", "Number of players"),
+    ("prompt.custom_board", "Personnaliser le plateau? [y/n]", "Customize the board? [y/n]"),
+    ("prompt.cols", "Nombre de colonnes", "Number of columns"),
+    ("prompt.rows", "Nombre de lignes", "Number of rows"),
+    ("prompt.win_length", "Nombre de jetons à aligner", "Number of tokens to align"),
+    ("prompt.first_player", "Joueur commençant la partie", "Player moving first"),
+    ("prompt.first_player_network", "Joueur commençant la partie (1: vous, 2: votre adversaire)", "Player moving first (1: you, 2: your opponent)"),
+    ("prompt.minutes", "Temps de réflexion par joueur en minutes (0 pour illimité)", "Thinking time per player in minutes (0 for unlimited)"),
+    ("prompt.increment", "Incrément par coup en secondes", "Increment per move in seconds"),
+    ("prompt.player_name", "Nom du joueur {}", "Name of player {}"),
+    ("prompt.level", "Niveau de l’adversaire", "Opponent level"),
+    ("prompt.save_interrupted", "Fichier où sauvegarder la partie (vide pour ne pas sauvegarder)", "File to save the game to (empty to skip saving)"),
+    ("prompt.analyze", "Analyser la partie? [y/n]", "Analyze the game? [y/n]"),
+    ("prompt.again", "Rejouer? [y/n]", "Play again? [y/n]"),
+    // Évaluation, conseils et analyse
+    ("eval.wins_now", "Évaluation: {} gagne immédiatement", "Evaluation: {} wins immediately"),
+    ("eval.wins_in", "Évaluation: {} gagne en {} coups", "Evaluation: {} wins in {} moves"),
+    ("eval.wins_next", "Évaluation: {} gagne au coup suivant", "Evaluation: {} wins on the next move"),
+    ("eval.draw", "Évaluation: match nul", "Evaluation: draw"),
+    ("eval.even", "Évaluation: 0.0", "Evaluation: 0.0"),
+    ("eval.advantage", "Évaluation: +{} pour {}", "Evaluation: +{} for {}"),
+    ("eval.failed", "Évaluation impossible: {}", "Evaluation failed: {}"),
+    ("hint.failed", "Conseil impossible: {}", "Hint unavailable: {}"),
+    ("hint.blocks", "bloque la victoire adverse", "blocks the opponent’s win"),
+    ("hint.only_safe", "seul coup ne perdant pas", "only move that does not lose"),
+    ("hint.draw", "mène au match nul", "leads to a draw"),
+    ("hint.estimate", "meilleure estimation: {}", "best estimate: {}"),
+    ("hint.advice", "Conseil: colonne {} ({})", "Hint: column {} ({})"),
+    ("hint.plan_own", "Plan à long terme: conserver la menace de la colonne {}, ligne {}; l’adversaire devra jouer dessous en fin de partie", "Long-term plan: keep the threat in column {}, row {}; the opponent will have to play below it late in the game"),
+    ("hint.plan_opponent", "Attention: la menace adverse de la colonne {}, ligne {} décidera la fin de partie", "Beware: the opponent’s threat in column {}, row {} will decide the endgame"),
+    ("review.failed", "Analyse impossible: {}", "Analysis failed: {}"),
+    ("review.best", " - meilleur coup: {} [{}]", " - best move: {} [{}]"),
+    ("review.inaccuracies", "Imprécisions", "Inaccuracies"),
+    ("review.mistakes", "Erreurs", "Mistakes"),
+    ("review.blunders", "Gaffes", "Blunders"),
+    ("review.double_threat", " - permet une menace double", " - allowed a double threat"),
+    ("review.double_threats", "Menaces doubles permises", "Double threats allowed"),
+    // Interface plein écran
+    ("tui.title", "Puissance 4", "Connect Four"),
+    ("tui.players", "Joueurs", "Players"),
+    ("tui.evaluation", "Évaluation", "Evaluation"),
+    ("tui.history", "Historique", "History"),
+    ("tui.finished", "Partie terminée", "Game over"),
+    ("tui.thinking", "{} réfléchit…", "{} is thinking…"),
+    ("tui.wins_in", "{} gagne en {} coups", "{} wins in {} moves"),
+    ("tui.advantage", "+{} pour {}", "+{} for {}"),
+    ("tui.no_column", "Aucune colonne disponible", "No column available"),
+    ("tui.prompt", "À {} de jouer ({}) — ←/→ ou souris choisir, Entrée ou clic jouer, q quitter", "{} to move ({}) — ←/→ or mouse to choose, Enter or click to play, q to quit"),
+    ("undo.done", "Coup annulé ({} annulations restantes)", "Move taken back ({} undos left)"),
+    ("undo.nothing", "Aucun coup à annuler", "There is no move to take back"),
+    ("undo.limit", "Limite de {} annulations atteinte", "Limit of {} undos reached"),
+    // Relecture d’une partie
+    ("replay.invalid", "Partie invalide: {}", "Invalid game: {}"),
+    ("replay.initial", "Position initiale ({} coups)", "Initial position ({} moves)"),
+    ("replay.move", "Coup {}/{}: joueur {} ({}) en colonne {}", "Move {}/{}: player {} ({}) in column {}"),
+    ("replay.winning", "Alignement gagnant (colonne, ligne): {}", "Winning line (column, row): {}"),
+    ("replay.unfinished", "Partie non terminée", "Game not finished"),
+    ("replay.help", "[n] suivant | [p] précédent | [j <coup>] aller au coup | [q] quitter", "[n] next | [p] previous | [j <move>] jump to move | [q] quit"),
+    ("replay.no_move", "Aucun coup dans cette direction", "No move in that direction"),
+    ("replay.invalid_command", "Commande invalide", "Invalid command"),
+    // Résolution de positions et bibliothèque d’ouvertures
+    ("solve.invalid", "Position invalide: {}", "Invalid position: {}"),
+    ("solve.failed", "Résolution impossible: {}", "Solving failed: {}"),
+    ("solve.to_move", "À {} de jouer", "{} to move"),
+    ("solve.column", "Colonne {}: {}", "Column {}: {}"),
+    ("solve.best", "Meilleur coup: colonne {} ({})", "Best move: column {} ({})"),
+    ("book.failed", "Génération impossible: {}", "Generation failed: {}"),
+    ("book.saved", "{} positions enregistrées dans {}", "{} positions saved to {}"),
+    ("book.save_failed", "Enregistrement impossible: {}", "Saving failed: {}"),
+    // Suites de coups écrites à l’avance
+    ("script.invalid", "Suite de coups invalide: {}", "Invalid move sequence: {}"),
+    ("script.exhausted", "La suite de coups est épuisée", "The move sequence is exhausted"),
+    ("script.unfinished", "Partie non terminée après {} coups", "Game not finished after {} moves"),
+    ("script.illegal", "Coup {} illégal (colonne {}): {}", "Illegal move {} (column {}): {}"),
+    ("script.ignored", "{} coups ignorés après la fin de la partie", "{} moves ignored after the end of the game"),
+    // Confrontations, classements et statistiques
+    ("arena.interrupted", "Série interrompue: {}", "Match interrupted: {}"),
+    ("arena.summary", "{} contre {}: {} victoires, {} nuls, {} défaites", "{} against {}: {} wins, {} draws, {} losses"),
+    ("arena.score", "Score: {} % (intervalle de confiance à 95 %: {} % - {} %)", "Score: {} % (95 % confidence interval: {} % - {} %)"),
+    ("arena.elo", "Écart Elo estimé: {}", "Estimated Elo difference: {}"),
+    ("match.score", "Match: {} {} - {} {} ({} nuls)", "Match: {} {} - {} {} ({} draws)"),
+    ("match.won", "{} remporte le match", "{} wins the match"),
+    ("match.draw", "Match nul", "The match is drawn"),
+    ("tournament.interrupted", "Tournoi interrompu: {}", "Tournament interrupted: {}"),
+    ("ratings.empty", "Aucune partie classée", "No rated game"),
+    ("ratings.load_failed", "Chargement du classement impossible: {}", "Loading the ratings failed: {}"),
+    ("ratings.update_failed", "Mise à jour du classement impossible: {}", "Updating the ratings failed: {}"),
+    ("stats.empty", "Aucune partie enregistrée", "No recorded game"),
+    ("stats.empty_player", "Aucune partie enregistrée pour {}", "No recorded game for {}"),
+    ("stats.summary", "{} parties, {} victoires, {} nuls, {} défaites, {} coups en moyenne", "{} games, {} wins, {} draws, {} losses, {} moves on average"),
+    ("stats.versus", "{} contre {}: {}", "{} against {}: {}"),
+    ("stats.total", "Total: {}", "Total: {}"),
+    ("stats.load_failed", "Chargement des statistiques impossible: {}", "Loading the statistics failed: {}"),
+    ("stats.update_failed", "Mise à jour des statistiques impossible: {}", "Updating the statistics failed: {}"),
+    ("archive.failed", "Archivage de la partie impossible: {}", "Archiving the game failed: {}"),
+    // Parties en réseau et serveur HTTP
+    ("net.your_name", "Votre nom", "Your name"),
+    ("net.waiting", "En attente d’un adversaire sur {}…", "Waiting for an opponent on {}…"),
+    ("net.unexpected", "Message inattendu: {}", "Unexpected message: {}"),
+    ("net.connect_failed", "Connexion impossible: {}", "Connection failed: {}"),
+    ("net.opponent", "Partie contre {}", "Game against {}"),
+    ("net.lost", "Connexion perdue ({}), reconnexion…", "Connection lost ({}), reconnecting…"),
+    ("net.resume_failed", "Partie interrompue: reconnexion impossible", "Game interrupted: reconnection failed"),
+    ("net.chat_failed", "Envoi du message impossible: {}", "Sending the message failed: {}"),
+    ("net.result_failed", "Envoi du résultat impossible: {}", "Sending the result failed: {}"),
+    ("http.serving", "API servie sur http://{}", "API served on http://{}"),
+    ("http.stopped", "Serveur interrompu: {}", "Server stopped: {}"),
+    // Arguments de la ligne de commande
+    ("args.invalid_depth", "Profondeur invalide: {}", "Invalid depth: {}"),
+    ("args.unknown_level", "Niveau inconnu: {} (niveaux: {}/glouton/minimax:<profondeur>)", "Unknown level: {} (levels: {}/glouton/minimax:<depth>)"),
+    ("args.handicap_format", "Format attendu: <joueur>:<colonnes>, par exemple 2:3,3", "Expected format: <player>:<columns>, for example 2:3,3"),
+    ("args.blocked_format", "Format attendu: <colonne>:<ligne>, par exemple 3:0", "Expected format: <column>:<row>, for example 3:0"),
+    ("args.party_bot", "Seuls les niveaux facile et glouton savent jouer à plus de deux joueurs", "Only the facile and glouton levels can play with more than two players"),
+    ("args.score_four_bot", "Seuls les niveaux facile et glouton savent jouer en trois dimensions", "Only the facile and glouton levels can play in three dimensions"),
+    ("args.board_format", "Format attendu: <colonnes>x<lignes>[x<jetons à aligner>][c], le suffixe c désignant un plateau cylindrique", "Expected format: <columns>x<rows>[x<tokens to align>][c], the c suffix denoting a cylinder board"),
+    ("args.match_format", "Le nombre de parties d’un match doit être impair", "The number of games of a match must be odd"),
+    ("args.time_format", "Format attendu: <minutes>[+<incrément en secondes>]", "Expected format: <minutes>[+<increment in seconds>]"),
+];
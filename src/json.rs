@@ -0,0 +1,176 @@
+//! Représentation JSON des zones de jeu, des coups et des parties
+//!
+//! Chaque objet est encapsulé dans un document indiquant son type et la version du schéma
+//! utilisé, afin que les interfaces web ou écrites dans d’autres langages puissent détecter un
+//! document qu’elles ne savent pas lire:
+//!
+//! ```json
+//! { "schema": "connect_four/record", "version": 1, "data": { ... } }
+//! ```
+//!
+//! Les schémas des données sont les suivants:
+//!  * zone de jeu (`connect_four/area`): `{ "config": { "rows", "cols", "win_length" },
+//!    "columns": [[jetons de la colonne 0 en partant du bas], …] }`, chaque jeton valant
+//!    `"YellowToken"` ou `"RedToken"`
+//!  * coup (`connect_four/move`): `{ "player", "column", "row", "token", "kind", "think_time" }`,
+//!    `kind` valant `"Drop"` pour un ajout ou `"Pop"` pour un retrait (variante Pop Out) et
+//!    `think_time` le temps de réflexion `{ "secs", "nanos" }`. Le nombre de positions explorées,
+//!    `"nodes"`, n’est présent que si le joueur les a comptabilisées.
+//!  * partie (`connect_four/record`): `{ "config", "first_player", "player_one_token", "moves" }`,
+//!    `moves` donnant les colonnes jouées du premier au dernier coup. Les parties de la variante
+//!    Pop Out ont en plus `"variant": "PopOut"` et, s’il y en a, `"pops"` donnant les indices des
+//!    retraits dans `moves`. Les parties jouées via le moteur ont également `"think_times"`,
+//!    donnant le temps de réflexion de chaque coup, et éventuellement `"nodes"`, donnant le
+//!    nombre de positions explorées pour chaque coup (`null` si le joueur ne les a pas
+//!    comptabilisées)
+//!
+//! Les données seules, sans document les encapsulant, sont également acceptées à la lecture et
+//! considérées comme de version 0. C’est notamment le cas des parties sauvegardées par les
+//! versions précédentes de l’interface en ligne de commande.
+//!
+//! Ce module n’est disponible qu’avec la fonctionnalité `serde`.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, AreaConfig, GameRecord};
+//! use connect_four::json::Json;
+//!
+//! let record = GameRecord::from_notation("4453", AreaConfig::default()).unwrap();
+//! let json = record.to_json().unwrap();
+//!
+//! assert!(json.starts_with(r#"{"schema":"connect_four/record","version":1"#));
+//! assert_eq!(GameRecord::from_json(&json).unwrap(), record);
+//!
+//! // Un document d’un autre type est refusé
+//! assert!(Area::from_json(&json).is_err());
+//! ```
+
+use super::connect_four::{Area, GameRecord, Move};
+use super::tr;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Display, Formatter};
+
+/// Version actuelle des schémas
+pub const SCHEMA_VERSION : u32 = 1;
+
+/// Liste des erreurs pouvant survenir lors de la conversion d’un objet en JSON ou inversement
+pub enum JsonError {
+    /// Le texte n’est pas un document JSON valide ou ses données ne respectent pas le schéma
+    /// attendu. Le message décrit l’erreur.
+    Invalid(String),
+    /// Le document décrit un autre type d’objet que celui attendu
+    WrongSchema {
+        /// Le type d’objet attendu
+        expected : &'static str,
+        /// Le type d’objet décrit par le document
+        found : String,
+    },
+    /// Le document a été produit avec une version des schémas plus récente que celle supportée
+    UnsupportedVersion(u32),
+}
+
+impl Debug for JsonError {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        return Display::fmt(self, f);
+    }
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonError::Invalid(e) => f.write_str(&tr!("error.json_invalid", e)),
+            JsonError::WrongSchema { expected, found } => f.write_str(&tr!("error.json_schema", found, expected)),
+            JsonError::UnsupportedVersion(version) => f.write_str(&tr!("error.json_version", version, SCHEMA_VERSION)),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(e : serde_json::Error) -> Self {
+        return JsonError::Invalid(e.to_string());
+    }
+}
+
+/// Document encapsulant un objet lors de l’écriture
+#[derive(Serialize)]
+struct Document<'a, T> {
+    schema : &'static str,
+    version : u32,
+    data : &'a T,
+}
+
+/// En-tête d’un document lu, les données étant interprétées une fois le schéma vérifié
+#[derive(Deserialize)]
+struct Header {
+    schema : String,
+    version : u32,
+    data : serde_json::Value,
+}
+
+/// Objet convertible en document JSON versionné
+pub trait Json : Serialize + DeserializeOwned {
+    /// Identifiant du schéma de l’objet
+    const SCHEMA : &'static str;
+
+    /// Écrit l’objet dans un document JSON
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `Invalid` : L’objet ne peut pas être représenté en JSON
+    fn to_json(&self) -> Result<String, JsonError> {
+        let document = Document {
+            schema: Self::SCHEMA,
+            version: SCHEMA_VERSION,
+            data: self,
+        };
+
+        return Ok(serde_json::to_string(&document)?);
+    }
+
+    /// Lit l’objet depuis un document JSON ou depuis ses seules données
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `Invalid` : Le texte n’est pas un document JSON valide ou les données ne respectent pas
+    ///    le schéma de l’objet (par exemple une zone de jeu impossible)
+    ///  * `WrongSchema` : Le document décrit un autre type d’objet
+    ///  * `UnsupportedVersion` : Le document utilise une version des schémas trop récente
+    fn from_json(json : &str) -> Result<Self, JsonError> {
+        let value : serde_json::Value = serde_json::from_str(json)?;
+
+        if value.get("schema").is_none() {
+            return Ok(serde_json::from_value(value)?);
+        }
+
+        let header : Header = serde_json::from_value(value)?;
+
+        if header.schema != Self::SCHEMA {
+            return Err(JsonError::WrongSchema {
+                expected: Self::SCHEMA,
+                found: header.schema,
+            });
+        }
+
+        if header.version > SCHEMA_VERSION {
+            return Err(JsonError::UnsupportedVersion(header.version));
+        }
+
+        return Ok(serde_json::from_value(header.data)?);
+    }
+}
+
+impl Json for Area {
+    const SCHEMA : &'static str = "connect_four/area";
+}
+
+impl Json for Move {
+    const SCHEMA : &'static str = "connect_four/move";
+}
+
+impl Json for GameRecord {
+    const SCHEMA : &'static str = "connect_four/record";
+}
@@ -2,161 +2,125 @@
 //!
 //! Ce dernier possède les fonctionnalités suivantes:
 //!  * Gestion des parties de puissance 4 avec possibilité de modifier les joueurs ainsi que leurs
-//! nom
-//!  * Implémentation d’une intelligence artificielle rudimentaire jouant ses coups au hasard
+//!    nom
+//!  * Implémentation d’une intelligence artificielle rudimentaire jouant ses coups au hasard, via
+//!    un générateur de nombres aléatoires remplaçable afin de reproduire les parties
+//!  * Implémentation d’une intelligence artificielle gloutonne saisissant les victoires immédiates
+//!    et bloquant celles de l’adversaire
+//!  * Implémentation d’une intelligence artificielle explorant les coups à venir (minimax)
+//!  * Solveur exact et intelligence artificielle jouant parfaitement
+//!  * Suivi de l’avancement des recherches des intelligences artificielles (voir [`progress`])
+//!  * Analyse de chaque coup possible par les intelligences artificielles (voir [`analysis`])
+//!  * Confrontation de deux intelligences artificielles sur une série de parties (voir [`arena`])
+//!  * Tournois entre un nombre quelconque d’intelligences artificielles (voir [`tournament`])
+//!  * Classement Elo des joueurs enregistré dans un fichier (voir [`ratings`])
+//!  * Statistiques des confrontations entre joueurs enregistrées dans un fichier (voir
+//!    [`statistics`])
+//!  * Archivage des parties terminées dans un fichier (voir [`archive`])
+//!  * Parties en réseau via TCP (voir [`net`])
+//!  * Gestion de nombreuses parties simultanées identifiées par un identifiant (voir [`server`])
+//!  * Thèmes d’affichage de la zone de jeu adaptés au terminal (voir [`theme`])
+//!  * Moteurs de rendu personnalisables de la zone de jeu (voir [`render`])
+//!  * Messages traduits en français et en anglais (voir [`i18n`])
+//!  * Variante en trois dimensions sur un plateau de 4 × 4 tiges (voir [`score_four`])
+//!  * Parties de trois ou quatre joueurs jouant à tour de rôle (voir [`Engine::with_players`])
+//!  * Bibliothèque d’ouvertures générée par le solveur et consultée par les intelligences
+//!    artificielles
+//!  * Positions de départ tirées au hasard et équilibrées par le solveur (voir [`scramble`])
+//!  * Gestionnaire de jeux générique, sans indirection, pour simuler de nombreuses parties (voir
+//!    [`simulation`])
+//!  * Extraction de caractéristiques numériques des positions, afin d’entraîner des fonctions
+//!    d’évaluation par apprentissage automatique (voir [`Area::features`])
+//!
+//! La bibliothèque ne dépend pas de l’interface en ligne de commande: cette dernière est fournie
+//! par le binaire du paquet et n’est compilée qu’avec la fonctionnalité `cli` (activée par
+//! défaut).
+//!
+//! La fonctionnalité `tokio` ajoute le module [`async_interface`] permettant de mener des parties
+//! avec des joueurs asynchrones.
+//!
+//! La fonctionnalité `wasm` ajoute le module [`wasm`] exposant le moteur à JavaScript afin
+//! d’animer une interface web. La bibliothèque compile alors pour la cible
+//! `wasm32-unknown-unknown`, sans les fonctionnalités par défaut.
+//!
+//! La fonctionnalité `python` ajoute le module [`python`] définissant le module Python
+//! `connect_four`, compilé avec maturin.
+//!
+//! La fonctionnalité `http` ajoute le module [`http`] servant des parties via une API REST
+//! construite avec `axum`. Elle active les fonctionnalités `serde` et `tokio`.
+//!
+//! La fonctionnalité `tui` ajoute le module [`tui`] proposant une interface plein écran dans le
+//! terminal, construite avec `ratatui`.
+//!
+//! La fonctionnalité `rayon` (activée par défaut) permet au robot minimax de répartir sa recherche
+//! sur plusieurs cœurs.
+//!
+//! La fonctionnalité `serde` rend sérialisables les zones de jeu, les coups, les résultats ainsi
+//! que les paramètres du gestionnaire de jeux ([`EngineSettings`]). Elle ajoute également le
+//! module [`json`] convertissant les zones de jeu, les coups et les parties en documents JSON
+//! versionnés.
 //!
 //! # Exemple
 //!
-//! Voici un exemple complet et fonctionnel d’une utilisation de ce moteur de jeu:
+//! Voici un exemple complet et fonctionnel d’une partie entre deux intelligences artificielles
+//! jouant au hasard:
 //!
 //! ```rust
-//! extern crate text_io;
-//! extern crate getrandom;
+//! use connect_four::{Engine, GameResult};
+//! use connect_four::random_bot::RandomBot;
 //!
-//! mod connect_four;
-//! mod random_bot;
+//! let player1 = Box::new(RandomBot::new("Robot 1"));
+//! let player2 = Box::new(RandomBot::new("Robot 2"));
 //!
-//! use crate::connect_four::{Interface, Area, State};
-//! use text_io::scan;
-//! use std::fmt::Display;
-//! use std::str::FromStr;
-//! use std::cell::RefCell;
+//! let mut game = Engine::new(player1, player2);
 //!
-//! /// Interface en ligne de commande avec un joueur
-//! struct PlayerCLI {
-//!     name : String
-//! }
-//!
-//! impl PlayerCLI {
-//!     /// Initialise l’interface avec le nom du joueur
-//!     ///
-//!     /// # Arguments
-//!     ///  * `name` - Le nom du joueur
-//!     pub fn new(name : &str) -> Self {
-//!         Self {
-//!             name: String::from(name)
-//!         }
-//!     }
+//! if let Ok(v) = game.play() {
+//!     println!("{}", game.get_disposition());
 //!
-//!     /// Renomme l’interface du joueur
-//!     ///
-//!     /// # Arguments
-//!     ///  * `name` - Le nom du joueur
-//!     pub fn rename(&mut self, name : &str) {
-//!         self.name = String::from(name);
-//!     }
-//! }
-//!
-//! impl Interface for PlayerCLI {
-//!     fn play(&mut self, area: &Area, token : State) -> usize {
-//!         println!("À {} de jouer ({})", self.name, if token == State::RedToken { "🔴" } else { "🟡" });
-//!
-//!         println!("{}", area);
-//!
-//!         let columns = area.get_available_columns();
-//!
-//!         if columns.len() == 1 {
-//!             println!("Une seule possibilité: {}", columns[0]);
-//!
-//!             return columns[0];
-//!         }
-//!
-//!         return request("Choisissez une position", columns);
-//!     }
-//!
-//!     fn name(&self) -> String {
-//!         return self.name.clone();
-//!     }
-//! }
-//!
-//! fn request<T : Sized + Copy + Display + Eq + FromStr>(req : &str, options : Vec<T>) -> T {
-//!     let option_text = {
-//!         let mut option_text = String::new();
-//!
-//!         for i in 0..options.len() {
-//!             if i != 0 {
-//!                 option_text += format!("/{}", options[i]).as_str();
-//!             } else {
-//!                 option_text += format!("{}", options[i]).as_str();
-//!             }
-//!         }
-//!
-//!         option_text
-//!     };
-//!
-//!     loop {
-//!         println!("{} [{}]", req, option_text);
-//!
-//!         let tmp : String;
-//!
-//!         scan!("{}", tmp);
-//!
-//!         if let Ok(t) = tmp.parse::<T>() {
-//!             if options.contains(&t) {
-//!                 return t;
-//!             }
-//!         }
-//!     }
-//! }
-//!
-//! fn main() {
-//!     let player1 = RefCell::new(PlayerCLI::new("Joueur 1"));
-//!     let player2 = RefCell::new(PlayerCLI::new("Joueur 2"));
-//!     let rnd_bot = RefCell::new(random_bot::RandomBot::new("Robot aléatoire"));
-//!
-//!     let mut game = connect_four::Engine::new(&player1, &player2);
-//!
-//!     loop {
-//!         let players = request("Nombre de joueurs", vec![1,2]);
-//!
-//!         if players == 1 {
-//!             game.set_player(2, &rnd_bot).unwrap();
-//!         } else {
-//!             game.set_player(2, &player2).unwrap();
-//!         }
-//!
-//!         {
-//!             let mut tmp : String;
-//!
-//!             println!("Nom du joueur 1");
-//!
-//!             scan!("{}", tmp);
-//!             player1.borrow_mut().rename(tmp.as_str());
-//!
-//!             if players == 2 {
-//!                 println!("Nom du joueur 2");
-//!
-//!                 scan!("{}", tmp);
-//!                 player2.borrow_mut().rename(tmp.as_str());
-//!             }
-//!         }
-//!
-//!         if let Ok(v) = game.play() {
-//!             println!("{}", game.get_disposition());
-//!
-//!             if let Some(p) = v {
-//!                 println!("{} a gagné", p);
-//!             } else {
-//!                 println!("Match nul");
-//!             }
-//!         } else {
-//!             break;
-//!         }
-//!
-//!         println!("Rejouer? [y/n]");
-//!
-//!         let com : String;
-//!
-//!         scan!("{}", com);
-//!
-//!         if com == "n" {
-//!             break;
-//!         }
-//!
-//!         game.reset();
+//!     match v {
+//!         GameResult::Winner { player_id, .. } => println!("{} a gagné", game.player_name(player_id).unwrap()),
+//!         GameResult::Draw => println!("Match nul"),
+//!         _ => println!("Partie interrompue"),
 //!     }
 //! }
 //! ```
+#![allow(clippy::needless_return)]
+
+pub mod connect_four;
+pub mod rng;
+pub mod random_bot;
+pub mod greedy_bot;
+pub mod minimax_bot;
+pub mod transposition;
+pub mod solver;
+pub mod book;
+pub mod scramble;
+pub mod simulation;
+pub mod eval;
+pub mod analysis;
+pub mod progress;
+pub mod arena;
+pub mod tournament;
+pub mod ratings;
+pub mod statistics;
+pub mod archive;
+pub mod net;
+pub mod server;
+pub mod theme;
+pub mod render;
+pub mod score_four;
+pub mod i18n;
+#[cfg(feature = "serde")]
+pub mod json;
+#[cfg(feature = "tokio")]
+pub mod async_interface;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "tui")]
+pub mod tui;
 
-mod connect_four;
-mod random_bot;
\ No newline at end of file
+pub use crate::connect_four::{Engine, EngineSettings, GameRecord, Handicap, GameResult, GameStatus, RetryPolicy, Move, MoveKind, Player, PlayerId, Action, Variant, Objective, TimeControl, MoveTimeout, TimeoutAction, Area, AreaConfig, Interface, Observer, PlayError, State, Error, Result};
@@ -1,19 +1,475 @@
-extern crate text_io;
-extern crate getrandom;
+//! Interface en ligne de commande du moteur de jeu puissance 4
+#![allow(clippy::needless_return)]
 
-mod connect_four;
-mod random_bot;
+extern crate text_io;
 
-use crate::connect_four::{Interface, Area, State};
+use connect_four::{Action, Interface, Area, AreaConfig, State, Engine, Error, GameRecord, GameResult, GameStatus, Handicap, Move, Objective, Observer, PlayError, Player, PlayerId, RetryPolicy, TimeControl, Variant};
+use connect_four::random_bot::RandomBot;
+use connect_four::greedy_bot::GreedyBot;
+use connect_four::rng::{OsRng, Pcg32, Rng};
+use connect_four::minimax_bot::MinimaxBot;
+use connect_four::book::OpeningBook;
+use connect_four::scramble::{self, Scramble};
+use connect_four::solver::{Solver, PerfectBot};
+use connect_four::analysis::{Analyzer, Annotation, Score, ThreatReport, review};
+use connect_four::progress::SearchProgress;
+use connect_four::arena::{play_match, Series};
+use connect_four::tournament::Tournament;
+use connect_four::ratings::Ratings;
+use connect_four::statistics::{HeadToHead, Statistics};
+use connect_four::archive::{self, ArchivedGame};
+use connect_four::json::Json;
+use connect_four::net::{self, ClientEvents, Connection, HandshakeError, Message, NetworkPlayer, Session};
+use connect_four::theme::Theme;
+use connect_four::i18n::{self, Lang};
+use connect_four::tr;
+use connect_four::render::{self, AnsiRenderer, CompactRenderer, Renderer, RenderOptions, TextRenderer};
+use connect_four::score_four::{self, Area3, Engine3, Interface3};
+#[cfg(feature = "tui")]
+use connect_four::tui::Tui;
 use text_io::scan;
+use clap::{Args, Parser, Subcommand};
 use std::fmt::Display;
 use std::str::FromStr;
-use std::cell::RefCell;
+use std::io::{IsTerminal, Write};
+use std::sync::{Arc, OnceLock};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Profondeur d’exploration de l’intelligence artificielle conseillant les joueurs
+const ADVISOR_DEPTH : usize = 10;
+
+/// Délai accordé à un adversaire en réseau pour se reconnecter après la perte de la connexion
+const RECONNECTION_GRACE : Duration = Duration::from_secs(60);
+
+/// Nombre de tentatives de reconnexion à l’hôte, espacées d’une seconde
+const RECONNECTION_ATTEMPTS : usize = 30;
+
+/// Délai entre deux images de l’animation de la chute d’un jeton
+const DROP_FRAME_DELAY : Duration = Duration::from_millis(40);
+
+/// Durée de réflexion d’une intelligence artificielle au-delà de laquelle l’indicateur de
+/// réflexion est affiché
+const THINKING_DELAY : Duration = Duration::from_millis(300);
+
+/// Délai entre deux mises à jour de l’indicateur de réflexion
+const THINKING_FRAME_DELAY : Duration = Duration::from_millis(100);
+
+/// Moteur de rendu de la zone de jeu
+type BoardRenderer = Box<dyn Renderer + Send + Sync>;
+
+/// Moteur de rendu et options d’affichage choisis par les options `--theme` et `--compact`
+static DISPLAY : OnceLock<(BoardRenderer, RenderOptions)> = OnceLock::new();
+
+/// Anime la chute des jetons, activé par l’option `--animate`
+static ANIMATE : AtomicBool = AtomicBool::new(false);
+
+/// Émet des événements JSON à la place des affichages, activé par l’option `--output json`
+static JSON_OUTPUT : AtomicBool = AtomicBool::new(false);
+
+/// Levé par un premier Ctrl-C afin d’interrompre la partie en cours
+static INTERRUPTED : AtomicBool = AtomicBool::new(false);
+
+/// Événement émis sur la sortie standard, à raison d’un objet JSON par ligne, avec l’option
+/// `--output json`
+#[derive(serde::Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event<'a> {
+    /// Début d’une partie
+    Start { area : &'a Area },
+    /// Attente du coup d’un joueur saisi sur l’entrée standard
+    Turn { name : &'a str, token : State, columns : Vec<usize>, area : &'a Area },
+    /// Coup joué
+    Move { played : &'a Move, area : &'a Area },
+    /// Coup annulé
+    Undo { undone : &'a Move, area : &'a Area },
+    /// Coup illégal proposé par un joueur
+    InvalidMove { player : PlayerId, column : usize, error : String },
+    /// Fin d’une partie
+    Result { result : &'a GameResult, area : &'a Area },
+    /// Question posée à l’utilisateur, dont la réponse est lue sur l’entrée standard
+    Prompt { text : &'a str, options : Vec<String> },
+    /// Message destiné à l’utilisateur
+    Message { text : &'a str },
+}
+
+/// Émet un événement sur la sortie standard
+fn emit(event : &Event<'_>) {
+    if let Ok(line) = serde_json::to_string(event) {
+        println!("{}", line);
+
+        let _ = std::io::stdout().flush();
+    }
+}
+
+/// Affiche un message, ou l’émet sous forme d’événement avec l’option `--output json`
+fn say(text : String) {
+    if JSON_OUTPUT.load(Ordering::Relaxed) {
+        return emit(&Event::Message { text: &text });
+    }
+
+    println!("{}", text);
+}
+
+/// Observateur émettant les événements des parties avec l’option `--output json`
+struct JsonObserver;
+
+impl Observer for JsonObserver {
+    fn on_game_start(&mut self, area : &Area) {
+        emit(&Event::Start { area });
+    }
+
+    fn on_move_played(&mut self, area : &Area, played : &Move) {
+        emit(&Event::Move { played, area });
+    }
+
+    fn on_move_undone(&mut self, area : &Area, undone : &Move) {
+        emit(&Event::Undo { undone, area });
+    }
+
+    fn on_invalid_move(&mut self, _area : &Area, player_id : PlayerId, column : usize, error : &Error) {
+        emit(&Event::InvalidMove { player: player_id, column, error: error.to_string() });
+    }
+
+    fn on_game_end(&mut self, area : &Area, result : &GameResult) {
+        emit(&Event::Result { result, area });
+    }
+}
+
+/// Ajoute l’observateur émettant les événements JSON avec l’option `--output json`
+fn observe(game : &mut Engine) {
+    if JSON_OUTPUT.load(Ordering::Relaxed) {
+        game.add_observer(Box::new(JsonObserver));
+    }
+}
+
+/// Choisi le moteur de rendu adapté au thème
+fn display_for(theme : Theme, compact : bool) -> (BoardRenderer, RenderOptions) {
+    let renderer : BoardRenderer = match (compact, theme.colors) {
+        (true, _) => Box::new(CompactRenderer),
+        (false, Some(_)) => Box::new(AnsiRenderer),
+        (false, None) => Box::new(TextRenderer),
+    };
+
+    return (renderer, RenderOptions { theme, highlight: true, ..Default::default() });
+}
+
+/// Fourni le thème d’affichage, détecté selon le terminal si aucun n’a été choisi
+fn theme() -> &'static Theme {
+    return &display().1.theme;
+}
+
+/// Fourni le moteur de rendu et les options d’affichage
+fn display() -> &'static (BoardRenderer, RenderOptions) {
+    return DISPLAY.get_or_init(|| display_for(Theme::detect(), false));
+}
+
+/// Met en forme la zone de jeu pour l’affichage
+fn board(area : &Area) -> String {
+    let (renderer, options) = display();
+
+    return renderer.render(area, options);
+}
+
+/// Affiche la zone de jeu, en animant la chute du dernier jeton ajouté si l’option `--animate`
+/// est active
+fn print_board(area : &Area) {
+    if JSON_OUTPUT.load(Ordering::Relaxed) {
+        return;
+    }
+
+    if !ANIMATE.load(Ordering::Relaxed) {
+        return println!("{}", board(area));
+    }
+
+    let (renderer, options) = display();
+
+    for (i, frame) in render::drop_frames(renderer.as_ref(), area, options).iter().enumerate() {
+        if i != 0 {
+            std::thread::sleep(DROP_FRAME_DELAY);
+
+            // Remonte le curseur au début de l’image précédente afin de l’écraser
+            print!("\x1b[{}F", frame.lines().count());
+        }
+
+        println!("{}", frame);
+
+        let _ = std::io::stdout().flush();
+    }
+}
+
+/// Commande saisie par un joueur à la place d’un coup
+enum Command {
+    /// Sauvegarde la partie dans le fichier donné
+    Save(String),
+    /// Reprend la partie sauvegardée dans le fichier donné
+    Load(String),
+    /// Annule le dernier coup du joueur et la réponse de son adversaire
+    Undo,
+}
+
+/// Niveau de l’intelligence artificielle affrontée en mode un joueur
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum BotLevel {
+    /// Joue au hasard
+    Easy,
+    /// Explore quelques coups à l’avance
+    Medium,
+    /// Explore de nombreux coups à l’avance
+    Hard,
+    /// Joue parfaitement grâce au solveur
+    Perfect,
+    /// Saisit les victoires immédiates et bloque celles de l’adversaire (`glouton`)
+    Greedy,
+    /// Explore le nombre de coups donné (`minimax:<profondeur>`)
+    Minimax(usize),
+}
+
+impl BotLevel {
+    /// Liste des niveaux, du plus facile au plus difficile
+    const ALL : [BotLevel; 4] = [BotLevel::Easy, BotLevel::Medium, BotLevel::Hard, BotLevel::Perfect];
+
+    /// Crée l’intelligence artificielle correspondant au niveau
+    ///
+    /// # Arguments
+    ///  * `book` - La bibliothèque d’ouvertures consultée par les niveaux les plus difficiles
+    ///  * `seed` - La graine du générateur aléatoire du niveau facile, afin de reproduire ses
+    ///    parties
+    ///  * `progress` - Le suivi de l’avancement des recherches, partagé avec les niveaux explorant
+    ///    les coups à venir
+    ///  * `objective` - L’objectif des parties, que seul le niveau parfait ne sait pas poursuivre en
+    ///    misère
+    fn create(self, book : Option<Arc<OpeningBook>>, seed : Option<u64>, progress : Option<Arc<SearchProgress>>, objective : Objective) -> Box<dyn Interface + Send> {
+        return match self {
+            BotLevel::Easy => match seed {
+                Some(seed) => Box::new(RandomBot::with_rng("Robot facile", Box::new(Pcg32::new(seed)))),
+                None => Box::new(RandomBot::new("Robot facile")),
+            },
+            BotLevel::Medium => {
+                let mut bot = MinimaxBot::new("Robot moyen", 4);
+
+                bot.set_progress(progress);
+                bot.set_objective(objective);
+
+                Box::new(bot)
+            },
+            BotLevel::Hard => {
+                let mut bot = MinimaxBot::new("Robot difficile", 10);
+
+                bot.set_opening_book(book);
+                bot.set_progress(progress);
+                bot.set_objective(objective);
+                bot.set_pondering(true);
+
+                #[cfg(feature = "rayon")]
+                bot.set_parallel(true);
+
+                Box::new(bot)
+            },
+            BotLevel::Perfect => {
+                let mut bot = PerfectBot::new("Robot parfait");
+
+                bot.set_opening_book(book);
+                bot.set_progress(progress);
+
+                Box::new(bot)
+            },
+            BotLevel::Greedy => {
+                let mut bot = GreedyBot::new("Robot glouton");
+
+                bot.set_objective(objective);
+
+                Box::new(bot)
+            },
+            BotLevel::Minimax(depth) => {
+                let mut bot = MinimaxBot::new(&format!("Robot minimax {}", depth), depth);
+
+                bot.set_progress(progress);
+                bot.set_objective(objective);
+
+                Box::new(bot)
+            },
+        };
+    }
+}
+
+impl Display for BotLevel {
+    fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(match self {
+            BotLevel::Easy => "facile",
+            BotLevel::Medium => "moyen",
+            BotLevel::Hard => "difficile",
+            BotLevel::Perfect => "parfait",
+            BotLevel::Greedy => "glouton",
+            BotLevel::Minimax(depth) => return write!(f, "minimax:{}", depth),
+        });
+    }
+}
+
+impl FromStr for BotLevel {
+    type Err = String;
+
+    fn from_str(s : &str) -> Result<Self, Self::Err> {
+        if let Some(level) = BotLevel::ALL.iter().find(|level| level.to_string() == s) {
+            return Ok(*level);
+        }
+
+        return match s.split_once(':') {
+            _ if s == "glouton" => Ok(BotLevel::Greedy),
+            Some(("minimax", depth)) => match depth.parse::<usize>() {
+                Ok(depth) if depth > 0 => Ok(BotLevel::Minimax(depth)),
+                _ => Err(tr!("args.invalid_depth", depth)),
+            },
+            _ => Err(tr!("args.unknown_level", s, options_text(&BotLevel::ALL))),
+        };
+    }
+}
+
+/// Intelligence artificielle affichant un indicateur de réflexion lorsqu’elle tarde à jouer et
+/// interrompant sa recherche au premier Ctrl-C
+struct ThinkingBot {
+    /// Intelligence artificielle jouant les coups
+    bot : Box<dyn Interface + Send>,
+    /// Suivi de l’avancement des recherches de l’intelligence artificielle
+    progress : Arc<SearchProgress>,
+    /// Affiche l’indicateur de réflexion, uniquement lorsque la sortie standard est un terminal
+    spinner : bool,
+}
+
+impl ThinkingBot {
+    /// Crée l’intelligence artificielle du niveau donné, accompagnée d’un indicateur de réflexion
+    /// si la sortie standard est un terminal
+    ///
+    /// # Arguments
+    ///  * `level` - Le niveau de l’intelligence artificielle
+    ///  * `book` - La bibliothèque d’ouvertures consultée par les niveaux les plus difficiles
+    ///  * `seed` - La graine du générateur aléatoire du niveau facile
+    ///  * `objective` - L’objectif des parties
+    fn create(level : BotLevel, book : Option<Arc<OpeningBook>>, seed : Option<u64>, objective : Objective) -> Self {
+        let progress = Arc::new(SearchProgress::new());
+        let spinner = !JSON_OUTPUT.load(Ordering::Relaxed) && std::io::stdout().is_terminal();
+
+        return Self { bot: level.create(book, seed, Some(Arc::clone(&progress)), objective), progress, spinner };
+    }
+}
+
+impl Interface for ThinkingBot {
+    fn play(&mut self, area : &Area, token : State) -> Result<usize, PlayError> {
+        let name = self.bot.name();
+        let progress = Arc::clone(&self.progress);
+        let (done, spinner) = (AtomicBool::new(false), self.spinner);
+
+        progress.start(None);
+
+        return thread::scope(|scope| {
+            scope.spawn(|| watch_search(&name, &progress, &done, spinner));
+
+            let played = self.bot.play(area, token);
+
+            done.store(true, Ordering::Relaxed);
+
+            return played;
+        });
+    }
+
+    fn name(&self) -> String {
+        return self.bot.name();
+    }
+
+    fn on_opponent_thinking(&mut self, area : &Area, token : State) {
+        self.bot.on_opponent_thinking(area, token);
+    }
+
+    fn on_opponent_move(&mut self, area : &Area, action : Action) {
+        self.bot.on_opponent_move(area, action);
+    }
+
+    fn on_game_start(&mut self, area : &Area, player : &Player) {
+        self.bot.on_game_start(area, player);
+    }
+
+    fn on_game_aborted(&mut self, area : &Area, reason : &str) {
+        self.bot.on_game_aborted(area, reason);
+    }
+
+    fn nodes_searched(&self) -> Option<u64> {
+        return self.bot.nodes_searched();
+    }
+}
+
+/// Surveille la recherche d’une intelligence artificielle jusqu’à ce qu’elle ait joué
+///
+/// La recherche est interrompue dès que [`INTERRUPTED`] est levé. Avec `spinner`, l’indicateur de
+/// réflexion apparaît après [`THINKING_DELAY`] puis est effacé une fois le coup joué.
+#[doc(hidden)]
+fn watch_search(name : &str, progress : &SearchProgress, done : &AtomicBool, spinner : bool) {
+    const FRAMES : [char; 4] = ['|', '/', '-', '\\'];
+
+    let start = Instant::now();
+    let mut frame = 0;
+
+    while !done.load(Ordering::Relaxed) {
+        thread::sleep(THINKING_FRAME_DELAY / 2);
+
+        if INTERRUPTED.load(Ordering::Relaxed) {
+            progress.cancel();
+
+            continue;
+        }
+
+        if !spinner || start.elapsed() < THINKING_DELAY || done.load(Ordering::Relaxed) {
+            continue;
+        }
+
+        let nodes = format_count(progress.nodes());
+        let text = match progress.depth() {
+            Some(depth) => tr!("thinking.depth", name, depth, nodes),
+            None => tr!("thinking.nodes", name, nodes),
+        };
+
+        print!("\r{} {}\x1b[K", FRAMES[frame / 2 % FRAMES.len()], text);
+        let _ = std::io::stdout().flush();
+
+        frame += 1;
+    }
+
+    if frame > 0 {
+        print!("\r\x1b[K");
+        let _ = std::io::stdout().flush();
+    }
+}
+
+/// Formate un nombre de positions de manière abrégée (`1.2M`, `350k`)
+#[doc(hidden)]
+fn format_count(count : u64) -> String {
+    return match count {
+        0..=999 => count.to_string(),
+        1_000..=99_999 => format!("{:.1}k", count as f64 / 1e3),
+        100_000..=999_999 => format!("{}k", count / 1_000),
+        _ => format!("{:.1}M", count as f64 / 1e6),
+    };
+}
 
 /// Interface en ligne de commande avec un joueur
 struct PlayerCLI {
     #[doc(hidden)]
-    name : String
+    name : String,
+    #[doc(hidden)]
+    commands : Sender<Command>,
+    /// Intelligence artificielle consultée lorsque le joueur demande un conseil
+    #[doc(hidden)]
+    advisor : MinimaxBot,
+    /// Affiche l’évaluation de la position sous la zone de jeu
+    #[doc(hidden)]
+    verbose : bool,
+    /// Canal où transmettre les messages de discussion saisis par le joueur, en réseau
+    #[doc(hidden)]
+    chat : Option<Sender<String>>,
+    /// Permet au joueur de retirer ses jetons (`pop <colonne>`), dans la variante Pop Out
+    #[doc(hidden)]
+    pop_out : bool,
 }
 
 impl PlayerCLI {
@@ -21,36 +477,243 @@ impl PlayerCLI {
     ///
     /// # Arguments
     ///  * `name` - Le nom du joueur
-    pub fn new(name : &str) -> Self {
+    ///  * `commands` - Canal où transmettre les commandes saisies par le joueur
+    ///  * `book` - La bibliothèque d’ouvertures consultée pour conseiller le joueur
+    pub fn new(name : &str, commands : Sender<Command>, book : Option<Arc<OpeningBook>>) -> Self {
+        let mut advisor = MinimaxBot::new("Conseiller", ADVISOR_DEPTH);
+
+        advisor.set_opening_book(book);
+
         Self {
-            name: String::from(name)
+            name: String::from(name),
+            commands,
+            advisor,
+            verbose: false,
+            chat: None,
+            pop_out: false,
         }
     }
 
-    /// Renomme l’interface du joueur
-    ///
-    /// # Arguments
-    ///  * `name` - Le nom du joueur
-    pub fn rename(&mut self, name : &str) {
-        self.name = String::from(name);
+    /// Active ou désactive l’affichage de l’évaluation de la position sous la zone de jeu
+    pub fn set_verbose(&mut self, verbose : bool) {
+        self.verbose = verbose;
+    }
+
+    /// Permet au joueur d’envoyer des messages de discussion (`chat <message>`) sur le canal donné
+    pub fn set_chat(&mut self, chat : Sender<String>) {
+        self.chat = Some(chat);
+    }
+
+    /// Permet au joueur de retirer ses jetons du bas des colonnes (variante Pop Out)
+    pub fn set_pop_out(&mut self, pop_out : bool) {
+        self.pop_out = pop_out;
+    }
+
+    /// Modifie l’objectif poursuivi par le conseiller du joueur
+    pub fn set_objective(&mut self, objective : Objective) {
+        self.advisor.set_objective(objective);
+    }
+
+    /// Affiche l’évaluation de la position du point de vue des deux joueurs
+    fn print_evaluation(&mut self, area : &Area, token : State) {
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+
+        match self.advisor.best(area, token) {
+            Ok(Some(best)) => match best.score {
+                Score::Win(1) => say(tr!("eval.wins_now", theme().glyph(token))),
+                Score::Win(n) => say(tr!("eval.wins_in", theme().glyph(token), n)),
+                Score::Loss(1) => say(tr!("eval.wins_next", theme().glyph(opponent))),
+                Score::Loss(n) => say(tr!("eval.wins_in", theme().glyph(opponent), n)),
+                Score::Draw => say(tr!("eval.draw")),
+                Score::Heuristic(0) => say(tr!("eval.even")),
+                Score::Heuristic(score) => {
+                    let leader = if score > 0 { token } else { opponent };
+
+                    say(tr!("eval.advantage", format!("{:.1}", score.abs() as f64 / 100.0), theme().glyph(leader)));
+                }
+            },
+            Ok(None) => {},
+            Err(e) => say(tr!("eval.failed", e)),
+        }
+    }
+
+    /// Affiche le coup conseillé au joueur ainsi que sa justification
+    fn hint(&mut self, area : &Area, token : State) {
+        let scores = match self.advisor.analyze(area, token) {
+            Ok(scores) => scores,
+            Err(e) => {
+                say(tr!("hint.failed", e));
+
+                return;
+            }
+        };
+
+        let opponent = if token == State::RedToken { State::YellowToken } else { State::RedToken };
+        let cols = area.config().cols;
+
+        // Parmi les coups de meilleure évaluation, le plus central est conseillé
+        let best = scores.iter().max_by_key(|s| (s.score, std::cmp::Reverse((2 * s.column).abs_diff(cols - 1))));
+
+        let best = match best {
+            Some(best) => best,
+            None => return,
+        };
+
+        let safe = scores.iter().filter(|s| !matches!(s.score, Score::Loss(_))).count();
+
+        let reason = match best.score {
+            Score::Win(_) | Score::Loss(_) => best.score.to_string(),
+            _ if area.immediate_threats(opponent).contains(&best.column) => tr!("hint.blocks"),
+            _ if safe == 1 && scores.len() > 1 => tr!("hint.only_safe"),
+            Score::Draw => tr!("hint.draw"),
+            Score::Heuristic(score) => tr!("hint.estimate", format!("{:+}", score)),
+        };
+
+        say(tr!("hint.advice", best.column, reason));
+
+        // La parité des menaces n’explique que les positions dont l’issue est encore inconnue
+        if best.score.is_exact() {
+            return;
+        }
+
+        match ThreatReport::new(area, token).zugzwang() {
+            Some((owner, (col, row))) if owner == token => say(tr!("hint.plan_own", col, row + 1)),
+            Some((_, (col, row))) => say(tr!("hint.plan_opponent", col, row + 1)),
+            None => {},
+        }
     }
 }
 
 impl Interface for PlayerCLI {
-    fn play(&mut self, area: &Area, token : State) -> usize {
-        println!("À {} de jouer ({})", self.name, if token == State::RedToken { "🔴" } else { "🟡" });
+    fn play(&mut self, area: &Area, token : State) -> Result<usize, PlayError> {
+        return match self.play_action(area, token)? {
+            Action::Drop(column) => Ok(column),
+            Action::Pop(_) => Err(PlayError::Other(tr!("error.pop_not_allowed"))),
+            Action::Swap => Err(PlayError::Other(tr!("error.swap_not_allowed"))),
+        };
+    }
 
-        println!("{}", area);
+    fn play_action(&mut self, area : &Area, token : State) -> Result<Action, PlayError> {
+        let json = JSON_OUTPUT.load(Ordering::Relaxed);
+
+        if json {
+            emit(&Event::Turn { name: &self.name, token, columns: area.get_available_columns(), area });
+        } else {
+            println!("{}", tr!("play.turn", self.name, theme().glyph(token)));
+        }
+
+        print_board(area);
+
+        if self.verbose {
+            self.print_evaluation(area, token);
+        }
 
         let columns = area.get_available_columns();
 
-        if columns.len() == 1 {
-            println!("Une seule possibilité: {}", columns[0]);
+        if columns.len() == 1 && !self.pop_out {
+            say(tr!("play.forced", columns[0]));
+
+            return Ok(Action::Drop(columns[0]));
+        }
+
+        let prompt = match (&self.chat, self.pop_out) {
+            (Some(_), _) => "play.prompt_chat",
+            (None, true) => "play.prompt_pop_out",
+            (None, false) => "play.prompt",
+        };
+        let labels : Vec<String> = columns.iter().copied().map(render::column_label).collect();
+
+        loop {
+            // En JSON, l’événement `turn` tient lieu d’invite
+            if !json {
+                println!("{}", tr!(prompt, options_text(&labels)));
+            }
+
+            let mut line = String::new();
+
+            if std::io::stdin().read_line(&mut line).map_err(PlayError::Io)? == 0 {
+                return Err(PlayError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+            }
+
+            // Un Ctrl-C reçu durant la saisie interrompt la partie une fois la ligne validée
+            if INTERRUPTED.load(Ordering::Relaxed) {
+                return Err(PlayError::Other(tr!("error.aborted")));
+            }
+
+            let mut words = line.split_whitespace();
+            let input = match words.next() {
+                Some(input) => input,
+                None => continue,
+            };
+
+            if let (Some(chat), "chat") = (&self.chat, input) {
+                let text = line.trim_start()[input.len()..].trim();
+
+                chat.send(String::from(text)).map_err(|_| PlayError::Disconnected)?;
+
+                continue;
+            }
+
+            if input == "hint" {
+                self.hint(area, token);
+
+                continue;
+            }
+
+            if self.chat.is_none() && (input == "u" || input == "undo") {
+                self.commands.send(Command::Undo).map_err(|_| PlayError::Disconnected)?;
+
+                return Err(PlayError::Other(String::from(input)));
+            }
+
+            if input == "save" || input == "load" {
+                let file = match words.next() {
+                    Some(file) => String::from(file),
+                    None => continue,
+                };
+
+                let command = if input == "save" { Command::Save(file) } else { Command::Load(file) };
+
+                self.commands.send(command).map_err(|_| PlayError::Disconnected)?;
+
+                return Err(PlayError::Other(String::from(input)));
+            }
+
+            if self.pop_out && input == "pop" {
+                // Seuls les jetons du joueur situés en bas d’une colonne peuvent être retirés
+                match words.next().and_then(render::parse_column) {
+                    Some(column) if column < area.config().cols && bottom_token(area, column) == token => return Ok(Action::Pop(column)),
+                    _ => continue,
+                }
+            }
 
-            return columns[0];
+            if let Some(column) = render::parse_column(input) {
+                if columns.contains(&column) {
+                    return Ok(Action::Drop(column));
+                }
+            }
         }
+    }
+
+    fn offer_swap(&mut self, area : &Area, token : State) -> Result<bool, PlayError> {
+        say(tr!("play.turn", self.name, theme().glyph(token)));
+        print_board(area);
+
+        loop {
+            say(tr!("play.swap_offer"));
+
+            let mut line = String::new();
+
+            if std::io::stdin().read_line(&mut line).map_err(PlayError::Io)? == 0 {
+                return Err(PlayError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+            }
 
-        return request("Choisissez une position", columns);
+            match line.trim() {
+                "y" => return Ok(true),
+                "n" => return Ok(false),
+                _ => continue,
+            }
+        }
     }
 
     fn name(&self) -> String {
@@ -58,23 +721,66 @@ impl Interface for PlayerCLI {
     }
 }
 
-fn request<T : Sized + Copy + Display + Eq + FromStr>(req : &str, options : Vec<T>) -> T {
-    let option_text = {
-        let mut option_text = String::new();
+impl Interface3 for PlayerCLI {
+    fn play_3d(&mut self, area : &Area3, token : State) -> Result<usize, PlayError> {
+        say(tr!("play.turn", self.name, theme().glyph(token)));
 
-        for i in 0..options.len() {
-            if i != 0 {
-                option_text += format!("/{}", options[i]).as_str();
-            } else {
-                option_text += format!("{}", options[i]).as_str();
+        if !JSON_OUTPUT.load(Ordering::Relaxed) {
+            println!("{}", render::render_layers(area, &display().1));
+        }
+
+        let pins = area.available_pins();
+        let labels : Vec<String> = pins.iter().copied().map(score_four::pin_label).collect();
+
+        loop {
+            say(tr!("play.prompt_pin", options_text(&labels)));
+
+            let mut line = String::new();
+
+            if std::io::stdin().read_line(&mut line).map_err(PlayError::Io)? == 0 {
+                return Err(PlayError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+            }
+
+            if let Some(pin) = score_four::parse_pin(line.trim()) {
+                if pins.contains(&pin) {
+                    return Ok(pin);
+                }
             }
         }
+    }
+}
 
-        option_text
-    };
+/// Fourni le jeton du bas d’une colonne, les cases bloquées étant ignorées
+fn bottom_token(area : &Area, column : usize) -> State {
+    let config = area.config();
+
+    return (0..config.rows).find(|row| !config.is_blocked(column, *row)).map_or(State::NoToken, |row| area[(column, row)]);
+}
+
+/// Met en forme une liste d’options sous la forme `a/b/c`
+fn options_text<T : Display>(options : &[T]) -> String {
+    let mut option_text = String::new();
+
+    for (i, option) in options.iter().enumerate() {
+        if i != 0 {
+            option_text += format!("/{}", option).as_str();
+        } else {
+            option_text += format!("{}", option).as_str();
+        }
+    }
+
+    return option_text;
+}
+
+fn request<T : Sized + Copy + Display + Eq + FromStr>(req : &str, options : Vec<T>) -> T {
+    let option_text = options_text(&options);
 
     loop {
-        println!("{} [{}]", req, option_text);
+        if JSON_OUTPUT.load(Ordering::Relaxed) {
+            emit(&Event::Prompt { text: req, options: options.iter().map(T::to_string).collect() });
+        } else {
+            println!("{} [{}]", req, option_text);
+        }
 
         let tmp : String;
 
@@ -88,52 +794,1761 @@ fn request<T : Sized + Copy + Display + Eq + FromStr>(req : &str, options : Vec<
     }
 }
 
-#[doc(hidden)]
-fn main() {
-    let player1 = RefCell::new(PlayerCLI::new("Joueur 1"));
-    let player2 = RefCell::new(PlayerCLI::new("Joueur 2"));
-    let rnd_bot = RefCell::new(random_bot::RandomBot::new("Robot aléatoire"));
+/// Formate un temps de réflexion restant en minutes et secondes
+fn format_clock(remaining : Duration) -> String {
+    let secs = remaining.as_millis().div_ceil(1000);
 
-    let mut game = connect_four::Engine::new(&player1, &player2);
+    return format!("{:02}:{:02}", secs / 60, secs % 60);
+}
 
-    loop {
-        let players = request("Nombre de joueurs", vec![1,2]);
+/// Affiche le temps de réflexion restant de chaque joueur
+fn print_clocks(game : &Engine) {
+    if let Some(remaining) = game.clocks() {
+        let clocks : Vec<String> = [PlayerId::ONE, PlayerId::TWO].iter()
+            .map(|player_id| format!("{} {}", game.player_name(*player_id).unwrap(), format_clock(remaining[player_id.index()])))
+            .collect();
 
-        if players == 1 {
-            game.set_player(2, &rnd_bot).unwrap();
-        } else {
-            game.set_player(2, &player2).unwrap();
-        }
+        say(tr!("play.clocks", clocks.join(" | ")));
+    }
+}
 
-        {
-            let mut tmp : String;
+/// Sauvegarde la partie en cours dans un fichier
+fn save(game : &Engine, path : &str) -> Result<(), String> {
+    let data = game.record().to_json().map_err(|e| e.to_string())?;
 
-            println!("Nom du joueur 1");
+    return std::fs::write(path, data).map_err(|e| e.to_string());
+}
 
-            scan!("{}", tmp);
-            player1.borrow_mut().rename(tmp.as_str());
+/// Lit une partie sauvegardée dans un fichier
+fn read_record(path : &str) -> Result<GameRecord, String> {
+    let data = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
 
-            if players == 2 {
-                println!("Nom du joueur 2");
+    return GameRecord::from_json(&data).map_err(|e| e.to_string());
+}
 
-                scan!("{}", tmp);
-                player2.borrow_mut().rename(tmp.as_str());
-            }
+/// Reprend une partie sauvegardée dans un fichier
+fn load(game : &mut Engine, path : &str) -> Result<GameStatus, String> {
+    let record = read_record(path)?;
+
+    return game.load_record(&record).map_err(|e| e.to_string());
+}
+
+/// Affiche la liste des coups de la partie annotés par l’analyse de l’intelligence artificielle
+fn print_review(game : &Engine) {
+    let mut advisor = MinimaxBot::new("Conseiller", ADVISOR_DEPTH);
+
+    let reviews = match review(&game.record(), &mut advisor) {
+        Ok(reviews) => reviews,
+        Err(e) => {
+            say(tr!("review.failed", e));
+
+            return;
+        }
+    };
+
+    for (i, r) in reviews.iter().enumerate() {
+        let annotation = r.annotation.map_or(String::new(), |a| a.to_string());
+
+        let mut line = format!("{:>3}. {} ({}): {}{} [{}]", i + 1, game.player_name(r.player).unwrap(), r.token, r.played.column, annotation, r.played.score);
+
+        if r.annotation.is_some() {
+            line += &tr!("review.best", r.best.column, r.best.score);
+        }
+
+        if r.allowed_double_threat {
+            line += i18n::message("review.double_threat");
+        }
+
+        say(line);
+    }
+
+    for (annotation, name) in [(Annotation::Inaccuracy, tr!("review.inaccuracies")), (Annotation::Mistake, tr!("review.mistakes")), (Annotation::Blunder, tr!("review.blunders"))] {
+        let counts : Vec<String> = [PlayerId::ONE, PlayerId::TWO].iter().map(|&player_id| {
+            let count = reviews.iter().filter(|r| r.player == player_id && r.annotation == Some(annotation)).count();
+
+            format!("{} {}", game.player_name(player_id).unwrap(), count)
+        }).collect();
+
+        say(format!("{} ({}): {}", name, annotation, counts.join(" | ")));
+    }
+
+    let counts : Vec<String> = [PlayerId::ONE, PlayerId::TWO].iter().map(|&player_id| {
+        let count = reviews.iter().filter(|r| r.player == player_id && r.allowed_double_threat).count();
+
+        format!("{} {}", game.player_name(player_id).unwrap(), count)
+    }).collect();
+
+    say(format!("{}: {}", tr!("review.double_threats"), counts.join(" | ")));
+}
+
+#[doc(hidden)]
+/// Rejoue coup par coup une partie sauvegardée
+///
+/// Après chaque affichage de la zone de jeu, l’utilisateur peut avancer (`n`), reculer (`p`),
+/// aller à un coup donné (`j <coup>`) ou quitter (`q`).
+///
+/// # Arguments
+///
+///  * `path` - Le fichier contenant la partie
+fn replay(path : &str) {
+    let record = match read_record(path) {
+        Ok(record) => record,
+        Err(e) => return say(tr!("play.load_failed", e)),
+    };
+
+    let positions = match record.positions() {
+        Ok(positions) => positions,
+        Err(e) => return say(tr!("replay.invalid", e)),
+    };
+
+    let last = record.moves.len();
+    let mut ply = 0;
+
+    loop {
+        let area = &positions[ply];
+
+        println!("{}", board(area));
+
+        if ply == 0 {
+            say(tr!("replay.initial", last));
+        } else {
+            let player = if ply % 2 == 1 { record.first_player } else { record.first_player.next(2) };
+            let token = match (player, record.player_one_token) {
+                (PlayerId::ONE, token) => token,
+                (_, State::RedToken) => State::YellowToken,
+                _ => State::RedToken,
+            };
+
+            say(tr!("replay.move", ply, last, player, theme().glyph(token), record.moves[ply - 1]));
         }
 
-        if let Ok(v) = game.play() {
-            println!("{}", game.get_disposition());
+        if ply == last {
+            if !area.winning_cells().is_empty() {
+                let cells : Vec<String> = area.winning_cells().iter().map(|(col, row)| format!("({}, {})", col, row)).collect();
 
-            if let Some(p) = v {
-                println!("{} a gagné", p);
+                say(tr!("replay.winning", cells.join(" ")));
+            } else if area.get_available_columns().is_empty() {
+                say(tr!("result.draw"));
             } else {
-                println!("Match nul");
+                say(tr!("replay.unfinished"));
+            }
+        }
+
+        say(tr!("replay.help"));
+
+        let mut line = String::new();
+
+        if !matches!(std::io::stdin().read_line(&mut line), Ok(n) if n > 0) {
+            return;
+        }
+
+        let mut words = line.split_whitespace();
+
+        match (words.next(), words.next().map(str::parse::<usize>)) {
+            (None, _) | (Some("n"), None) if ply < last => ply += 1,
+            (Some("p"), None) if ply > 0 => ply -= 1,
+            (Some("j"), Some(Ok(target))) if target <= last => ply = target,
+            (Some("q"), None) => return,
+            (None, _) | (Some("n"), None) | (Some("p"), None) => say(tr!("replay.no_move")),
+            _ => say(tr!("replay.invalid_command")),
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Génère une bibliothèque d’ouvertures pour le plateau classique
+///
+/// # Arguments
+///
+///  * `plies` - Le nombre de coups des positions enregistrées
+///  * `path` - Le fichier où enregistrer la bibliothèque
+fn build_book(plies : usize, path : &str) {
+    let book = match OpeningBook::build(AreaConfig::default(), plies, &mut Solver::new()) {
+        Ok(book) => book,
+        Err(e) => {
+            say(tr!("book.failed", e));
+
+            return;
+        }
+    };
+
+    match book.save(path) {
+        Ok(()) => say(tr!("book.saved", book.len(), path)),
+        Err(e) => say(tr!("book.save_failed", e)),
+    }
+}
+
+#[doc(hidden)]
+/// Fait s’affronter deux intelligences artificielles sur une série de parties
+///
+/// # Arguments
+///
+///  * `args` - Les arguments de la commande `arena`
+fn arena(args : ArenaArgs) {
+    let ArenaArgs { first, second, games, ratings: ratings_path, seed } = args;
+
+    let mut engine = Engine::new(first.create(None, seed, None, Objective::Connect), second.create(None, seed, None, Objective::Connect));
+
+    let stats = match play_match(&mut engine, games) {
+        Ok(stats) => stats,
+        Err(e) => {
+            say(tr!("arena.interrupted", e));
+
+            return;
+        }
+    };
+
+    let (low, high) = stats.confidence_interval();
+
+    say(tr!("arena.summary", first, second, stats.wins, stats.draws, stats.losses));
+    say(tr!("arena.score", format!("{:.1}", 100.0 * stats.score()), format!("{:.1}", 100.0 * low), format!("{:.1}", 100.0 * high)));
+
+    if let Some(elo) = stats.elo_difference() {
+        say(tr!("arena.elo", format!("{:+.0}", elo)));
+    }
+
+    if let Some(path) = ratings_path {
+        let first = engine.player_name(PlayerId::ONE).unwrap_or_default();
+        let second = engine.player_name(PlayerId::TWO).unwrap_or_default();
+
+        update_ratings(&path, |ratings| ratings.add_match(&first, &second, &stats));
+    }
+}
+
+#[doc(hidden)]
+/// Charge le classement enregistré dans le fichier donné, un fichier inexistant correspondant à
+/// un classement vide
+fn load_ratings(path : &str) -> Result<Ratings, String> {
+    return match Ratings::load(path) {
+        Ok(ratings) => Ok(ratings),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Ratings::new()),
+        Err(e) => Err(e.to_string()),
+    };
+}
+
+#[doc(hidden)]
+/// Met à jour le classement enregistré dans le fichier donné
+///
+/// # Arguments
+///
+///  * `path` - Le fichier contenant le classement
+///  * `update` - La mise à jour à appliquer au classement
+fn update_ratings<F : FnOnce(&mut Ratings)>(path : &str, update : F) {
+    let result = load_ratings(path).and_then(|mut ratings| {
+        update(&mut ratings);
+
+        return ratings.save(path).map_err(|e| e.to_string());
+    });
+
+    if let Err(e) = result {
+        say(tr!("ratings.update_failed", e));
+    }
+}
+
+#[doc(hidden)]
+/// Affiche le classement enregistré dans un fichier
+///
+/// # Arguments
+///
+///  * `path` - Le fichier contenant le classement
+fn ratings(path : &str) {
+    match load_ratings(path) {
+        Ok(ratings) if ratings.is_empty() => say(tr!("ratings.empty")),
+        Ok(ratings) => print!("{}", ratings),
+        Err(e) => say(tr!("ratings.load_failed", e)),
+    }
+}
+
+#[doc(hidden)]
+/// Établit la connexion d’une partie en réseau, effectue la poignée de main puis échange le
+/// jeton de session
+///
+/// # Arguments
+///
+///  * `host` - `true` pour attendre la connexion d’un client à l’adresse donnée, `false` pour se
+///    connecter à l’hôte
+///  * `addr` - L’adresse d’écoute de l’hôte ou celle à laquelle se connecter
+///  * `name` - Le nom du joueur local
+///  * `auth` - Le jeton d’authentification exigé par l’hôte ou fourni par le client
+///
+/// # Retour
+///
+/// La connexion établie, le nom du joueur distant, la session ouverte par l’hôte et le jeton de
+/// session reçu par le client
+fn connect(host : bool, addr : &str, name : &str, auth : Option<&str>) -> Result<(Connection, String, Option<Session>, Option<String>), HandshakeError> {
+    let unexpected = |message : Message| std::io::Error::new(std::io::ErrorKind::InvalidData, tr!("net.unexpected", format!("{:?}", message))).into();
+
+    let (mut connection, listener) = if host {
+        let listener = std::net::TcpListener::bind(addr)?;
+
+        say(tr!("net.waiting", listener.local_addr()?));
+
+        (Connection::new(listener.accept()?.0)?, Some(listener))
+    } else {
+        (Connection::connect(addr)?, None)
+    };
+
+    if let Some(listener) = listener {
+        let remote = net::host_handshake(&mut connection, AreaConfig::default(), name, auth)?;
+        let session = Session::new(listener, RECONNECTION_GRACE)?;
+
+        connection.send(&Message::Session(String::from(session.token())))?;
+
+        return Ok((connection, remote, Some(session), None));
+    }
+
+    let remote = net::join_handshake(&mut connection, AreaConfig::default(), name, auth)?;
+
+    return match connection.receive()? {
+        Message::Session(token) => Ok((connection, remote, None, Some(token))),
+        message => Err(unexpected(message)),
+    };
+}
+
+#[doc(hidden)]
+/// Évènements d’une partie rejointe: affichage des pendules et discussion avec l’hôte
+struct NetworkClient {
+    /// Nom de l’hôte
+    #[doc(hidden)]
+    remote : String,
+    /// Messages de discussion saisis par le joueur local
+    #[doc(hidden)]
+    chat : mpsc::Receiver<String>,
+}
+
+impl ClientEvents for NetworkClient {
+    fn on_sync(&mut self, _ : &Area, clocks : Option<[Duration; 2]>) {
+        if let Some([yellow, red]) = clocks {
+            say(tr!("play.clocks", format!("{:#} {} | {:#} {}", State::YellowToken, format_clock(yellow), State::RedToken, format_clock(red))));
+        }
+    }
+
+    fn on_chat(&mut self, text : &str) {
+        println!("[{}] {}", self.remote, text);
+    }
+
+    fn outgoing_chat(&mut self) -> Vec<String> {
+        return self.chat.try_iter().collect();
+    }
+}
+
+#[doc(hidden)]
+/// Mène une partie en réseau
+///
+/// L’hôte mène la partie, choisit le joueur commençant et le temps de réflexion; le client joue
+/// les coups demandés par l’hôte. Si la connexion est perdue, le client tente de reprendre la
+/// partie auprès de l’hôte, qui l’attend pendant [`RECONNECTION_GRACE`].
+///
+/// # Arguments
+///
+///  * `host` - `true` pour héberger la partie (commande `host`), `false` pour rejoindre une
+///    partie hébergée (commande `join`)
+///  * `addr` - L’adresse d’écoute de l’hôte ou celle à laquelle se connecter
+///  * `auth` - Le jeton d’authentification exigé par l’hôte ou fourni par le client
+///    (`--auth <jeton>`)
+fn network_game(host : bool, addr : &str, auth : Option<&str>) {
+    say(tr!("net.your_name"));
+
+    let name : String;
+
+    scan!("{}", name);
+
+    let (mut connection, remote, session, token) = match connect(host, addr, &name, auth) {
+        Ok(connected) => connected,
+        Err(e) => return say(tr!("net.connect_failed", e)),
+    };
+
+    say(tr!("net.opponent", remote));
+
+    let (sender, _receiver) = mpsc::channel();
+    let (chat_sender, chat) = mpsc::channel();
+    let mut local = PlayerCLI::new(&name, sender, None);
+
+    local.set_chat(chat_sender);
+
+    if let Some(token) = token {
+        let mut events = NetworkClient { remote, chat };
+
+        loop {
+            match net::serve(&mut connection, &mut local, &mut events) {
+                Ok((area, winner)) => {
+                    print_board(&area);
+
+                    return match winner {
+                        State::NoToken => say(tr!("result.draw")),
+                        token => say(tr!("result.tokens_won", theme().glyph(token))),
+                    };
+                },
+                Err(e) => {
+                    say(tr!("net.lost", e));
+
+                    let resumed = (0..RECONNECTION_ATTEMPTS).find_map(|attempt| {
+                        if attempt > 0 {
+                            std::thread::sleep(Duration::from_secs(1));
+                        }
+
+                        return net::resume(addr, &token).ok();
+                    });
+
+                    match resumed {
+                        Some(resumed) => connection = resumed,
+                        None => return say(tr!("net.resume_failed")),
+                    }
+                },
+            }
+        }
+    }
+
+    let remote_player = NetworkPlayer::new(&remote, connection);
+
+    if let Some(session) = session {
+        remote_player.set_session(session);
+    }
+
+    let mut game = Engine::new(Box::new(local), Box::new(remote_player.clone()));
+
+    game.set_first_player(PlayerId::new(request(&tr!("prompt.first_player_network"), vec![1, 2])).unwrap()).unwrap();
+
+    let minutes : u64 = request(&tr!("prompt.minutes"), vec![0, 1, 3, 5, 10]);
+
+    if minutes > 0 {
+        let increment : u64 = request(&tr!("prompt.increment"), vec![0, 2, 5, 10]);
+
+        game.set_time_control(Some(TimeControl {
+            initial: Duration::from_secs(minutes * 60),
+            increment: Duration::from_secs(increment),
+        }));
+    }
+
+    // Les messages de discussion sont échangés et affichés entre les coups
+    let exchange_chat = || {
+        for text in chat.try_iter() {
+            if let Err(e) = remote_player.chat(&text) {
+                say(tr!("net.chat_failed", e));
+            }
+        }
+
+        for text in remote_player.take_chat() {
+            println!("[{}] {}", remote, text);
+        }
+    };
+
+    let status = loop {
+        exchange_chat();
+
+        let mut clocks = [Duration::ZERO; 2];
+
+        for player_id in [PlayerId::ONE, PlayerId::TWO] {
+            let index = if game.player_token(player_id).unwrap() == State::YellowToken { 0 } else { 1 };
+
+            clocks[index] = game.remaining_time(player_id).unwrap().unwrap_or_default();
+        }
+
+        remote_player.set_clocks(game.time_control().map(|_| clocks));
+        print_clocks(&game);
+
+        match game.step() {
+            Ok(GameStatus::InProgress) => {},
+            Ok(status) => break status,
+            Err(e) => {
+                let _ = game.abort(&e.to_string());
+
+                return say(tr!("result.interrupted", e));
+            },
+        }
+    };
+
+    let winner = match status {
+        GameStatus::Won(player_id) => game.player_token(player_id).unwrap_or(State::NoToken),
+        GameStatus::Timeout(player_id) => game.player_token(player_id.next(2)).unwrap_or(State::NoToken),
+        GameStatus::Draw | GameStatus::InProgress | GameStatus::Aborted => State::NoToken,
+    };
+    let area = game.get_disposition().clone();
+
+    exchange_chat();
+
+    if let Err(e) = remote_player.finish(&area, winner) {
+        say(tr!("net.result_failed", e));
+    }
+
+    print_board(&area);
+
+    match status {
+        GameStatus::Won(player_id) => say(tr!("result.won", game.player_name(player_id).unwrap())),
+        GameStatus::Timeout(player_id) => say(tr!("result.timeout", game.player_name(player_id).unwrap())),
+        _ => say(tr!("result.draw")),
+    }
+}
+
+#[doc(hidden)]
+/// Ajoute une partie au fichier d’archive donné
+fn archive_game(path : &str, game : &ArchivedGame) {
+    if let Err(e) = archive::append(path, game) {
+        say(tr!("archive.failed", e));
+    }
+}
+
+#[doc(hidden)]
+/// Charge les statistiques enregistrées dans le fichier donné, un fichier inexistant
+/// correspondant à des statistiques vides
+fn load_statistics(path : &str) -> Result<Statistics, String> {
+    return match Statistics::load(path) {
+        Ok(statistics) => Ok(statistics),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Statistics::new()),
+        Err(e) => Err(e.to_string()),
+    };
+}
+
+#[doc(hidden)]
+/// Affiche les statistiques enregistrées dans un fichier, globales ou détaillées par adversaire
+/// pour un joueur
+///
+/// # Arguments
+///
+///  * `path` - Le fichier contenant les statistiques
+///  * `player` - Le joueur dont détailler les statistiques par adversaire
+fn stats(path : &str, player : Option<&str>) {
+    let statistics = match load_statistics(path) {
+        Ok(statistics) => statistics,
+        Err(e) => return say(tr!("stats.load_failed", e)),
+    };
+
+    match player {
+        _ if statistics.is_empty() => say(tr!("stats.empty")),
+        None => print!("{}", statistics),
+        Some(player) => {
+            let total = statistics.player(player);
+
+            if total.stats.games() == 0 {
+                return say(tr!("stats.empty_player", player));
+            }
+
+            let summary = |record : &HeadToHead| tr!("stats.summary", record.stats.games(), record.stats.wins, record.stats.draws, record.stats.losses,
+                format!("{:.1}", record.average_length().unwrap_or(0.0)));
+
+            for (opponent, record) in statistics.opponents(player) {
+                say(tr!("stats.versus", player, opponent, summary(record)));
+            }
+
+            say(tr!("stats.total", summary(&total)));
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Organise un tournoi entre intelligences artificielles, toutes rondes ou suivant le système
+/// suisse
+///
+/// # Arguments
+///
+///  * `args` - Les arguments de la commande `tournament`
+fn tournament(args : TournamentArgs) {
+    let TournamentArgs { levels, double, swiss, ratings: ratings_path, archive: archive_path, seed } = args;
+
+    let mut tournament = Tournament::new();
+
+    for level in levels {
+        tournament.add_player(level.create(None, seed, None, Objective::Connect));
+    }
+
+    tournament.set_double_round_robin(double);
+
+    let crosstable = match swiss {
+        Some(rounds) => tournament.play_swiss(rounds),
+        None => tournament.play_round_robin(),
+    };
+
+    match crosstable {
+        Ok(crosstable) => {
+            print!("{}", crosstable);
+
+            if let Some(path) = ratings_path {
+                update_ratings(&path, |ratings| ratings.add_tournament(&tournament));
+            }
+
+            if let Some(path) = archive_path {
+                for game in tournament.games() {
+                    let status = match game.result {
+                        GameResult::Winner { player_id, .. } => GameStatus::Won(player_id),
+                        GameResult::Draw => GameStatus::Draw,
+                        GameResult::Timeout { player_id } => GameStatus::Timeout(player_id),
+                        GameResult::Aborted => GameStatus::InProgress,
+                    };
+                    let players = [String::from(crosstable.name(game.first)), String::from(crosstable.name(game.second))];
+
+                    archive_game(&path, &ArchivedGame::now(players, game.record.clone(), status));
+                }
+            }
+        },
+        Err(e) => say(tr!("tournament.interrupted", e)),
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "http")]
+/// Sert l’API REST des parties (voir [`connect_four::http`])
+///
+/// # Arguments
+///
+///  * `addr` - L’adresse d’écoute du serveur
+fn serve_http(addr : &str) {
+    let served = tokio::runtime::Builder::new_current_thread().enable_all().build().and_then(|runtime| runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+
+        say(tr!("http.serving", listener.local_addr()?));
+
+        return connect_four::http::serve(listener).await;
+    }));
+
+    if let Err(e) = served {
+        say(tr!("http.stopped", e));
+    }
+}
+
+/// Jeu de puissance 4 en ligne de commande
+///
+/// Sans commande, une partie est jouée (voir la commande `play`).
+#[derive(Parser)]
+#[command(version, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command : Option<CliCommand>,
+    #[command(flatten)]
+    play : PlayArgs,
+    #[command(flatten)]
+    display : DisplayArgs,
+}
+
+/// Options d’affichage, acceptées par toutes les commandes
+#[derive(Args)]
+struct DisplayArgs {
+    /// Thème d’affichage de la zone de jeu, détecté selon le terminal par défaut
+    #[arg(long, global = true, value_name = "THÈME", value_parser = Theme::PRESETS)]
+    theme : Option<String>,
+    /// Affiche la zone de jeu sans emoji (équivalent à `--theme ascii`)
+    #[arg(long, global = true, conflicts_with = "theme")]
+    no_emoji : bool,
+    /// Affiche la zone de jeu sous forme compacte, sans séparateurs
+    #[arg(long, global = true)]
+    compact : bool,
+    /// Anime la chute des jetons
+    #[arg(long, global = true)]
+    animate : bool,
+    /// Format de sortie des parties: texte ou un événement JSON par ligne (début de partie,
+    /// coups, zone de jeu, résultat, messages)
+    #[arg(long, global = true, value_name = "FORMAT", value_parser = ["text", "json"], default_value = "text")]
+    output : String,
+    /// Langue des messages, détectée selon la variable d’environnement `LANG` par défaut
+    #[arg(long, global = true, value_name = "LANGUE", value_parser = ["fr", "en"])]
+    lang : Option<String>,
+}
+
+#[derive(Subcommand)]
+enum CliCommand {
+    /// Joue une partie contre un autre joueur ou une intelligence artificielle
+    Play(Box<PlayArgs>),
+    /// Héberge une partie en réseau et attend la connexion d’un adversaire
+    Host(NetworkArgs),
+    /// Rejoint une partie en réseau hébergée
+    Join(NetworkArgs),
+    /// Fait s’affronter deux intelligences artificielles sur une série de parties
+    Arena(ArenaArgs),
+    /// Organise un tournoi entre intelligences artificielles
+    Tournament(TournamentArgs),
+    /// Rejoue coup par coup une partie sauvegardée
+    Replay {
+        /// Fichier contenant la partie
+        #[arg(value_name = "FICHIER")]
+        path : String,
+    },
+    /// Calcule le score exact de chaque coup d’une position
+    Solve(SolveArgs),
+    /// Joue une suite de coups lue dans un fichier ou sur l’entrée standard
+    Script(ScriptArgs),
+    /// Joue une partie de la variante en trois dimensions, sur un plateau de 4 × 4 tiges
+    ScoreFour(ScoreFourArgs),
+    /// Joue une partie à trois ou quatre joueurs
+    Party(PartyArgs),
+    /// Gère les bibliothèques d’ouvertures
+    Book {
+        #[command(subcommand)]
+        command : BookCommand,
+    },
+    /// Affiche le classement enregistré dans un fichier
+    Ratings {
+        /// Fichier contenant le classement
+        #[arg(value_name = "FICHIER")]
+        path : String,
+    },
+    /// Affiche les statistiques enregistrées dans un fichier
+    Stats {
+        /// Fichier contenant les statistiques
+        #[arg(value_name = "FICHIER")]
+        path : String,
+        /// Joueur dont détailler les statistiques par adversaire
+        #[arg(value_name = "JOUEUR")]
+        player : Option<String>,
+    },
+    /// Sert l’API REST des parties
+    #[cfg(feature = "http")]
+    Serve {
+        /// Adresse d’écoute du serveur
+        #[arg(value_name = "ADRESSE")]
+        addr : String,
+    },
+}
+
+#[derive(Subcommand)]
+enum BookCommand {
+    /// Génère par le solveur une bibliothèque d’ouvertures pour le plateau classique
+    Build {
+        /// Nombre de coups des positions enregistrées
+        #[arg(value_name = "COUPS")]
+        plies : usize,
+        /// Fichier où enregistrer la bibliothèque
+        #[arg(value_name = "FICHIER")]
+        path : String,
+    },
+}
+
+/// Paramètres d’une partie locale
+///
+/// Chaque paramètre donné n’est plus demandé au lancement de la partie.
+#[derive(Args, Clone, Default)]
+struct PlayArgs {
+    /// Nombre de joueurs humains: une seule partie est alors jouée, sans question, les paramètres
+    /// absents prenant leur valeur par défaut
+    #[arg(long, value_name = "1|2", value_parser = clap::value_parser!(u8).range(1..=2))]
+    players : Option<u8>,
+    /// Intelligence artificielle adverse en mode un joueur: facile, moyen, difficile, parfait,
+    /// glouton ou minimax:<profondeur>
+    #[arg(long, value_name = "NIVEAU", value_parser = BotLevel::from_str)]
+    bot : Option<BotLevel>,
+    /// Noms des joueurs, séparés par une virgule
+    #[arg(long, value_name = "NOMS", value_delimiter = ',')]
+    names : Vec<String>,
+    /// Dimensions de la zone de jeu: <colonnes>x<lignes>[x<jetons à aligner>][c],
+    /// `c` désignant un plateau cylindrique
+    #[arg(long, value_name = "DIMENSIONS", value_parser = parse_board)]
+    board : Option<AreaConfig>,
+    /// Cases bloquées, séparées par une virgule, sous la forme <colonne>:<ligne>, numérotées à
+    /// partir de 0 (`3:0` pour la case du bas de la quatrième colonne)
+    #[arg(long, value_name = "CASES", value_delimiter = ',', value_parser = parse_blocked, conflicts_with = "load")]
+    blocked : Vec<(usize, usize)>,
+    /// Les jetons reposent sur les cases bloquées au lieu de passer à côté
+    #[arg(long, requires = "blocked")]
+    rest_on_blocked : bool,
+    /// Joueur commençant la partie
+    #[arg(long, value_name = "1|2", value_parser = clap::value_parser!(u8).range(1..=2))]
+    first : Option<u8>,
+    /// Temps de réflexion par joueur: <minutes>[+<incrément en secondes>], 0 pour illimité
+    #[arg(long, value_name = "TEMPS", value_parser = parse_time_control)]
+    time : Option<TimeSetting>,
+    /// Graine du générateur aléatoire de l’intelligence artificielle facile et des coups tirés
+    /// par `--scramble`
+    #[arg(long, value_name = "GRAINE")]
+    seed : Option<u64>,
+    /// Reprend la partie sauvegardée dans le fichier donné
+    #[arg(long, value_name = "FICHIER")]
+    load : Option<String>,
+    /// Joue un match au meilleur de N parties (nombre impair), le joueur commençant la partie
+    /// changeant à chaque partie
+    #[arg(long = "match", value_name = "N", value_parser = parse_series, conflicts_with_all = ["load", "first"])]
+    series : Option<usize>,
+    /// Bibliothèque d’ouvertures consultée par les intelligences artificielles
+    #[arg(long, value_name = "FICHIER")]
+    book : Option<String>,
+    /// Nombre maximal d’annulations (`undo`) par joueur et par partie
+    #[arg(long, value_name = "N", default_value_t = 3)]
+    undo_limit : usize,
+    /// Joue à la variante Pop Out: chaque joueur peut aussi retirer l’un de ses jetons du bas
+    /// d’une colonne (`pop <colonne>`)
+    #[arg(long)]
+    popout : bool,
+    /// Joue en misère: le premier joueur alignant ses jetons perd la partie
+    #[arg(long)]
+    misere : bool,
+    /// Applique la règle du gâteau: le second joueur peut prendre le camp du premier après son
+    /// premier coup
+    #[arg(long, conflicts_with = "handicap")]
+    swap : bool,
+    /// Handicap: jetons placés avant la partie, sous la forme `<joueur>:<colonnes>`, les colonnes
+    /// étant séparées par une virgule et numérotées à partir de 0 (`2:3,3` pour deux jetons du
+    /// joueur 2 dans la quatrième colonne)
+    #[arg(long, value_name = "HANDICAP", value_parser = parse_handicap, conflicts_with = "load")]
+    handicap : Option<Handicap>,
+    /// Commence la partie par le nombre de coups donné, tirés au hasard parmi les positions
+    /// équilibrées selon le solveur
+    #[arg(long, value_name = "COUPS", conflicts_with_all = ["load", "handicap", "swap", "series"])]
+    scramble : Option<usize>,
+    /// Écart de score toléré par `--scramble`, 0 n’acceptant que les positions nulles
+    #[arg(long, value_name = "SCORE", default_value_t = scramble::DEFAULT_THRESHOLD, requires = "scramble")]
+    scramble_threshold : i32,
+    /// Met à jour le classement enregistré dans le fichier donné
+    #[arg(long, value_name = "FICHIER")]
+    ratings : Option<String>,
+    /// Archive les parties terminées dans le fichier donné
+    #[arg(long, value_name = "FICHIER")]
+    archive : Option<String>,
+    /// Met à jour les statistiques enregistrées dans le fichier donné
+    #[arg(long, value_name = "FICHIER")]
+    stats : Option<String>,
+    /// Affiche l’évaluation de la position sous la zone de jeu
+    #[arg(short, long)]
+    verbose : bool,
+    /// Affiche la partie en plein écran
+    #[cfg(feature = "tui")]
+    #[arg(long)]
+    tui : bool,
+}
+
+/// Temps de réflexion choisi par l’option `--time`, `None` pour un temps illimité
+#[derive(Clone, Copy)]
+struct TimeSetting(Option<TimeControl>);
+
+#[derive(Args)]
+struct NetworkArgs {
+    /// Adresse d’écoute de l’hôte ou à laquelle se connecter
+    #[arg(value_name = "ADRESSE")]
+    addr : String,
+    /// Jeton d’authentification exigé par l’hôte ou fourni par le client
+    #[arg(long, value_name = "JETON")]
+    auth : Option<String>,
+}
+
+#[derive(Args)]
+struct ArenaArgs {
+    /// Niveau de la première intelligence artificielle
+    #[arg(value_name = "NIVEAU", value_parser = BotLevel::from_str)]
+    first : BotLevel,
+    /// Niveau de la seconde intelligence artificielle
+    #[arg(value_name = "NIVEAU", value_parser = BotLevel::from_str)]
+    second : BotLevel,
+    /// Nombre de parties
+    #[arg(value_name = "PARTIES")]
+    games : usize,
+    /// Met à jour le classement enregistré dans le fichier donné
+    #[arg(long, value_name = "FICHIER")]
+    ratings : Option<String>,
+    /// Graine du générateur aléatoire de l’intelligence artificielle facile
+    #[arg(long, value_name = "GRAINE")]
+    seed : Option<u64>,
+}
+
+#[derive(Args)]
+struct TournamentArgs {
+    /// Niveaux des intelligences artificielles participantes
+    #[arg(value_name = "NIVEAU", value_parser = BotLevel::from_str, num_args = 2.., required = true)]
+    levels : Vec<BotLevel>,
+    /// Chaque paire de joueurs dispute une partie de chaque couleur
+    #[arg(long, conflicts_with = "swiss")]
+    double : bool,
+    /// Joue le nombre de rondes donné suivant le système suisse
+    #[arg(long, value_name = "RONDES")]
+    swiss : Option<usize>,
+    /// Met à jour le classement enregistré dans le fichier donné
+    #[arg(long, value_name = "FICHIER")]
+    ratings : Option<String>,
+    /// Archive les parties dans le fichier donné
+    #[arg(long, value_name = "FICHIER")]
+    archive : Option<String>,
+    /// Graine du générateur aléatoire de l’intelligence artificielle facile
+    #[arg(long, value_name = "GRAINE")]
+    seed : Option<u64>,
+}
+
+#[derive(Args)]
+struct SolveArgs {
+    /// Position à résoudre: coups joués depuis la zone de jeu vide, en notation compacte numérotant
+    /// les colonnes à partir de 1 (`4453`) ou séparés par des virgules à partir de 0 (`3,3,4,2`),
+    /// ou code de la position
+    #[arg(value_name = "POSITION")]
+    position : String,
+    /// Dimensions de la zone de jeu des colonnes jouées: <colonnes>x<lignes>[x<jetons à aligner>][c],
+    /// `c` désignant un plateau cylindrique
+    #[arg(long, value_name = "DIMENSIONS", value_parser = parse_board, default_value = "7x6")]
+    board : AreaConfig,
+}
+
+#[derive(Args)]
+struct ScriptArgs {
+    /// Fichier contenant les coups, l’entrée standard étant lue en son absence ou avec `-`
+    #[arg(value_name = "FICHIER")]
+    path : Option<String>,
+    /// Dimensions de la zone de jeu: <colonnes>x<lignes>[x<jetons à aligner>][c],
+    /// `c` désignant un plateau cylindrique
+    #[arg(long, value_name = "DIMENSIONS", value_parser = parse_board, default_value = "7x6")]
+    board : AreaConfig,
+    /// Sauvegarde la partie jouée dans un fichier
+    #[arg(long, value_name = "FICHIER")]
+    save : Option<String>,
+}
+
+#[derive(Args)]
+struct ScoreFourArgs {
+    /// Nombre de joueurs humains
+    #[arg(long, value_name = "1|2", value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 1)]
+    players : u8,
+    /// Intelligence artificielle adverse en mode un joueur: facile ou glouton
+    #[arg(long, value_name = "NIVEAU", value_parser = parse_score_four_bot, default_value = "glouton")]
+    bot : BotLevel,
+    /// Noms des joueurs, séparés par une virgule
+    #[arg(long, value_name = "NOMS", value_delimiter = ',')]
+    names : Vec<String>,
+    /// Joueur commençant la partie
+    #[arg(long, value_name = "1|2", value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 1)]
+    first : u8,
+    /// Graine du générateur aléatoire de l’intelligence artificielle facile
+    #[arg(long, value_name = "GRAINE")]
+    seed : Option<u64>,
+}
+
+#[derive(Args)]
+struct PartyArgs {
+    /// Nombre de joueurs autour du plateau
+    #[arg(long, value_name = "3|4", value_parser = clap::value_parser!(u8).range(3..=4), default_value_t = 3)]
+    seats : u8,
+    /// Nombre de joueurs humains, les places restantes étant occupées par des intelligences
+    /// artificielles
+    #[arg(long, value_name = "NOMBRE", value_parser = clap::value_parser!(u8).range(0..=4), default_value_t = 1)]
+    players : u8,
+    /// Intelligence artificielle des places restantes: facile ou glouton
+    #[arg(long, value_name = "NIVEAU", value_parser = parse_party_bot, default_value = "glouton")]
+    bot : BotLevel,
+    /// Noms des joueurs, séparés par une virgule
+    #[arg(long, value_name = "NOMS", value_delimiter = ',')]
+    names : Vec<String>,
+    /// Dimensions de la zone de jeu: `<colonnes>x<lignes>[x<jetons à aligner>][c]`, le suffixe
+    /// `c` désignant un plateau cylindrique
+    #[arg(long, value_name = "DIMENSIONS", value_parser = parse_board, default_value = "8x7")]
+    board : AreaConfig,
+    /// Graine du générateur aléatoire des intelligences artificielles faciles
+    #[arg(long, value_name = "GRAINE")]
+    seed : Option<u64>,
+}
+
+/// Lit le niveau des intelligences artificielles des parties à plus de deux joueurs, seuls les
+/// niveaux facile et glouton tenant compte de chacun des adversaires
+fn parse_party_bot(s : &str) -> Result<BotLevel, String> {
+    return match BotLevel::from_str(s)? {
+        level @ (BotLevel::Easy | BotLevel::Greedy) => Ok(level),
+        _ => Err(tr!("args.party_bot")),
+    };
+}
+
+/// Lit le niveau de l’intelligence artificielle de la variante en trois dimensions, seuls les
+/// niveaux facile et glouton sachant y jouer
+fn parse_score_four_bot(s : &str) -> Result<BotLevel, String> {
+    return match BotLevel::from_str(s)? {
+        level @ (BotLevel::Easy | BotLevel::Greedy) => Ok(level),
+        _ => Err(tr!("args.score_four_bot")),
+    };
+}
+
+/// Lit les dimensions de la zone de jeu sous la forme `<colonnes>x<lignes>[x<jetons à aligner>][c]`,
+/// quatre jetons devant être alignés par défaut et le suffixe `c` désignant un plateau cylindrique
+fn parse_board(s : &str) -> Result<AreaConfig, String> {
+    let (s, wrap_columns) = match s.strip_suffix('c') {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+    let values : Result<Vec<usize>, _> = s.split('x').map(str::parse).collect();
+
+    let (cols, rows, win_length) = match values.as_deref() {
+        Ok([cols, rows]) => (*cols, *rows, 4),
+        Ok([cols, rows, win_length]) => (*cols, *rows, *win_length),
+        _ => return Err(tr!("args.board_format")),
+    };
+
+    let config = AreaConfig { rows, cols, win_length, wrap_columns, ..AreaConfig::default() };
+
+    return config.validate().map(|_| config).map_err(|e| e.to_string());
+}
+
+/// Lit une case bloquée sous la forme `<colonne>:<ligne>`, les colonnes et les lignes étant
+/// numérotées à partir de 0 comme dans l’invite de commande, la ligne 0 étant celle du bas
+fn parse_blocked(s : &str) -> Result<(usize, usize), String> {
+    let cell = s.split_once(':').and_then(|(col, row)| Some((col.parse::<usize>().ok()?, row.parse::<usize>().ok()?)));
+
+    return cell.ok_or_else(|| tr!("args.blocked_format"));
+}
+
+/// Lit le nombre de parties d’un match, impair
+fn parse_series(s : &str) -> Result<usize, String> {
+    return match s.parse::<usize>() {
+        Ok(best_of) if best_of % 2 == 1 => Ok(best_of),
+        _ => Err(tr!("args.match_format")),
+    };
+}
+
+/// Lit un handicap sous la forme `<joueur>:<colonnes>`, les colonnes étant séparées par une virgule
+/// et numérotées à partir de 0 comme dans l’invite de commande
+fn parse_handicap(s : &str) -> Result<Handicap, String> {
+    let (player_id, list) = s.split_once(':').ok_or_else(|| tr!("args.handicap_format"))?;
+    let player_id = match player_id.parse::<usize>() {
+        Ok(1) => PlayerId::ONE,
+        Ok(2) => PlayerId::TWO,
+        _ => return Err(tr!("args.handicap_format")),
+    };
+    let columns = list.split(',').map(|column| column.trim().parse::<usize>().map_err(|_| tr!("args.handicap_format"))).collect::<Result<Vec<usize>, String>>()?;
+
+    if columns.is_empty() {
+        return Err(tr!("args.handicap_format"));
+    }
+
+    return Ok(Handicap { player_id, columns });
+}
+
+/// Lit un temps de réflexion sous la forme `<minutes>[+<incrément en secondes>]`
+fn parse_time_control(s : &str) -> Result<TimeSetting, String> {
+    let (minutes, increment) = s.split_once('+').unwrap_or((s, "0"));
+
+    let (minutes, increment) = match (minutes.parse::<u64>(), increment.parse::<u64>()) {
+        (Ok(minutes), Ok(increment)) => (minutes, increment),
+        _ => return Err(tr!("args.time_format")),
+    };
+
+    return Ok(TimeSetting((minutes != 0).then(|| TimeControl {
+        initial: Duration::from_secs(minutes * 60),
+        increment: Duration::from_secs(increment),
+    })));
+}
+
+#[doc(hidden)]
+/// Lit une position donnée par les coups joués depuis la zone de jeu vide ou par son code
+///
+/// Les coups sont donnés dans la notation compacte des enregistrements (voir
+/// [`GameRecord::to_notation`]) ou par les colonnes séparées par des virgules, numérotées à partir
+/// de 0.
+///
+/// # Retour
+///
+/// La zone de jeu et le jeton devant jouer le prochain coup
+fn read_position(position : &str, config : AreaConfig) -> Result<(Area, State), Error> {
+    if position.contains(',') {
+        let moves : Vec<usize> = position.split(',').map(|column| column.trim().parse().map_err(|_| Error::InvalidColumn)).collect::<Result<_, _>>()?;
+        let mut area = Area::with_config(config)?;
+        let mut token = State::YellowToken;
+
+        for column in moves {
+            area.set_token(token, column)?;
+
+            token = if token == State::YellowToken { State::RedToken } else { State::YellowToken };
+        }
+
+        return Ok((area, token));
+    }
+
+    let area = match GameRecord::from_notation(position, config).and_then(|record| record.positions()) {
+        Ok(mut positions) => positions.pop().ok_or(Error::InvalidPosition)?,
+        Err(_) => return Area::from_code(position),
+    };
+
+    // Les jetons alternent, y compris après un échange des camps
+    let token = if area.token_count() % 2 == 0 { State::YellowToken } else { State::RedToken };
+
+    return Ok((area, token));
+}
+
+#[doc(hidden)]
+/// Calcule et affiche le score exact de chaque coup d’une position
+///
+/// # Arguments
+///
+///  * `args` - Les arguments de la commande `solve`
+fn solve(args : SolveArgs) {
+    let (area, token) = match read_position(&args.position, args.board) {
+        Ok(position) => position,
+        Err(e) => return say(tr!("solve.invalid", e)),
+    };
+
+    println!("{}", board(&area));
+
+    let scores = match PerfectBot::new("Solveur").analyze(&area, token) {
+        Ok(scores) => scores,
+        Err(e) => return say(tr!("solve.failed", e)),
+    };
+
+    say(tr!("solve.to_move", theme().glyph(token)));
+
+    for score in &scores {
+        say(tr!("solve.column", score.column, score.score));
+    }
+
+    if let Some(best) = scores.iter().max_by_key(|s| (s.score, std::cmp::Reverse((2 * s.column).abs_diff(area.config().cols - 1)))) {
+        say(tr!("solve.best", best.column, best.score));
+    }
+}
+
+/// Lit une suite de coups, à raison d’une colonne par ligne ou en notation compacte
+///
+/// Les lignes vides et les commentaires commençant par `#` sont ignorés. Une ligne peut contenir
+/// plusieurs colonnes séparées par des virgules ou des espaces, numérotées à partir de 0. Un
+/// élément ne désignant pas une colonne de la zone de jeu est lu dans la notation compacte des
+/// enregistrements (voir [`GameRecord::to_notation`]), numérotant les colonnes à partir de 1
+/// (`4453` pour les colonnes 3, 3, 4 et 2). Les retraits et les échanges des camps n’y sont pas
+/// acceptés.
+///
+/// # Arguments
+///
+///  * `text` - Le texte décrivant les coups
+///  * `cols` - Le nombre de colonnes de la zone de jeu
+///
+/// # Retour
+///
+/// Les colonnes jouées, du premier au dernier coup, ou le premier élément invalide
+fn parse_script(text : &str, cols : usize) -> Result<Vec<usize>, String> {
+    let mut moves = Vec::new();
+
+    for line in text.lines().map(|line| line.split('#').next().unwrap_or_default()) {
+        for item in line.split(|c : char| c == ',' || c.is_whitespace()).filter(|item| !item.is_empty()) {
+            if let Some(column) = item.parse::<usize>().ok().filter(|column| *column < cols) {
+                moves.push(column);
+
+                continue;
+            }
+
+            match GameRecord::parse_moves(item) {
+                Ok((columns, pops, swaps)) if pops.is_empty() && swaps.is_empty() => moves.extend(columns),
+                _ => return Err(String::from(item)),
+            }
+        }
+    }
+
+    return Ok(moves);
+}
+
+/// Joueur jouant les coups d’une suite écrite à l’avance
+struct ScriptPlayer {
+    #[doc(hidden)]
+    name : String,
+    #[doc(hidden)]
+    moves : std::vec::IntoIter<usize>,
+}
+
+impl Interface for ScriptPlayer {
+    fn play(&mut self, _area : &Area, _token : State) -> Result<usize, PlayError> {
+        return self.moves.next().ok_or_else(|| PlayError::Other(tr!("script.exhausted")));
+    }
+
+    fn name(&self) -> String {
+        return self.name.clone();
+    }
+}
+
+#[doc(hidden)]
+/// Joue une partie de la variante en trois dimensions
+///
+/// # Arguments
+///
+///  * `args` - Les paramètres de la partie
+fn score_four(args : ScoreFourArgs) {
+    let (commands, _) = mpsc::channel();
+    let name = |id : usize| args.names.get(id - 1).cloned().unwrap_or_else(|| tr!("play.default_name", id));
+    let player = |id : usize| -> Box<dyn Interface3 + Send> {
+        if id == 2 && args.players == 1 {
+            return match (args.bot, args.seed) {
+                (BotLevel::Easy, Some(seed)) => Box::new(RandomBot::with_rng("Robot facile", Box::new(Pcg32::new(seed)))),
+                (BotLevel::Easy, None) => Box::new(RandomBot::new("Robot facile")),
+                _ => Box::new(GreedyBot::new("Robot glouton")),
+            };
+        }
+
+        return Box::new(PlayerCLI::new(&name(id), commands.clone(), None));
+    };
+
+    let mut game = Engine3::new(player(1), player(2));
+
+    game.set_first_player(PlayerId::new(usize::from(args.first)).unwrap()).unwrap();
+
+    loop {
+        match game.step() {
+            Ok(GameStatus::InProgress) => {},
+            Ok(_) => break,
+            Err(e) => {
+                say(tr!("result.interrupted", e));
+
+                return;
             }
+        }
+    }
+
+    if !JSON_OUTPUT.load(Ordering::Relaxed) {
+        println!("{}", render::render_layers(game.get_disposition(), &display().1));
+    }
+
+    match game.result() {
+        GameResult::Winner { player_id, .. } => say(tr!("result.won", game.player_name(player_id).unwrap())),
+        _ => say(tr!("result.draw")),
+    }
+}
+
+#[doc(hidden)]
+/// Joue une partie à trois ou quatre joueurs, à tour de rôle sur la même zone de jeu
+///
+/// # Arguments
+///
+///  * `args` - Les paramètres de la partie
+fn party(args : PartyArgs) {
+    let seats = args.seats as usize;
+
+    if args.players as usize > seats {
+        return say(tr!("party.too_many_players", args.players, seats));
+    }
+
+    let (commands, _) = mpsc::channel();
+    let players = (1..=seats).map(|id| -> Box<dyn Interface + Send> {
+        if id > args.players as usize {
+            return args.bot.create(None, args.seed.map(|seed| seed.wrapping_add(id as u64)), None, Objective::Connect);
+        }
+
+        let name = args.names.get(id - 1).cloned().unwrap_or_else(|| tr!("play.default_name", id));
+
+        return Box::new(PlayerCLI::new(&name, commands.clone(), None));
+    }).collect();
+
+    let mut game = Engine::with_players(players, args.board).unwrap();
+
+    observe(&mut game);
+
+    let result = game.play();
+
+    print_board(game.get_disposition());
+
+    match result {
+        Ok(GameResult::Winner { player_id, token, .. }) => say(tr!("party.won", game.player_name(player_id).unwrap(), format!("{:#}", token))),
+        Ok(_) => say(tr!("result.draw")),
+        Err(e) => say(tr!("result.interrupted", e)),
+    }
+}
+
+#[doc(hidden)]
+/// Joue une suite de coups lue dans un fichier ou sur l’entrée standard et affiche la zone de jeu
+/// finale ainsi que le résultat
+///
+/// # Arguments
+///
+///  * `args` - Les arguments de la commande `script`
+fn script(args : ScriptArgs) {
+    let text = match args.path.as_deref() {
+        None | Some("-") => std::io::read_to_string(std::io::stdin()),
+        Some(path) => std::fs::read_to_string(path),
+    };
+
+    let moves = match text.map_err(|e| e.to_string()).and_then(|text| parse_script(&text, args.board.cols)) {
+        Ok(moves) => moves,
+        Err(e) => return say(tr!("script.invalid", e)),
+    };
+
+    let total = moves.len();
+    let player = |player_id : usize| Box::new(ScriptPlayer {
+        name: tr!("play.default_name", player_id),
+        moves: moves.iter().copied().skip(player_id - 1).step_by(2).collect::<Vec<_>>().into_iter(),
+    });
+    let mut game = Engine::with_config(player(1), player(2), args.board).unwrap();
+
+    observe(&mut game);
+
+    let result = game.play();
+    let played = game.history().len();
+
+    print_board(game.get_disposition());
+
+    match &result {
+        Ok(GameResult::Winner { player_id, .. }) => say(tr!("result.won", game.player_name(*player_id).unwrap())),
+        Ok(_) => say(tr!("result.draw")),
+        Err(Error::InterfaceFailure(_)) => say(tr!("script.unfinished", played)),
+        Err(e) => say(tr!("script.illegal", played + 1, moves[played], e)),
+    }
+
+    if result.is_ok() && played < total {
+        say(tr!("script.ignored", total - played));
+    }
+
+    if let Some(path) = args.save {
+        match save(&game, &path) {
+            Ok(()) => say(tr!("play.saved", path)),
+            Err(e) => say(tr!("play.save_failed", e)),
+        }
+    }
+}
+
+fn main() {
+    i18n::set_lang(Lang::detect());
+
+    let cli = Cli::parse();
+
+    if let Some(lang) = cli.display.lang.as_deref().and_then(|lang| lang.parse().ok()) {
+        i18n::set_lang(lang);
+    }
+
+    let theme = match (cli.display.theme, cli.display.no_emoji) {
+        (_, true) => Theme::ascii(),
+        (Some(name), false) => name.parse().unwrap_or_else(|()| Theme::detect()),
+        (None, false) => Theme::detect(),
+    };
+
+    ANIMATE.store(cli.display.animate, Ordering::Relaxed);
+    JSON_OUTPUT.store(cli.display.output == "json", Ordering::Relaxed);
+
+    let _ = DISPLAY.set(display_for(theme, cli.display.compact));
+
+    match cli.command.unwrap_or(CliCommand::Play(Box::new(cli.play))) {
+        CliCommand::Play(args) => play(*args),
+        CliCommand::Host(args) => network_game(true, &args.addr, args.auth.as_deref()),
+        CliCommand::Join(args) => network_game(false, &args.addr, args.auth.as_deref()),
+        CliCommand::Arena(args) => arena(args),
+        CliCommand::Tournament(args) => tournament(args),
+        CliCommand::Replay { path } => replay(&path),
+        CliCommand::Solve(args) => solve(args),
+        CliCommand::Script(args) => script(args),
+        CliCommand::ScoreFour(args) => score_four(args),
+        CliCommand::Party(args) => party(args),
+        CliCommand::Book { command: BookCommand::Build { plies, path } } => build_book(plies, &path),
+        CliCommand::Ratings { path } => ratings(&path),
+        CliCommand::Stats { path, player } => stats(&path, player.as_deref()),
+        #[cfg(feature = "http")]
+        CliCommand::Serve { addr } => serve_http(&addr),
+    }
+}
+
+#[doc(hidden)]
+/// Gestionnaire de Ctrl-C installé durant les parties
+///
+/// Le premier Ctrl-C interrompt la partie en cours, qui peut alors être sauvegardée. Le second
+/// rétablit le terminal et quitte immédiatement.
+fn on_interrupt() {
+    let json = JSON_OUTPUT.load(Ordering::Relaxed);
+
+    if INTERRUPTED.swap(true, Ordering::Relaxed) {
+        if !json {
+            println!("\r\x1b[0m\x1b[K");
+        }
+
+        std::process::exit(130);
+    }
+
+    if !json {
+        println!("\r\x1b[0m\x1b[K");
+    }
+
+    say(tr!("interrupt.requested"));
+}
+
+#[doc(hidden)]
+/// Propose de sauvegarder puis archive une partie interrompue par Ctrl-C
+///
+/// # Arguments
+///
+///  * `game` - La partie interrompue
+///  * `archive_path` - L’archive où enregistrer la partie, notée comme inachevée
+///  * `batch` - Ne pose aucune question
+fn abort_game(game : &Engine, archive_path : Option<&str>, batch : bool) {
+    say(tr!("interrupt.aborted"));
+
+    if !batch && !game.history().is_empty() {
+        say(tr!("prompt.save_interrupted"));
+
+        let mut line = String::new();
+        let _ = std::io::stdin().read_line(&mut line);
+        let path = line.trim();
+
+        if !path.is_empty() {
+            match save(game, path) {
+                Ok(()) => say(tr!("play.saved", path)),
+                Err(e) => say(tr!("play.save_failed", e)),
+            }
+        }
+    }
+
+    if let Some(path) = archive_path {
+        let players = [game.player_name(PlayerId::ONE).unwrap_or_default(), game.player_name(PlayerId::TWO).unwrap_or_default()];
+
+        archive_game(path, &ArchivedGame::now(players, game.record(), game.status()));
+    }
+}
+
+#[doc(hidden)]
+/// Joue des parties locales, contre un autre joueur ou une intelligence artificielle
+///
+/// # Arguments
+///
+///  * `args` - Les paramètres des parties, demandés au joueur lorsqu’ils sont absents
+fn play(args : PlayArgs) {
+    if let Err(e) = ctrlc::set_handler(on_interrupt) {
+        say(tr!("interrupt.handler_failed", e));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let mut game = Engine::new(Box::new(PlayerCLI::new("Joueur 1", sender.clone(), None)), Box::new(PlayerCLI::new("Joueur 2", sender.clone(), None)));
+
+    game.set_retry_policy(RetryPolicy::Reprompt { max_attempts: 3 }).unwrap();
+    game.set_variant(if args.popout { Variant::PopOut } else { Variant::Standard }).unwrap();
+    game.set_objective(if args.misere { Objective::Misere } else { Objective::Connect }).unwrap();
+    game.set_swap_rule(args.swap).unwrap();
+
+    if args.misere {
+        say(tr!("play.misere_rules"));
+    }
+    observe(&mut game);
+
+    let mut pending_load = match args.load.as_deref().map(read_record) {
+        Some(Ok(record)) => Some(record),
+        Some(Err(e)) => return say(tr!("play.load_failed", e)),
+        None => None,
+    };
+
+    let book = match args.book.as_deref().map(OpeningBook::load) {
+        Some(Ok(book)) => Some(Arc::new(book)),
+        Some(Err(e)) => return say(tr!("play.book_failed", e)),
+        None => None,
+    };
+
+    // Une partie lancée avec le nombre de joueurs ne pose aucune question
+    let batch = args.players.is_some();
+    let (ratings_path, archive_path, statistics_path) = (args.ratings, args.archive, args.stats);
+    let (names, verbose, undo_limit, pop_out) = (args.names, args.verbose, args.undo_limit, args.popout);
+    let objective = if args.misere { Objective::Misere } else { Objective::Connect };
+    let mut series = args.series.map(|best_of| Series::new(best_of).unwrap());
+
+    #[cfg(feature = "tui")]
+    let tui = args.tui.then(Tui::new);
+
+    let player = |name : &str| -> Box<dyn Interface + Send> {
+        #[cfg(feature = "tui")]
+        if let Some(tui) = &tui {
+            return Box::new(tui.player(name));
+        }
+
+        let mut player = PlayerCLI::new(name, sender.clone(), book.clone());
+
+        player.set_verbose(verbose);
+        player.set_pop_out(pop_out);
+        player.set_objective(objective);
+
+        return Box::new(player);
+    };
+
+    // Nom du joueur donné par l’option `--names` ou, à défaut, saisi
+    let player_name = |player_id : usize| -> String {
+        if let Some(name) = names.get(player_id - 1) {
+            return name.clone();
+        }
+
+        if batch {
+            return tr!("play.default_name", player_id);
+        }
+
+        say(tr!("prompt.player_name", player_id));
+
+        let name : String;
+
+        scan!("{}", name);
+
+        return name;
+    };
+
+    loop {
+        // Les parties suivantes d’un match conservent les paramètres de la première
+        if let Some(series) = series.filter(|series| series.stats().games() > 0) {
+            series.prepare(&mut game).unwrap();
         } else {
-            break;
+            let players = match (args.players, args.bot) {
+                (Some(players), _) => usize::from(players),
+                (None, Some(_)) => 1,
+                (None, None) => request(&tr!("prompt.players"), vec![1, 2]),
+            };
+            let record = pending_load.take();
+
+            if record.is_none() {
+                let custom = match args.board {
+                    Some(_) => String::new(),
+                    None if batch => String::from("n"),
+                    None => {
+                        say(tr!("prompt.custom_board"));
+
+                        let custom : String;
+
+                        scan!("{}", custom);
+
+                        custom
+                    }
+                };
+
+                let mut config = if let Some(config) = args.board {
+                    config
+                } else if custom == "y" {
+                    let cols = request(&tr!("prompt.cols"), (4..=12).collect());
+                    let rows = request(&tr!("prompt.rows"), (4..=usize::min(12, 64 / cols - 1)).collect());
+                    let win_length = request(&tr!("prompt.win_length"), (3..=usize::max(cols, rows)).collect());
+
+                    AreaConfig { rows, cols, win_length, ..AreaConfig::default() }
+                } else {
+                    AreaConfig::default()
+                };
+
+                for (col, row) in args.blocked.iter() {
+                    if let Err(e) = config.block(*col, *row) {
+                        return say(tr!("play.blocked_failed", e));
+                    }
+                }
+
+                config.rest_on_blocked = args.rest_on_blocked;
+
+                if let Err(e) = config.validate() {
+                    return say(tr!("play.blocked_failed", e));
+                }
+
+                if config != *game.config() {
+                    game.set_config(config).unwrap();
+                }
+
+                let first_player = match args.first {
+                    Some(first_player) => usize::from(first_player),
+                    None if batch => 1,
+                    None => request(&tr!("prompt.first_player"), vec![1, 2]),
+                };
+
+                game.set_first_player(PlayerId::new(first_player).unwrap()).unwrap();
+
+                if let Err(e) = game.set_handicap(args.handicap.clone()) {
+                    return say(tr!("play.handicap_failed", e));
+                }
+
+                if let Some(plies) = args.scramble {
+                    let mut scramble = Scramble::new(plies);
+                    let mut rng : Box<dyn Rng> = match args.seed {
+                        Some(seed) => Box::new(Pcg32::new(seed)),
+                        None => Box::new(OsRng),
+                    };
+
+                    scramble.threshold = args.scramble_threshold;
+                    say(tr!("play.scramble_searching", plies));
+
+                    match scramble.generate(*game.config(), rng.as_mut(), &mut Solver::new(), book.as_deref()) {
+                        Ok(moves) => for col in moves {
+                            game.apply_move(col).unwrap();
+                        },
+                        Err(e) => return say(tr!("play.scramble_failed", e)),
+                    }
+                }
+            }
+
+            let time_control = match args.time {
+                Some(TimeSetting(time_control)) => time_control,
+                None if batch => None,
+                None => match request(&tr!("prompt.minutes"), vec![0, 1, 3, 5, 10]) {
+                    0 => None,
+                    minutes => Some(TimeControl {
+                        initial: Duration::from_secs(minutes * 60),
+                        increment: Duration::from_secs(request(&tr!("prompt.increment"), vec![0, 2, 5, 10])),
+                    }),
+                },
+            };
+
+            game.set_time_control(time_control);
+            game.set_player(PlayerId::ONE, player(&player_name(1))).unwrap();
+
+            if players == 2 {
+                game.set_player(PlayerId::TWO, player(&player_name(2))).unwrap();
+            } else {
+                let level = match args.bot {
+                    Some(level) => level,
+                    None if batch => BotLevel::Medium,
+                    None => request(&tr!("prompt.level"), BotLevel::ALL.to_vec()),
+                };
+                let objective = record.as_ref().map_or(game.objective(), |record| record.objective);
+
+                // Le solveur ne sait résoudre que les positions du jeu classique
+                let level = if level == BotLevel::Perfect && objective == Objective::Misere {
+                    say(tr!("play.misere_perfect"));
+
+                    BotLevel::Hard
+                } else {
+                    level
+                };
+
+                game.set_player(PlayerId::TWO, Box::new(ThinkingBot::create(level, book.clone(), args.seed, objective))).unwrap();
+            }
+
+            if let Some(record) = record {
+                match game.load_record(&record) {
+                    Ok(GameStatus::InProgress) => {},
+                    Ok(_) => {
+                        say(tr!("play.loaded_finished"));
+
+                        game.reset();
+                    },
+                    Err(e) => {
+                        say(tr!("play.load_failed", e));
+
+                        game.reset();
+                    }
+                }
+            }
+
+            if let Some(series) = &series {
+                series.prepare(&mut game).unwrap();
+            }
+        }
+
+        #[cfg(feature = "tui")]
+        if let Some(Err(e)) = tui.as_ref().map(Tui::start) {
+            say(tr!("play.tui_failed", e));
+
+            return;
+        }
+
+        // Annulations déjà demandées par chaque joueur durant la partie
+        let mut undos = [0; 2];
+
+        let status = loop {
+            if INTERRUPTED.load(Ordering::Relaxed) {
+                break Err(Error::Aborted);
+            }
+
+            #[cfg(feature = "tui")]
+            if let Some(tui) = &tui {
+                tui.update(&game);
+            } else {
+                print_clocks(&game);
+            }
+
+            #[cfg(not(feature = "tui"))]
+            print_clocks(&game);
+
+            match game.step() {
+                Ok(GameStatus::InProgress) => {},
+                Err(Error::InterfaceFailure(e)) => match receiver.try_recv() {
+                    Ok(Command::Save(path)) => match save(&game, &path) {
+                        Ok(()) => say(tr!("play.saved", path)),
+                        Err(e) => say(tr!("play.save_failed", e)),
+                    },
+                    Ok(Command::Load(path)) => match load(&mut game, &path) {
+                        Ok(GameStatus::InProgress) => say(tr!("play.loaded", path)),
+                        Ok(status) => break Ok(status),
+                        Err(e) => say(tr!("play.load_failed", e)),
+                    },
+                    Ok(Command::Undo) => {
+                        let player_id = game.history().last().map_or(game.first_player(), |played| played.player.next(2));
+
+                        if undos[player_id.index()] >= undo_limit {
+                            say(tr!("undo.limit", undo_limit));
+                        } else if game.history().len() < 2 {
+                            say(tr!("undo.nothing"));
+                        } else {
+                            game.undo().and_then(|_| game.undo()).unwrap();
+                            undos[player_id.index()] += 1;
+
+                            say(tr!("undo.done", undo_limit - undos[player_id.index()]));
+                        }
+                    },
+                    Err(_) => break Err(Error::InterfaceFailure(e)),
+                },
+                r => break r,
+            }
+        };
+
+        // Les interfaces et les observateurs sont prévenus de l’interruption de la partie
+        if let Err(e) = &status {
+            let _ = game.abort(&e.to_string());
+        }
+
+        #[cfg(feature = "tui")]
+        if let Some(Err(e)) = tui.as_ref().map(Tui::stop) {
+            say(tr!("play.tui_restore_failed", e));
+        }
+
+        match status {
+            Ok(v) => {
+                print_board(game.get_disposition());
+
+                match v {
+                    GameStatus::Won(player_id) => say(tr!("result.won", game.player_name(player_id).unwrap())),
+                    GameStatus::Draw => say(tr!("result.draw")),
+                    GameStatus::Timeout(player_id) => say(tr!("result.timeout", game.player_name(player_id).unwrap())),
+                    GameStatus::InProgress | GameStatus::Aborted => unreachable!(),
+                }
+
+                if let Some(path) = &ratings_path {
+                    let score = match v {
+                        GameStatus::Won(PlayerId::ONE) | GameStatus::Timeout(PlayerId::TWO) => 1.0,
+                        GameStatus::Draw => 0.5,
+                        _ => 0.0,
+                    };
+                    let first = game.player_name(PlayerId::ONE).unwrap_or_default();
+                    let second = game.player_name(PlayerId::TWO).unwrap_or_default();
+
+                    update_ratings(path, |ratings| ratings.add_game(&first, &second, score));
+                }
+
+                if let Some(path) = &archive_path {
+                    let players = [game.player_name(PlayerId::ONE).unwrap_or_default(), game.player_name(PlayerId::TWO).unwrap_or_default()];
+
+                    archive_game(path, &ArchivedGame::now(players, game.record(), v));
+                }
+
+                if let Some(path) = &statistics_path {
+                    let first = game.player_name(PlayerId::ONE).unwrap_or_default();
+                    let second = game.player_name(PlayerId::TWO).unwrap_or_default();
+                    let result = load_statistics(path).and_then(|mut statistics| {
+                        statistics.add_record(&first, &second, &game.record(), v).map_err(|e| e.to_string())?;
+
+                        return statistics.save(path).map_err(|e| e.to_string());
+                    });
+
+                    if let Err(e) = result {
+                        say(tr!("stats.update_failed", e));
+                    }
+                }
+
+                if let Some(series) = &mut series {
+                    series.add(v);
+
+                    let (first, second) = (game.player_name(PlayerId::ONE).unwrap_or_default(), game.player_name(PlayerId::TWO).unwrap_or_default());
+                    let stats = series.stats();
+
+                    say(tr!("match.score", first, stats.wins, stats.losses, second, stats.draws));
+
+                    if !series.is_over() {
+                        continue;
+                    }
+
+                    match series.winner() {
+                        Some(player_id) => say(tr!("match.won", game.player_name(player_id).unwrap())),
+                        None => say(tr!("match.draw")),
+                    }
+
+                    break;
+                }
+
+                if batch {
+                    break;
+                }
+
+                say(tr!("prompt.analyze"));
+
+                let analyze : String;
+
+                scan!("{}", analyze);
+
+                if analyze == "y" {
+                    print_review(&game);
+                }
+            },
+            Err(_) if INTERRUPTED.load(Ordering::Relaxed) => {
+                abort_game(&game, archive_path.as_deref(), batch);
+
+                break;
+            },
+            Err(e) => {
+                say(tr!("result.interrupted", e));
+
+                break;
+            }
         }
 
-        println!("Rejouer? [y/n]");
+        say(tr!("prompt.again"));
 
         let com : String;
 
@@ -0,0 +1,708 @@
+//! Implémentation d’une intelligence artificielle explorant les coups possibles via l’algorithme
+//! minimax
+//!
+//! L’exploration est limitée à une profondeur donnée. Les victoires et défaites sont détectées
+//! directement, plus une victoire étant proche, plus elle est favorisée. Les positions atteintes
+//! en fin d’exploration sont estimées par un [`Evaluator`], par défaut le
+//! [`HeuristicEvaluator`].
+//!
+//! Les branches ne pouvant pas modifier le coup choisi sont élaguées (élagage alpha-bêta) et les
+//! colonnes centrales, généralement meilleures, sont explorées en premier afin de maximiser cet
+//! élagage. Une profondeur de 10 reste ainsi jouable en temps interactif sur le plateau classique.
+//!
+//! Les positions déjà évaluées sont conservées dans une [`TranspositionTable`] afin de ne pas
+//! explorer plusieurs fois une position atteinte par différents ordres de coups.
+//!
+//! L’intelligence artificielle peut également préparer sa réponse pendant le tour de l’adversaire
+//! (voir [`MinimaxBot::set_pondering`]).
+//!
+//! Avec la fonctionnalité `rayon`, la recherche peut être répartie sur plusieurs cœurs (voir
+//! [`MinimaxBot::set_parallel`]).
+//!
+//! L’avancement des recherches peut être suivi, et les recherches interrompues, depuis un autre
+//! fil d’exécution (voir [`MinimaxBot::set_progress`]).
+
+use super::connect_four::{Interface, Action, Area, AreaConfig, Objective, State, PlayError, Error, Result};
+use super::transposition::{TranspositionTable, Entry, Bound};
+use super::book::OpeningBook;
+use super::eval::{Evaluator, HeuristicEvaluator};
+use super::analysis::{Analyzer, ColumnScore, Score};
+use super::progress::SearchProgress;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+#[cfg(feature = "rayon")]
+use super::transposition::SharedTranspositionTable;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Score d’une victoire obtenue au dernier niveau d’exploration
+const WIN_SCORE : i32 = 1_000_000;
+
+/// Mémoire allouée par défaut à la table de transposition (4 Mio)
+const DEFAULT_TABLE_MEMORY : usize = 4 << 20;
+
+/// Profondeur de la recherche prévoyant le coup de l’adversaire
+const PREDICTION_DEPTH : usize = 4;
+
+/// Clé combinée à celle de la position lorsque les jetons rouges doivent jouer
+const RED_TO_PLAY_KEY : u64 = 0x2545_F491_4F6C_DD1D;
+
+/// Intelligence artificielle minimax
+pub struct MinimaxBot {
+    #[doc(hidden)]
+    name : String,
+    #[doc(hidden)]
+    depth : usize,
+    #[doc(hidden)]
+    table : Option<TranspositionTable>,
+    /// Configuration des positions enregistrées dans la table de transposition
+    #[doc(hidden)]
+    table_config : Option<AreaConfig>,
+    #[doc(hidden)]
+    book : Option<Arc<OpeningBook>>,
+    #[doc(hidden)]
+    evaluator : Arc<dyn Evaluator>,
+    #[doc(hidden)]
+    pondering : bool,
+    #[doc(hidden)]
+    progress : Option<Arc<SearchProgress>>,
+    #[doc(hidden)]
+    objective : Objective,
+    /// Positions explorées lors de la recherche du dernier coup joué
+    #[doc(hidden)]
+    nodes : Option<u64>,
+    /// Réflexion en cours pendant le tour de l’adversaire
+    #[doc(hidden)]
+    ponder : Option<Ponder>,
+    #[doc(hidden)]
+    #[cfg(feature = "rayon")]
+    parallel : bool,
+    /// Table de transposition utilisée lors de la recherche parallèle
+    #[doc(hidden)]
+    #[cfg(feature = "rayon")]
+    shared_table : Option<SharedTranspositionTable>,
+}
+
+/// Réflexion menée pendant le tour de l’adversaire
+struct Ponder {
+    /// Position attendue après le coup prévu de l’adversaire
+    expected : Area,
+    /// Drapeau interrompant la réflexion
+    stop : Arc<AtomicBool>,
+    /// Fil d’exécution fournissant le coup préparé ainsi que la table de transposition utilisée
+    handle : JoinHandle<(Option<usize>, Option<TranspositionTable>)>,
+}
+
+impl MinimaxBot {
+    /// Initialise l’intelligence artificielle
+    ///
+    /// # Arguments
+    ///
+    ///  * `name` : Le nom donné à l’intelligence artificielle.
+    ///  * `depth` : Le nombre de coups explorés à l’avance, y compris celui à jouer. Une
+    ///    profondeur nulle est ramenée à un.
+    ///
+    /// # Retour
+    ///
+    /// Une instance de l’intelligence artificielle
+    pub fn new(name : &str, depth : usize) -> Self {
+        Self {
+            name: String::from(name),
+            depth: usize::max(depth, 1),
+            table: Some(TranspositionTable::with_memory(DEFAULT_TABLE_MEMORY)),
+            table_config: None,
+            book: None,
+            evaluator: Arc::new(HeuristicEvaluator),
+            pondering: false,
+            progress: None,
+            objective: Objective::Connect,
+            nodes: None,
+            ponder: None,
+            #[cfg(feature = "rayon")]
+            parallel: false,
+            #[cfg(feature = "rayon")]
+            shared_table: None,
+        }
+    }
+
+    /// Remplace la table de transposition utilisée lors de la recherche
+    ///
+    /// Par défaut, une table de 4 Mio est utilisée.
+    ///
+    /// # Arguments
+    ///
+    ///  * `table` : La nouvelle table ou `None` afin de désactiver la table de transposition
+    pub fn set_transposition_table(&mut self, table : Option<TranspositionTable>) {
+        self.finish_ponder(None);
+        self.table = table;
+        self.table_config = None;
+
+        #[cfg(feature = "rayon")]
+        self.set_parallel(self.parallel);
+    }
+
+    /// Remplace la fonction d’évaluation des positions atteintes en fin d’exploration
+    ///
+    /// # Arguments
+    ///
+    ///  * `evaluator` : La nouvelle fonction d’évaluation, pouvant être partagée avec d’autres
+    ///    intelligences artificielles
+    pub fn set_evaluator(&mut self, evaluator : Arc<dyn Evaluator>) {
+        self.evaluator = evaluator;
+        self.table_config = None;
+    }
+
+    /// Remplace la bibliothèque d’ouvertures consultée avant toute recherche
+    ///
+    /// # Arguments
+    ///
+    ///  * `book` : La nouvelle bibliothèque ou `None` afin de toujours effectuer la recherche
+    pub fn set_opening_book(&mut self, book : Option<Arc<OpeningBook>>) {
+        self.book = book;
+    }
+
+    /// Active ou désactive la réflexion pendant le tour de l’adversaire
+    ///
+    /// Lorsqu’elle est active, l’intelligence artificielle prévoit le coup de l’adversaire dès le
+    /// début de son tour puis prépare sa réponse sur un autre fil d’exécution. Si l’adversaire
+    /// joue le coup prévu, cette réponse est reprise, sinon elle est abandonnée.
+    ///
+    /// # Arguments
+    ///
+    ///  * `pondering` : `true` afin d’activer la réflexion pendant le tour de l’adversaire
+    pub fn set_pondering(&mut self, pondering : bool) {
+        self.pondering = pondering;
+
+        if !pondering {
+            self.finish_ponder(None);
+        }
+    }
+
+    /// Partage l’avancement des recherches
+    ///
+    /// Au début de chaque recherche, le suivi est remis à zéro avec la profondeur de la recherche,
+    /// puis chaque position explorée y est comptabilisée. Les réflexions menées pendant le tour
+    /// de l’adversaire ne sont pas suivies.
+    ///
+    /// Une fois le suivi interrompu (voir [`SearchProgress::cancel`]), la recherche en cours
+    /// s’arrête au plus vite et l’intelligence artificielle refuse de jouer.
+    ///
+    /// # Arguments
+    ///
+    ///  * `progress` : Le suivi à mettre à jour ou `None` afin de ne pas suivre les recherches
+    pub fn set_progress(&mut self, progress : Option<Arc<SearchProgress>>) {
+        self.progress = progress;
+    }
+
+    /// Modifie l’objectif poursuivi par l’intelligence artificielle
+    ///
+    /// En misère (voir [`Objective::Misere`]), les coups formant un alignement sont perdants et
+    /// l’évaluation des positions est celle de l’évaluateur pour cet objectif (voir
+    /// [`Evaluator::evaluate_for`]). La bibliothèque d’ouvertures, calculée pour le jeu classique,
+    /// n’est alors plus consultée.
+    ///
+    /// # Arguments
+    ///
+    ///  * `objective` : L’objectif des parties jouées
+    pub fn set_objective(&mut self, objective : Objective) {
+        self.finish_ponder(None);
+        self.objective = objective;
+        self.table_config = None;
+    }
+
+    /// Vide les tables de transposition si elles concernent une autre configuration de zone de jeu
+    fn prepare_tables(&mut self, config : &AreaConfig) {
+        if self.table_config == Some(*config) {
+            return;
+        }
+
+        if let Some(table) = self.table.as_mut() {
+            table.clear();
+        }
+
+        #[cfg(feature = "rayon")]
+        if let Some(table) = self.shared_table.as_ref() {
+            table.clear();
+        }
+
+        self.table_config = Some(*config);
+    }
+
+    /// Met fin à la réflexion menée pendant le tour de l’adversaire et récupère la table de
+    /// transposition qu’elle utilisait
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position sur laquelle jouer, la réflexion étant abandonnée si elle ne
+    ///    correspond pas à la position prévue
+    ///
+    /// # Retour
+    ///
+    /// Le coup préparé si la position correspond à celle prévue
+    fn finish_ponder(&mut self, area : Option<&Area>) -> Option<usize> {
+        let ponder = self.ponder.take()?;
+        let expected = area == Some(&ponder.expected);
+
+        if !expected {
+            ponder.stop.store(true, Ordering::Relaxed);
+        }
+
+        let (col, table) = ponder.handle.join().ok()?;
+
+        self.table = table;
+
+        return col.filter(|_| expected);
+    }
+
+    /// Active ou désactive la recherche parallèle
+    ///
+    /// Lorsqu’elle est active, le coup le plus central est exploré en premier puis les autres
+    /// coups sont explorés simultanément sur l’ensemble des cœurs disponibles (via `rayon`). La
+    /// table de transposition est alors remplacée par une [`SharedTranspositionTable`] de même
+    /// capacité, partagée entre les fils d’exécution.
+    ///
+    /// # Arguments
+    ///
+    ///  * `parallel` : `true` afin d’activer la recherche parallèle
+    #[cfg(feature = "rayon")]
+    pub fn set_parallel(&mut self, parallel : bool) {
+        self.parallel = parallel;
+        self.shared_table = match (&self.table, parallel) {
+            (Some(table), true) => Some(SharedTranspositionTable::new(table.capacity())),
+            _ => None,
+        };
+        self.table_config = None;
+    }
+
+    /// Recherche le meilleur coup en explorant les coups à la racine en parallèle
+    #[cfg(feature = "rayon")]
+    fn play_parallel(&mut self, area : &Area, token : State) -> std::result::Result<usize, PlayError> {
+        let columns = ordered_columns(area);
+        let (first, others) = columns.split_first().ok_or_else(|| PlayError::Other(String::from("Aucune colonne disponible")))?;
+        let evaluator = &*self.evaluator;
+        let shared = self.shared_table.as_ref();
+        let progress = self.progress.as_deref();
+        let objective = self.objective;
+        let search = || Search { table: shared, evaluator, stop: None, progress, objective, nodes: 0 };
+
+        if let Some(table) = shared {
+            table.new_search();
+        }
+
+        // Le premier coup fourni une borne permettant d’élaguer l’exploration des suivants
+        let mut first_search = search();
+        let first_score = root_score(&mut first_search, &mut area.clone(), token, *first, self.depth, -i32::MAX);
+
+        let scores : Vec<(i32, u64)> = others.par_iter().map(|col| {
+            let mut search = search();
+            let score = root_score(&mut search, &mut area.clone(), token, *col, self.depth, first_score);
+
+            return (score, search.nodes);
+        }).collect();
+
+        let mut best = (*first, first_score);
+        let mut nodes = first_search.nodes;
+
+        for (col, (score, searched)) in others.iter().zip(scores) {
+            nodes += searched;
+
+            if score > best.1 {
+                best = (*col, score);
+            }
+        }
+
+        self.nodes = Some(nodes);
+
+        if progress.is_some_and(SearchProgress::is_cancelled) {
+            return Err(interrupted());
+        }
+
+        return Ok(best.0);
+    }
+}
+
+/// Erreur retournée lorsque la recherche du coup à jouer a été interrompue
+fn interrupted() -> PlayError {
+    return PlayError::Other(String::from("Recherche interrompue"));
+}
+
+impl Interface for MinimaxBot {
+    fn play(&mut self, area : &Area, token : State) -> std::result::Result<usize, PlayError> {
+        self.nodes = None;
+
+        if let Some(col) = self.finish_ponder(Some(area)) {
+            return Ok(col);
+        }
+
+        if let Some(entry) = self.book.as_ref().filter(|_| self.objective == Objective::Connect).and_then(|book| book.get(area, token)) {
+            return Ok(entry.column);
+        }
+
+        self.prepare_tables(area.config());
+
+        if let Some(progress) = &self.progress {
+            progress.start(Some(self.depth));
+        }
+
+        #[cfg(feature = "rayon")]
+        if self.parallel {
+            return self.play_parallel(area, token);
+        }
+
+        let mut search = Search { table: self.table.as_mut(), evaluator: &*self.evaluator, stop: None, progress: self.progress.as_deref(), objective: self.objective, nodes: 0 };
+
+        if let Some(table) = search.table.as_mut() {
+            table.new_search();
+        }
+
+        let best = best_move(&mut search, &mut area.clone(), token, self.depth);
+        let (stopped, nodes) = (search.stopped(), search.nodes);
+
+        self.nodes = Some(nodes);
+
+        if stopped {
+            return Err(interrupted());
+        }
+
+        return best.ok_or_else(|| PlayError::Other(String::from("Aucune colonne disponible")));
+    }
+
+    fn name(&self) -> String {
+        return self.name.clone();
+    }
+
+    fn nodes_searched(&self) -> Option<u64> {
+        return self.nodes;
+    }
+
+    fn on_opponent_thinking(&mut self, area : &Area, token : State) {
+        if !self.pondering {
+            return;
+        }
+
+        self.finish_ponder(None);
+        self.prepare_tables(area.config());
+
+        let mut expected = area.clone();
+        let predicted = {
+            let mut search = Search { table: self.table.as_mut(), evaluator: &*self.evaluator, stop: None, progress: None, objective: self.objective, nodes: 0 };
+
+            best_move(&mut search, &mut expected, token, usize::min(self.depth, PREDICTION_DEPTH))
+        };
+
+        match predicted {
+            Some(col) if matches!(expected.set_token(token, col), Ok(false)) && !expected.get_available_columns().is_empty() => {},
+            _ => return,
+        }
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let evaluator = Arc::clone(&self.evaluator);
+        let mut table = self.table.take();
+        let (thread_stop, mut position, depth, objective) = (Arc::clone(&stop), expected.clone(), self.depth, self.objective);
+
+        let handle = thread::spawn(move || {
+            if let Some(table) = table.as_mut() {
+                table.new_search();
+            }
+
+            let mut search = Search { table: table.as_mut(), evaluator: &*evaluator, stop: Some(&*thread_stop), progress: None, objective, nodes: 0 };
+            let col = best_move(&mut search, &mut position, opponent(token), depth);
+
+            return (col, table);
+        });
+
+        self.ponder = Some(Ponder { expected, stop, handle });
+    }
+
+    fn on_opponent_move(&mut self, area : &Area, _action : Action) {
+        if self.ponder.as_ref().is_some_and(|ponder| ponder.expected != *area) {
+            self.finish_ponder(None);
+        }
+    }
+}
+
+/// Les coups sont explorés avec la même profondeur que lors du jeu. Les victoires et défaites
+/// découvertes dans cette limite sont exactes, les autres coups étant estimés.
+impl Analyzer for MinimaxBot {
+    fn analyze(&mut self, area : &Area, token : State) -> Result<Vec<ColumnScore>> {
+        if token == State::NoToken {
+            return Err(Error::NotAToken);
+        }
+
+        if !area.winning_cells().is_empty() {
+            return Err(Error::GameAlreadyFinished);
+        }
+
+        self.finish_ponder(None);
+        self.prepare_tables(area.config());
+
+        if let Some(progress) = &self.progress {
+            progress.start(Some(self.depth));
+        }
+
+        let depth = self.depth;
+        let mut area = area.clone();
+        let mut search = Search { table: self.table.as_mut(), evaluator: &*self.evaluator, stop: None, progress: self.progress.as_deref(), objective: self.objective, nodes: 0 };
+
+        if let Some(table) = search.table.as_mut() {
+            table.new_search();
+        }
+
+        let mut scores = Vec::new();
+
+        for column in area.get_available_columns() {
+            let score = root_score(&mut search, &mut area, token, column, depth, -i32::MAX);
+
+            scores.push(ColumnScore { column, score: to_score(score, depth) });
+        }
+
+        if search.stopped() {
+            return Err(Error::Aborted);
+        }
+
+        return Ok(scores);
+    }
+}
+
+impl Drop for MinimaxBot {
+    fn drop(&mut self) {
+        if let Some(ponder) = self.ponder.take() {
+            ponder.stop.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Accès à une table de transposition depuis la recherche
+trait Table {
+    /// Voir [`TranspositionTable::probe`]
+    fn probe(&self, key : u64) -> Option<Entry>;
+
+    /// Voir [`TranspositionTable::store`]
+    fn store(&mut self, entry : Entry);
+}
+
+impl Table for &mut TranspositionTable {
+    fn probe(&self, key : u64) -> Option<Entry> {
+        return TranspositionTable::probe(self, key).copied();
+    }
+
+    fn store(&mut self, entry : Entry) {
+        TranspositionTable::store(self, entry);
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Table for &SharedTranspositionTable {
+    fn probe(&self, key : u64) -> Option<Entry> {
+        return SharedTranspositionTable::probe(self, key);
+    }
+
+    fn store(&mut self, entry : Entry) {
+        SharedTranspositionTable::store(self, entry);
+    }
+}
+
+/// Contexte d’une recherche
+struct Search<'a, T : Table> {
+    /// Table de transposition consultée et complétée lors de la recherche
+    table : Option<T>,
+    /// Fonction d’évaluation des positions atteintes en fin d’exploration
+    evaluator : &'a dyn Evaluator,
+    /// Drapeau interrompant la recherche lorsqu’il est levé
+    stop : Option<&'a AtomicBool>,
+    /// Suivi de l’avancement comptabilisant les positions explorées
+    progress : Option<&'a SearchProgress>,
+    /// Objectif des joueurs
+    objective : Objective,
+    /// Nombre de positions explorées
+    nodes : u64,
+}
+
+impl<T : Table> Search<'_, T> {
+    /// Indique si la recherche doit être interrompue, son résultat devant alors être ignoré
+    fn stopped(&self) -> bool {
+        return self.stop.is_some_and(|stop| stop.load(Ordering::Relaxed)) || self.progress.is_some_and(SearchProgress::is_cancelled);
+    }
+}
+
+/// Recherche le meilleur coup pour le joueur devant jouer `token` en explorant au plus `depth`
+/// coups. Parmi les coups de meilleur score, le plus central est choisi.
+fn best_move<T : Table>(search : &mut Search<'_, T>, area : &mut Area, token : State, depth : usize) -> Option<usize> {
+    let mut best : Option<(usize, i32)> = None;
+
+    for col in ordered_columns(area) {
+        let alpha = best.map_or(-i32::MAX, |(_, s)| s);
+        let score = root_score(search, area, token, col, depth, alpha);
+
+        match best {
+            Some((_, best_score)) if best_score >= score => {},
+            _ => best = Some((col, score)),
+        }
+    }
+
+    return best.map(|(col, _)| col);
+}
+
+/// Évalue le coup joué à la racine dans la colonne `col`, seuls les scores supérieurs à `alpha`
+/// étant exacts
+fn root_score<T : Table>(search : &mut Search<'_, T>, area : &mut Area, token : State, col : usize, depth : usize, alpha : i32) -> i32 {
+    if let Ok(true) = area.is_winning_move(token, col) {
+        // En misère, l’alignement est perdu comme si l’adversaire l’avait formé au coup suivant
+        return match search.objective {
+            Objective::Connect => WIN_SCORE + depth as i32,
+            Objective::Misere => -(WIN_SCORE + depth as i32 - 1),
+        };
+    }
+
+    area.set_token(token, col).unwrap();
+
+    let score = -negamax(search, area, opponent(token), depth - 1, -i32::MAX, -alpha);
+
+    area.unset_token(col).unwrap();
+
+    return score;
+}
+
+/// Évalue la position du point de vue du joueur devant jouer `token`, en explorant au plus
+/// `depth` coups. Seuls les scores compris entre `alpha` et `beta` sont exacts, les autres
+/// n’étant que des bornes.
+fn negamax<T : Table>(search : &mut Search<'_, T>, area : &mut Area, token : State, depth : usize, mut alpha : i32, mut beta : i32) -> i32 {
+    search.nodes += 1;
+
+    if let Some(progress) = search.progress {
+        progress.add_nodes(1);
+    }
+
+    let mut columns = ordered_columns(area);
+
+    if columns.is_empty() || search.stopped() {
+        return 0;
+    }
+
+    match search.objective {
+        Objective::Connect => {
+            for col in columns.iter() {
+                if let Ok(true) = area.is_winning_move(token, *col) {
+                    return WIN_SCORE + depth as i32;
+                }
+            }
+        },
+        Objective::Misere => {
+            // Les coups formant un alignement ne sont joués qu’à défaut d’autres
+            columns.retain(|col| !matches!(area.is_winning_move(token, *col), Ok(true)));
+
+            if columns.is_empty() {
+                return -(WIN_SCORE + depth as i32 - 1);
+            }
+        },
+    }
+
+    if depth <= 1 {
+        return search.evaluator.evaluate_for(area, token, search.objective);
+    }
+
+    let key = position_key(area, token);
+    let alpha_orig = alpha;
+
+    if let Some(entry) = search.table.as_ref().and_then(|table| table.probe(key)) {
+        if entry.depth >= depth {
+            match entry.bound {
+                Bound::Exact => return entry.score,
+                Bound::Lower => alpha = i32::max(alpha, entry.score),
+                Bound::Upper => beta = i32::min(beta, entry.score),
+            }
+
+            if alpha >= beta {
+                return entry.score;
+            }
+        }
+
+        // Le meilleur coup trouvé précédemment est exploré en premier
+        if let Some(pos) = entry.best_move.and_then(|best| columns.iter().position(|col| *col == best)) {
+            let col = columns.remove(pos);
+
+            columns.insert(0, col);
+        }
+    }
+
+    let (mut best_move, mut best_score) = (columns[0], -i32::MAX);
+
+    for col in columns {
+        area.set_token(token, col).unwrap();
+
+        let score = -negamax(search, area, opponent(token), depth - 1, -beta, -alpha);
+
+        area.unset_token(col).unwrap();
+
+        if score > best_score {
+            best_move = col;
+            best_score = score;
+        }
+
+        alpha = i32::max(alpha, score);
+
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    if search.stopped() {
+        return best_score;
+    }
+
+    if let Some(table) = search.table.as_mut() {
+        let bound = if best_score <= alpha_orig {
+            Bound::Upper
+        } else if best_score >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+
+        table.store(Entry { key, depth, score: best_score, bound, best_move: Some(best_move) });
+    }
+
+    return best_score;
+}
+
+/// Convertit le score d’un coup joué à la racine d’une recherche de profondeur `depth`
+fn to_score(score : i32, depth : usize) -> Score {
+    let remaining = (score.abs() - WIN_SCORE) as usize;
+
+    return if score > WIN_SCORE / 2 {
+        Score::Win((depth - remaining) / 2 + 1)
+    } else if score < -WIN_SCORE / 2 {
+        Score::Loss((depth - 1 - remaining) / 2 + 1)
+    } else {
+        Score::Heuristic(score)
+    };
+}
+
+/// Fourni les colonnes disponibles, des plus centrales aux plus excentrées
+fn ordered_columns(area : &Area) -> Vec<usize> {
+    let cols = area.config().cols;
+    let mut columns = area.get_available_columns();
+
+    columns.sort_by_key(|col| (2 * col).abs_diff(cols - 1));
+
+    return columns;
+}
+
+/// Fourni la clé identifiant la position ainsi que le joueur devant jouer
+fn position_key(area : &Area, token : State) -> u64 {
+    if token == State::RedToken {
+        area.zobrist_key() ^ RED_TO_PLAY_KEY
+    } else {
+        area.zobrist_key()
+    }
+}
+
+/// Fourni le jeton de l’adversaire
+fn opponent(token : State) -> State {
+    if token == State::RedToken {
+        State::YellowToken
+    } else {
+        State::RedToken
+    }
+}
@@ -0,0 +1,904 @@
+//! Parties en réseau via TCP
+//!
+//! Une partie en réseau oppose un hôte, dont le gestionnaire de jeux mène la partie, à un client
+//! distant. Côté hôte, le client est représenté par un [`NetworkPlayer`] implémentant
+//! [`Interface`]; côté client, la fonction [`serve`] relaie les demandes de l’hôte à un joueur
+//! local.
+//!
+//! Une partie peut survivre à la perte de la connexion: l’hôte attribue à la partie un jeton de
+//! session (voir [`Session`]) et conserve son état. Si la connexion est perdue, l’hôte attend
+//! pendant un délai de grâce que le client se reconnecte (voir [`resume`]) avec ce jeton, puis lui
+//! renvoie la position et les pendules avant de reprendre la partie là où elle s’était arrêtée.
+//!
+//! # Protocole
+//!
+//! Les messages sont échangés sous forme de lignes de texte terminées par un retour à la ligne,
+//! le premier mot indiquant le type du message:
+//!  * `HELLO <version> <colonnes> <lignes> <alignement> <authentification> <nom>` : Poignée de
+//!    main, premier message du client puis réponse de l’hôte (voir [`join_handshake`] et
+//!    [`host_handshake`]). Elle indique la version du protocole ([`PROTOCOL_VERSION`]), la
+//!    configuration de la zone de jeu, le jeton d’authentification du client (`-` s’il n’en
+//!    fournit pas) et le nom du joueur.
+//!  * `REJECT <raison>` : Refus de la poignée de main par l’hôte, qui ferme ensuite la connexion
+//!  * `SESSION <jeton>` : Jeton de session de la partie, envoyé par l’hôte après la poignée de
+//!    main puis en réponse à une demande de reprise acceptée
+//!  * `RESUME <jeton>` : Demande de reprise d’une partie, premier message d’un client se
+//!    reconnectant
+//!  * `SYNC <code> [<jaune> <rouge>]` : Position actuelle et jeton devant jouer, encodés par
+//!    [`Area::to_code`], suivis pour une partie chronométrée du temps restant aux jetons jaunes et
+//!    rouges en millisecondes. Envoyé par l’hôte lorsqu’il attend un coup du client, puis à la fin
+//!    de la partie afin que le client affiche la position finale.
+//!  * `MOVE <colonne>` : Coup joué par le client en réponse à un message `SYNC`
+//!  * `CHAT <texte>` : Message de discussion, envoyé par l’hôte avant un message `SYNC` ou
+//!    `RESULT` et par le client avant un message `MOVE`. L’hôte ignore les messages du client
+//!    dépassant [`CHAT_RATE_LIMIT`] messages sur [`CHAT_RATE_WINDOW`] et tronque ceux dépassant
+//!    [`MAX_CHAT_LENGTH`] caractères.
+//!  * `RESULT <jeton>` : Fin de la partie, `Y` ou `R` désignant le jeton gagnant et `D` un match
+//!    nul
+//!
+//! Une ligne dépassant [`MAX_LINE_LENGTH`] octets est refusée. Un pair n’envoyant plus rien
+//! pendant [`READ_TIMEOUT`] est abandonné (voir [`Connection::set_read_timeout`]).
+//!
+//! # Exemple
+//!
+//! ```rust,no_run
+//! use connect_four::{AreaConfig, Engine, GameResult, State};
+//! use connect_four::greedy_bot::GreedyBot;
+//! use connect_four::net::{self, Connection, Message, NetworkPlayer, Session};
+//! use std::net::TcpListener;
+//! use std::time::Duration;
+//!
+//! let listener = TcpListener::bind("0.0.0.0:4444").unwrap();
+//! let (stream, _) = listener.accept().unwrap();
+//! let mut connection = Connection::new(stream).unwrap();
+//!
+//! // Seuls les clients fournissant le mot de passe sont acceptés
+//! let name = net::host_handshake(&mut connection, AreaConfig::default(), "Hôte", Some("secret")).unwrap();
+//!
+//! // Le client dispose d’une minute pour se reconnecter en cas de perte de la connexion
+//! let session = Session::new(listener, Duration::from_secs(60)).unwrap();
+//!
+//! connection.send(&Message::Session(String::from(session.token()))).unwrap();
+//!
+//! let remote = NetworkPlayer::new(&name, connection);
+//!
+//! remote.set_session(session);
+//!
+//! let mut game = Engine::new(Box::new(GreedyBot::new("Hôte")), Box::new(remote.clone()));
+//!
+//! let winner = match game.play().unwrap() {
+//!     GameResult::Winner { token, .. } => token,
+//!     _ => State::NoToken,
+//! };
+//!
+//! remote.finish(game.get_disposition(), winner).unwrap();
+//! ```
+
+use super::connect_four::{Area, AreaConfig, Interface, PlayError, State};
+use super::rng::{OsRng, Rng};
+use super::{i18n, tr};
+use std::fmt::{Debug, Display, Formatter};
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Version actuelle du protocole, échangée lors de la poignée de main
+pub const PROTOCOL_VERSION : u32 = 1;
+
+/// Nombre maximal de messages de discussion acceptés d’un client sur [`CHAT_RATE_WINDOW`]
+pub const CHAT_RATE_LIMIT : usize = 5;
+
+/// Période sur laquelle le nombre de messages de discussion d’un client est limité
+pub const CHAT_RATE_WINDOW : Duration = Duration::from_secs(10);
+
+/// Longueur maximale, en caractères, d’un message de discussion accepté d’un client
+pub const MAX_CHAT_LENGTH : usize = 200;
+
+/// Longueur maximale, en octets et retour à la ligne compris, d’une ligne reçue
+pub const MAX_LINE_LENGTH : usize = 4096;
+
+/// Délai d’attente par défaut d’un message du pair, au-delà duquel la réception échoue
+pub const READ_TIMEOUT : Duration = Duration::from_secs(600);
+
+/// Intervalle entre deux vérifications de la présence d’un client se reconnectant
+const RECONNECTION_POLL : Duration = Duration::from_millis(100);
+
+/// Délai accordé à un client se reconnectant pour envoyer sa demande de reprise
+const RESUME_TIMEOUT : Duration = Duration::from_secs(5);
+
+/// Indique si l’erreur correspond à la perte de la connexion
+fn is_disconnection(e : &io::Error) -> bool {
+    return matches!(e.kind(),
+        io::ErrorKind::UnexpectedEof | io::ErrorKind::ConnectionReset | io::ErrorKind::ConnectionAborted
+        | io::ErrorKind::BrokenPipe | io::ErrorKind::NotConnected);
+}
+
+/// Contenu d’une poignée de main
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Hello {
+    /// Version du protocole utilisée
+    pub version : u32,
+    /// Configuration de la zone de jeu attendue
+    pub config : AreaConfig,
+    /// Jeton d’authentification, fourni par le client lorsque l’hôte en exige un
+    pub auth : Option<String>,
+    /// Nom du participant
+    pub name : String,
+}
+
+impl Hello {
+    /// Crée une poignée de main utilisant la version actuelle du protocole
+    ///
+    /// # Arguments
+    ///
+    ///  * `config` : La configuration de la zone de jeu attendue
+    ///  * `name` : Le nom du participant
+    ///  * `auth` : Le jeton d’authentification éventuel
+    pub fn new(config : AreaConfig, name : &str, auth : Option<&str>) -> Self {
+        return Self {
+            version: PROTOCOL_VERSION,
+            config,
+            auth: auth.map(String::from),
+            name: String::from(name),
+        };
+    }
+}
+
+/// Liste des erreurs pouvant survenir lors de la poignée de main
+pub enum HandshakeError {
+    /// Erreur d’entrée/sortie lors de l’échange
+    Io(io::Error),
+    /// Le pair utilise une autre version du protocole, `None` s’il n’envoie pas de poignée de main
+    /// (version antérieure à la version 1)
+    Version {
+        /// Version utilisée localement
+        local : u32,
+        /// Version utilisée par le pair
+        remote : Option<u32>,
+    },
+    /// Le pair attend une autre configuration de la zone de jeu
+    Config {
+        /// Configuration attendue localement
+        local : AreaConfig,
+        /// Configuration attendue par le pair
+        remote : AreaConfig,
+    },
+    /// Le client n’a pas fourni le jeton d’authentification exigé par l’hôte
+    Unauthorized,
+    /// L’hôte a refusé la poignée de main. Le message décrit la raison du refus.
+    Rejected(String),
+}
+
+impl Debug for HandshakeError {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        return Display::fmt(self, f);
+    }
+}
+
+impl Display for HandshakeError {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        let config = |config : &AreaConfig| tr!("error.handshake_board", config.cols, config.rows, config.win_length);
+
+        match self {
+            HandshakeError::Io(e) => f.write_str(&tr!("error.handshake_io", e)),
+            HandshakeError::Version { local, remote: Some(remote) } => f.write_str(&tr!("error.handshake_version", remote, local)),
+            HandshakeError::Version { local, remote: None } => f.write_str(&tr!("error.handshake_no_version", local)),
+            HandshakeError::Config { local, remote } => f.write_str(&tr!("error.handshake_config", config(remote), config(local))),
+            HandshakeError::Unauthorized => f.write_str(i18n::message("error.handshake_unauthorized")),
+            HandshakeError::Rejected(reason) => f.write_str(&tr!("error.handshake_rejected", reason)),
+        }
+    }
+}
+
+impl std::error::Error for HandshakeError {}
+
+impl From<io::Error> for HandshakeError {
+    fn from(e : io::Error) -> Self {
+        return HandshakeError::Io(e);
+    }
+}
+
+/// Message échangé entre l’hôte et le client
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Message {
+    /// Poignée de main
+    Hello(Hello),
+    /// Refus de la poignée de main, pour la raison donnée
+    Reject(String),
+    /// Jeton de session de la partie
+    Session(String),
+    /// Demande de reprise de la partie de jeton donné
+    Resume(String),
+    /// Position actuelle et jeton devant jouer
+    Sync {
+        /// Position actuelle
+        area : Area,
+        /// Jeton devant jouer le prochain coup
+        to_move : State,
+        /// Temps restant aux jetons jaunes puis aux jetons rouges, `None` pour une partie non
+        /// chronométrée
+        clocks : Option<[Duration; 2]>,
+    },
+    /// Coup joué par le client
+    Move(usize),
+    /// Message de discussion
+    Chat(String),
+    /// Fin de la partie, gagnée par le jeton donné ou nulle (`State::NoToken`)
+    Result(State),
+}
+
+impl Message {
+    /// Met le message en forme sur une ligne, sans retour à la ligne
+    fn encode(&self) -> io::Result<String> {
+        return match self {
+            Message::Hello(hello) if hello.name.contains(['\n', '\r']) => Err(io::Error::new(io::ErrorKind::InvalidInput, "Nom de joueur invalide")),
+            Message::Hello(Hello { auth: Some(auth), .. }) if auth.is_empty() || auth == "-" || auth.contains(char::is_whitespace) => {
+                Err(io::Error::new(io::ErrorKind::InvalidInput, "Jeton d’authentification invalide"))
+            },
+            Message::Hello(hello) => Ok(format!("HELLO {} {} {} {} {} {}", hello.version, hello.config.cols, hello.config.rows,
+                hello.config.win_length, hello.auth.as_deref().unwrap_or("-"), hello.name)),
+            Message::Reject(reason) if reason.contains(['\n', '\r']) => Err(io::Error::new(io::ErrorKind::InvalidInput, "Raison du refus invalide")),
+            Message::Reject(reason) => Ok(format!("REJECT {}", reason)),
+            Message::Session(token) | Message::Resume(token) if token.is_empty() || token.contains(char::is_whitespace) => {
+                Err(io::Error::new(io::ErrorKind::InvalidInput, "Jeton de session invalide"))
+            },
+            Message::Session(token) => Ok(format!("SESSION {}", token)),
+            Message::Resume(token) => Ok(format!("RESUME {}", token)),
+            Message::Sync { area, to_move, clocks } => area.to_code(*to_move)
+                .map(|code| match clocks {
+                    Some([yellow, red]) => format!("SYNC {} {} {}", code, yellow.as_millis(), red.as_millis()),
+                    None => format!("SYNC {}", code),
+                })
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string())),
+            Message::Move(column) => Ok(format!("MOVE {}", column)),
+            Message::Chat(text) if text.contains(['\n', '\r']) => Err(io::Error::new(io::ErrorKind::InvalidInput, "Message de discussion invalide")),
+            Message::Chat(text) => Ok(format!("CHAT {}", text)),
+            Message::Result(State::YellowToken) => Ok(String::from("RESULT Y")),
+            Message::Result(State::RedToken) => Ok(String::from("RESULT R")),
+            Message::Result(State::NoToken) => Ok(String::from("RESULT D")),
+            Message::Result(_) => Err(io::Error::new(io::ErrorKind::InvalidInput, "Jeton inconnu du protocole")),
+        };
+    }
+
+    /// Interprète une ligne reçue
+    fn decode(line : &str) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("Message invalide: {}", line));
+        let (kind, content) = line.split_once(' ').ok_or_else(invalid)?;
+
+        return match kind {
+            "HELLO" => {
+                let fields : Vec<&str> = content.splitn(6, ' ').collect();
+                let version : u32 = fields[0].parse().map_err(|_| invalid())?;
+
+                // Le reste d’une poignée de main d’une autre version peut suivre un autre format
+                if version != PROTOCOL_VERSION {
+                    return Ok(Message::Hello(Hello { version, config: AreaConfig::default(), auth: None, name: String::new() }));
+                }
+
+                let [_, cols, rows, win_length, auth, name] = fields[..] else {
+                    return Err(invalid());
+                };
+                let number = |field : &str| field.parse::<usize>().map_err(|_| invalid());
+                let config = AreaConfig::new(number(cols)?, number(rows)?, number(win_length)?).map_err(|_| invalid())?;
+
+                Ok(Message::Hello(Hello {
+                    version,
+                    config,
+                    auth: if auth == "-" { None } else { Some(String::from(auth)) },
+                    name: String::from(name),
+                }))
+            },
+            "REJECT" => Ok(Message::Reject(String::from(content))),
+            "SESSION" => Ok(Message::Session(String::from(content))),
+            "RESUME" => Ok(Message::Resume(String::from(content))),
+            "SYNC" => {
+                let fields : Vec<&str> = content.split(' ').collect();
+                let (area, to_move) = Area::from_code(fields[0]).map_err(|_| invalid())?;
+                let millis = |field : &str| field.parse::<u64>().map(Duration::from_millis).map_err(|_| invalid());
+                let clocks = match fields[1..] {
+                    [yellow, red] => Some([millis(yellow)?, millis(red)?]),
+                    [] => None,
+                    _ => return Err(invalid()),
+                };
+
+                Ok(Message::Sync { area, to_move, clocks })
+            },
+            "MOVE" => content.parse().map(Message::Move).map_err(|_| invalid()),
+            "CHAT" => Ok(Message::Chat(String::from(content))),
+            "RESULT" => match content {
+                "Y" => Ok(Message::Result(State::YellowToken)),
+                "R" => Ok(Message::Result(State::RedToken)),
+                "D" => Ok(Message::Result(State::NoToken)),
+                _ => Err(invalid()),
+            },
+            _ => Err(invalid()),
+        };
+    }
+}
+
+/// Connexion TCP échangeant des [`Message`]
+pub struct Connection {
+    #[doc(hidden)]
+    reader : BufReader<TcpStream>,
+    #[doc(hidden)]
+    writer : TcpStream,
+}
+
+impl Connection {
+    /// Utilise une connexion TCP établie
+    ///
+    /// Le délai d’attente des messages reçus est fixé à [`READ_TIMEOUT`].
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur survenant lors de la duplication du flux ou de la modification du délai
+    pub fn new(stream : TcpStream) -> io::Result<Self> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+
+        return Ok(Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        });
+    }
+
+    /// Se connecte à un hôte
+    ///
+    /// # Arguments
+    ///
+    ///  * `addr` : L’adresse de l’hôte
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur survenant lors de la connexion
+    pub fn connect<A : ToSocketAddrs>(addr : A) -> io::Result<Self> {
+        return Self::new(TcpStream::connect(addr)?);
+    }
+
+    /// Crée une seconde connexion partageant le même flux TCP
+    ///
+    /// Les messages reçus mais non encore lus par une connexion ne sont pas visibles de l’autre.
+    pub fn try_clone(&self) -> io::Result<Self> {
+        return Self::new(self.writer.try_clone()?);
+    }
+
+    /// Modifie le délai d’attente des messages reçus, par exemple selon la cadence de la partie
+    ///
+    /// # Arguments
+    ///
+    ///  * `timeout` : Le délai, `None` pour attendre indéfiniment
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// `InvalidInput` si le délai est nul, toute erreur survenant lors de sa modification
+    pub fn set_read_timeout(&self, timeout : Option<Duration>) -> io::Result<()> {
+        return self.writer.set_read_timeout(timeout);
+    }
+
+    /// Envoie un message
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, `InvalidInput` si le message ne peut pas être encodé
+    pub fn send(&mut self, message : &Message) -> io::Result<()> {
+        writeln!(self.writer, "{}", message.encode()?)?;
+
+        return self.writer.flush();
+    }
+
+    /// Attend le prochain message
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, `UnexpectedEof` si la connexion a été fermée, `InvalidData`
+    /// si le message reçu est invalide ou dépasse [`MAX_LINE_LENGTH`] octets, `WouldBlock` ou
+    /// `TimedOut` selon le système si aucun message n’est reçu dans le délai imparti (voir
+    /// [`Connection::set_read_timeout`])
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::net::{Connection, MAX_LINE_LENGTH};
+    /// use std::io::{ErrorKind, Write};
+    /// use std::net::{TcpListener, TcpStream};
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    /// let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    /// let mut connection = Connection::new(listener.accept().unwrap().0).unwrap();
+    ///
+    /// client.write_all(&vec![b'A'; 2 * MAX_LINE_LENGTH]).unwrap();
+    ///
+    /// assert_eq!(connection.receive().unwrap_err().kind(), ErrorKind::InvalidData);
+    /// ```
+    pub fn receive(&mut self) -> io::Result<Message> {
+        let mut line = Vec::new();
+
+        if (&mut self.reader).take(MAX_LINE_LENGTH as u64).read_until(b'\n', &mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Connexion fermée"));
+        }
+
+        if line.len() == MAX_LINE_LENGTH && !line.ends_with(b"\n") {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Message trop long"));
+        }
+
+        let line = String::from_utf8(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        return Message::decode(line.trim_end_matches(['\n', '\r']));
+    }
+}
+
+/// Effectue la poignée de main côté hôte
+///
+/// La poignée de main du client est attendue puis vérifiée. Si elle est acceptée, l’hôte répond
+/// par sa propre poignée de main; sinon il envoie la raison du refus (message `REJECT`).
+///
+/// # Arguments
+///
+///  * `connection` : La connexion au client
+///  * `config` : La configuration de la zone de jeu de la partie
+///  * `name` : Le nom du joueur local
+///  * `auth` : Le jeton d’authentification exigé du client, `None` pour accepter tout client
+///
+/// # Retour
+///
+/// Le nom du client
+///
+/// # Liste des erreurs possibles
+///
+///  * `Io` : Erreur d’entrée/sortie lors de l’échange
+///  * `Version` : Le client utilise une autre version du protocole.
+///  * `Config` : Le client attend une autre configuration de la zone de jeu.
+///  * `Unauthorized` : Le client n’a pas fourni le jeton d’authentification exigé.
+pub fn host_handshake(connection : &mut Connection, config : AreaConfig, name : &str, auth : Option<&str>) -> Result<String, HandshakeError> {
+    let hello = match connection.receive() {
+        Ok(Message::Hello(hello)) => Ok(hello),
+        Ok(_) => Err(HandshakeError::Version { local: PROTOCOL_VERSION, remote: None }),
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => Err(HandshakeError::Version { local: PROTOCOL_VERSION, remote: None }),
+        Err(e) => return Err(HandshakeError::Io(e)),
+    };
+
+    let checked = hello.and_then(|hello| {
+        if hello.version != PROTOCOL_VERSION {
+            return Err(HandshakeError::Version { local: PROTOCOL_VERSION, remote: Some(hello.version) });
+        }
+
+        if hello.config != config {
+            return Err(HandshakeError::Config { local: config, remote: hello.config });
+        }
+
+        if auth.is_some() && hello.auth.as_deref() != auth {
+            return Err(HandshakeError::Unauthorized);
+        }
+
+        return Ok(hello.name);
+    });
+
+    return match checked {
+        Ok(remote) => {
+            connection.send(&Message::Hello(Hello::new(config, name, None)))?;
+
+            Ok(remote)
+        },
+        Err(e) => {
+            // Le refus est envoyé au mieux, l’erreur locale primant sur celle de l’envoi
+            let _ = connection.send(&Message::Reject(e.to_string()));
+
+            Err(e)
+        },
+    };
+}
+
+/// Effectue la poignée de main côté client
+///
+/// # Arguments
+///
+///  * `connection` : La connexion à l’hôte
+///  * `config` : La configuration de la zone de jeu attendue
+///  * `name` : Le nom du joueur local
+///  * `auth` : Le jeton d’authentification exigé par l’hôte
+///
+/// # Retour
+///
+/// Le nom de l’hôte
+///
+/// # Liste des erreurs possibles
+///
+///  * `Io` : Erreur d’entrée/sortie lors de l’échange, notamment si l’hôte ferme la connexion
+///  * `Rejected` : L’hôte a refusé la poignée de main.
+///  * `Version`, `Config` : La poignée de main de l’hôte ne correspond pas à celle du client.
+pub fn join_handshake(connection : &mut Connection, config : AreaConfig, name : &str, auth : Option<&str>) -> Result<String, HandshakeError> {
+    connection.send(&Message::Hello(Hello::new(config, name, auth)))?;
+
+    return match connection.receive() {
+        Ok(Message::Hello(hello)) if hello.version != PROTOCOL_VERSION => {
+            Err(HandshakeError::Version { local: PROTOCOL_VERSION, remote: Some(hello.version) })
+        },
+        Ok(Message::Hello(hello)) if hello.config != config => Err(HandshakeError::Config { local: config, remote: hello.config }),
+        Ok(Message::Hello(hello)) => Ok(hello.name),
+        Ok(Message::Reject(reason)) => Err(HandshakeError::Rejected(reason)),
+        Ok(_) => Err(HandshakeError::Version { local: PROTOCOL_VERSION, remote: None }),
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => Err(HandshakeError::Version { local: PROTOCOL_VERSION, remote: None }),
+        Err(e) => Err(HandshakeError::Io(e)),
+    };
+}
+
+/// Session d’une partie hébergée, permettant au client de se reconnecter
+pub struct Session {
+    #[doc(hidden)]
+    token : String,
+    #[doc(hidden)]
+    listener : TcpListener,
+    #[doc(hidden)]
+    grace : Duration,
+}
+
+impl Session {
+    /// Crée une session identifiée par un jeton aléatoire
+    ///
+    /// # Arguments
+    ///
+    ///  * `listener` : Le port d’écoute sur lequel le client se reconnecte
+    ///  * `grace` : Le délai accordé au client pour se reconnecter après la perte de la connexion
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur survenant lors du tirage du jeton
+    pub fn new(listener : TcpListener, grace : Duration) -> io::Result<Self> {
+        let token = format!("{:016x}{:016x}", OsRng.next_u64()?, OsRng.next_u64()?);
+
+        return Ok(Self { token, listener, grace });
+    }
+
+    /// Fourni le jeton de la session, à transmettre au client
+    pub fn token(&self) -> &str {
+        return &self.token;
+    }
+
+    /// Attend la reconnexion du client pendant le délai de grâce
+    ///
+    /// Les connexions ne présentant pas le jeton de la session sont refusées.
+    ///
+    /// # Retour
+    ///
+    /// La nouvelle connexion au client ou `None` si le délai est écoulé
+    fn accept(&self) -> io::Result<Option<Connection>> {
+        let deadline = Instant::now() + self.grace;
+
+        self.listener.set_nonblocking(true)?;
+
+        let result = loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    if let Ok(Some(connection)) = self.authenticate(stream) {
+                        break Ok(Some(connection));
+                    }
+                },
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        break Ok(None);
+                    }
+
+                    std::thread::sleep(RECONNECTION_POLL);
+                },
+                Err(e) => break Err(e),
+            }
+        };
+
+        self.listener.set_nonblocking(false)?;
+
+        return result;
+    }
+
+    /// Vérifie la demande de reprise d’un client se reconnectant
+    fn authenticate(&self, stream : TcpStream) -> io::Result<Option<Connection>> {
+        stream.set_nonblocking(false)?;
+
+        let mut connection = Connection::new(stream)?;
+
+        connection.set_read_timeout(Some(RESUME_TIMEOUT))?;
+
+        if connection.receive()? != Message::Resume(self.token.clone()) {
+            return Ok(None);
+        }
+
+        connection.set_read_timeout(Some(READ_TIMEOUT))?;
+        connection.send(&Message::Session(self.token.clone()))?;
+
+        return Ok(Some(connection));
+    }
+}
+
+/// État du joueur distant, partagé entre ses copies
+struct Remote {
+    name : String,
+    connection : Connection,
+    session : Option<Session>,
+    clocks : Option<[Duration; 2]>,
+    /// Messages de discussion à envoyer au client
+    outbox : VecDeque<String>,
+    /// Messages de discussion reçus du client et non encore consultés
+    inbox : Vec<String>,
+    /// Dates de réception des derniers messages de discussion acceptés
+    chat_times : VecDeque<Instant>,
+}
+
+impl Remote {
+    /// Envoie les messages de discussion en attente puis le message donné et attend
+    /// éventuellement la réponse du client, les messages de discussion reçus entre-temps étant
+    /// conservés
+    fn transmit(&mut self, message : &Message, reply : bool) -> io::Result<Option<Message>> {
+        while let Some(text) = self.outbox.front() {
+            self.connection.send(&Message::Chat(text.clone()))?;
+            self.outbox.pop_front();
+        }
+
+        self.connection.send(message)?;
+
+        if !reply {
+            return Ok(None);
+        }
+
+        loop {
+            match self.connection.receive()? {
+                Message::Chat(text) => self.receive_chat(&text),
+                message => return Ok(Some(message)),
+            }
+        }
+    }
+
+    /// Conserve un message de discussion du client, sauf s’il dépasse la limite de messages
+    fn receive_chat(&mut self, text : &str) {
+        let now = Instant::now();
+
+        while self.chat_times.front().is_some_and(|time| now.duration_since(*time) >= CHAT_RATE_WINDOW) {
+            self.chat_times.pop_front();
+        }
+
+        if self.chat_times.len() >= CHAT_RATE_LIMIT {
+            return;
+        }
+
+        self.chat_times.push_back(now);
+        self.inbox.push(text.chars().take(MAX_CHAT_LENGTH).collect());
+    }
+
+    /// Envoie un message puis attend éventuellement la réponse du client
+    ///
+    /// Si la connexion est perdue et qu’une session est ouverte, le client est attendu puis le
+    /// message lui est renvoyé.
+    fn exchange(&mut self, message : &Message, reply : bool) -> io::Result<Option<Message>> {
+        loop {
+            let result = self.transmit(message, reply);
+
+            match (result, &self.session) {
+                (Err(e), Some(session)) if is_disconnection(&e) => match session.accept()? {
+                    Some(connection) => self.connection = connection,
+                    None => return Err(e),
+                },
+                (result, _) => return result,
+            }
+        }
+    }
+}
+
+/// Joueur distant, représenté côté hôte
+///
+/// À chaque coup, la position est envoyée au client par un message `SYNC` et le coup est attendu
+/// en retour.
+///
+/// Les copies d’un joueur distant partagent sa connexion: l’une d’elles est confiée au
+/// gestionnaire de jeux tandis qu’une autre permet de mettre à jour les pendules et d’annoncer le
+/// résultat de la partie.
+#[derive(Clone)]
+pub struct NetworkPlayer {
+    #[doc(hidden)]
+    remote : Arc<Mutex<Remote>>,
+}
+
+impl NetworkPlayer {
+    /// Initialise le joueur distant
+    ///
+    /// # Arguments
+    ///
+    ///  * `name` : Le nom du joueur distant
+    ///  * `connection` : La connexion au client
+    pub fn new(name : &str, connection : Connection) -> Self {
+        Self {
+            remote: Arc::new(Mutex::new(Remote {
+                name: String::from(name),
+                connection,
+                session: None,
+                clocks: None,
+                outbox: VecDeque::new(),
+                inbox: Vec::new(),
+                chat_times: VecDeque::new(),
+            })),
+        }
+    }
+
+    fn lock(&self) -> io::Result<std::sync::MutexGuard<'_, Remote>> {
+        return self.remote.lock().map_err(|_| io::Error::other("La connexion au joueur distant a paniqué"));
+    }
+
+    /// Ouvre une session permettant au client de se reconnecter
+    ///
+    /// Le jeton de la session doit avoir été transmis au client.
+    pub fn set_session(&self, session : Session) {
+        if let Ok(mut remote) = self.lock() {
+            remote.session = Some(session);
+        }
+    }
+
+    /// Renseigne le temps restant aux jetons jaunes puis aux jetons rouges, transmis au client avec
+    /// chaque position
+    pub fn set_clocks(&self, clocks : Option<[Duration; 2]>) {
+        if let Ok(mut remote) = self.lock() {
+            remote.clocks = clocks;
+        }
+    }
+
+    /// Envoie un message de discussion au client avec la prochaine position
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// `InvalidInput` si le message contient un retour à la ligne
+    pub fn chat(&self, text : &str) -> io::Result<()> {
+        if text.contains(['\n', '\r']) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Message de discussion invalide"));
+        }
+
+        self.lock()?.outbox.push_back(String::from(text));
+
+        return Ok(());
+    }
+
+    /// Fourni les messages de discussion reçus du client depuis le dernier appel
+    pub fn take_chat(&self) -> Vec<String> {
+        return self.lock().map(|mut remote| std::mem::take(&mut remote.inbox)).unwrap_or_default();
+    }
+
+    /// Annonce la fin de la partie au client
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position finale
+    ///  * `winner` : Le jeton gagnant, `State::NoToken` pour un match nul
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, notamment si le client ne s’est pas reconnecté à temps
+    pub fn finish(&self, area : &Area, winner : State) -> io::Result<()> {
+        let mut remote = self.lock()?;
+        let clocks = remote.clocks;
+
+        remote.exchange(&Message::Sync { area: area.clone(), to_move: State::YellowToken, clocks }, false)?;
+        remote.exchange(&Message::Result(winner), false)?;
+
+        return Ok(());
+    }
+}
+
+impl Interface for NetworkPlayer {
+    fn play(&mut self, area : &Area, token : State) -> Result<usize, PlayError> {
+        let mut remote = self.lock()?;
+        let clocks = remote.clocks;
+
+        return match remote.exchange(&Message::Sync { area: area.clone(), to_move: token, clocks }, true) {
+            Ok(Some(Message::Move(column))) => Ok(column),
+            Ok(message) => Err(PlayError::Other(format!("Message inattendu: {:?}", message))),
+            Err(e) if is_disconnection(&e) => Err(PlayError::Disconnected),
+            Err(e) => Err(PlayError::Io(e)),
+        };
+    }
+
+    fn name(&self) -> String {
+        return self.lock().map(|remote| remote.name.clone()).unwrap_or_default();
+    }
+}
+
+/// Se reconnecte à une partie hébergée
+///
+/// # Arguments
+///
+///  * `addr` : L’adresse de l’hôte
+///  * `token` : Le jeton de session reçu à la connexion
+///
+/// # Retour
+///
+/// La nouvelle connexion, sur laquelle l’hôte renverra la position actuelle
+///
+/// # Liste des erreurs possibles
+///
+/// Toute erreur survenant lors de la connexion, `PermissionDenied` si l’hôte refuse la reprise
+pub fn resume<A : ToSocketAddrs>(addr : A, token : &str) -> io::Result<Connection> {
+    let mut connection = Connection::connect(addr)?;
+
+    connection.send(&Message::Resume(String::from(token)))?;
+
+    return match connection.receive() {
+        Ok(Message::Session(accepted)) if accepted == token => Ok(connection),
+        Ok(_) => Err(io::Error::new(io::ErrorKind::PermissionDenied, "Reprise de la partie refusée")),
+        Err(e) if is_disconnection(&e) => Err(io::Error::new(io::ErrorKind::PermissionDenied, "Reprise de la partie refusée")),
+        Err(e) => Err(e),
+    };
+}
+
+/// Évènements d’une partie jouée en tant que client (voir [`serve`])
+pub trait ClientEvents {
+    /// Appelée à chaque position reçue, avec le temps restant aux jetons jaunes puis aux jetons
+    /// rouges pour une partie chronométrée
+    fn on_sync(&mut self, _area : &Area, _clocks : Option<[Duration; 2]>) {}
+
+    /// Appelée à chaque message de discussion reçu de l’hôte
+    fn on_chat(&mut self, _text : &str) {}
+
+    /// Fourni les messages de discussion à envoyer à l’hôte avec le prochain coup
+    fn outgoing_chat(&mut self) -> Vec<String> {
+        return Vec::new();
+    }
+}
+
+/// Partie sans discussion ni affichage des pendules
+impl ClientEvents for () {}
+
+/// Joue une partie en tant que client, en relayant les demandes de l’hôte au joueur local
+///
+/// Chaque message `SYNC` d’une partie non terminée est transmis au joueur local, dont le coup est
+/// renvoyé à l’hôte. Si le joueur local ne fournit pas de coup (`PlayError::Other`), un coup lui
+/// est redemandé.
+///
+/// Si la connexion est perdue, la partie peut être reprise en appelant de nouveau cette fonction
+/// avec la connexion fournie par [`resume`].
+///
+/// # Arguments
+///
+///  * `connection` : La connexion à l’hôte, poignée de main effectuée
+///  * `player` : Le joueur local
+///  * `events` : Les évènements de la partie (positions reçues et discussion)
+///
+/// # Retour
+///
+/// La position finale et le jeton gagnant, `State::NoToken` pour un match nul
+///
+/// # Liste des erreurs possibles
+///
+/// Toute erreur d’entrée/sortie, `InvalidData` si l’hôte envoie un message inattendu et
+/// `UnexpectedEof` s’il se déconnecte avant la fin de la partie
+pub fn serve(connection : &mut Connection, player : &mut dyn Interface, events : &mut dyn ClientEvents) -> io::Result<(Area, State)> {
+    let mut last = Area::new();
+
+    loop {
+        match connection.receive()? {
+            Message::Sync { area, to_move, clocks } => {
+                let finished = !area.winning_cells().is_empty() || area.get_available_columns().is_empty();
+
+                events.on_sync(&area, clocks);
+
+                last = area;
+
+                if finished {
+                    continue;
+                }
+
+                let column = loop {
+                    match player.play(&last, to_move) {
+                        Ok(column) => break column,
+                        Err(PlayError::Other(_)) => continue,
+                        Err(PlayError::Io(e)) => return Err(e),
+                        Err(PlayError::Disconnected) => return Err(io::Error::new(io::ErrorKind::Interrupted, "Le joueur local a quitté la partie")),
+                    }
+                };
+
+                for text in events.outgoing_chat() {
+                    connection.send(&Message::Chat(text))?;
+                }
+
+                connection.send(&Message::Move(column))?;
+            },
+            Message::Chat(text) => events.on_chat(&text),
+            Message::Result(winner) => return Ok((last, winner)),
+            message => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Message inattendu: {:?}", message))),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Suivi de l’avancement des recherches des intelligences artificielles
+//!
+//! Une recherche profonde peut durer plusieurs secondes. Un [`SearchProgress`] partagé avec
+//! l’intelligence artificielle (voir [`MinimaxBot::set_progress`] et
+//! [`PerfectBot::set_progress`]) est mis à jour pendant la recherche et peut être consulté depuis
+//! un autre fil d’exécution, par exemple afin d’afficher un indicateur de réflexion.
+//!
+//! Le suivi permet également d’interrompre les recherches depuis un autre fil d’exécution (voir
+//! [`SearchProgress::cancel`]), par exemple lorsque l’utilisateur quitte la partie.
+//!
+//! [`MinimaxBot::set_progress`]: super::minimax_bot::MinimaxBot::set_progress
+//! [`PerfectBot::set_progress`]: super::solver::PerfectBot::set_progress
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, Interface, State};
+//! use connect_four::minimax_bot::MinimaxBot;
+//! use connect_four::progress::SearchProgress;
+//! use std::sync::Arc;
+//!
+//! let progress = Arc::new(SearchProgress::new());
+//! let mut bot = MinimaxBot::new("Robot", 6);
+//!
+//! bot.set_progress(Some(Arc::clone(&progress)));
+//! bot.play(&Area::new(), State::YellowToken).unwrap();
+//!
+//! assert_eq!(progress.depth(), Some(6));
+//! assert!(progress.nodes() > 0);
+//! ```
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+/// Avancement d’une recherche, mis à jour par l’intelligence artificielle et consultable depuis
+/// d’autres fils d’exécution
+#[derive(Debug, Default)]
+pub struct SearchProgress {
+    #[doc(hidden)]
+    nodes : AtomicU64,
+    /// Profondeur de la recherche, nulle si elle est inconnue
+    #[doc(hidden)]
+    depth : AtomicUsize,
+    #[doc(hidden)]
+    cancelled : AtomicBool,
+}
+
+impl SearchProgress {
+    /// Crée un suivi d’une recherche n’ayant encore exploré aucune position
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Remet à zéro le suivi au début d’une nouvelle recherche
+    ///
+    /// # Arguments
+    ///
+    ///  * `depth` : La profondeur de la recherche, `None` si elle est inconnue
+    pub fn start(&self, depth : Option<usize>) {
+        self.nodes.store(0, Ordering::Relaxed);
+        self.depth.store(depth.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// Comptabilise des positions explorées
+    ///
+    /// # Arguments
+    ///
+    ///  * `nodes` : Le nombre de positions explorées depuis le dernier appel
+    pub fn add_nodes(&self, nodes : u64) {
+        self.nodes.fetch_add(nodes, Ordering::Relaxed);
+    }
+
+    /// Fourni le nombre de positions explorées depuis le début de la recherche
+    pub fn nodes(&self) -> u64 {
+        return self.nodes.load(Ordering::Relaxed);
+    }
+
+    /// Fourni la profondeur de la recherche ou `None` si elle est inconnue
+    pub fn depth(&self) -> Option<usize> {
+        return Some(self.depth.load(Ordering::Relaxed)).filter(|depth| *depth != 0);
+    }
+
+    /// Interrompt la recherche en cours ainsi que toutes les suivantes
+    ///
+    /// L’intelligence artificielle abandonne alors sa recherche au plus vite et retourne une
+    /// erreur au lieu d’un coup.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use connect_four::{Area, Interface, State};
+    /// use connect_four::solver::PerfectBot;
+    /// use connect_four::progress::SearchProgress;
+    /// use std::sync::Arc;
+    ///
+    /// let progress = Arc::new(SearchProgress::new());
+    /// let mut bot = PerfectBot::new("Robot");
+    ///
+    /// bot.set_progress(Some(Arc::clone(&progress)));
+    /// progress.cancel();
+    ///
+    /// assert!(bot.play(&Area::new(), State::YellowToken).is_err());
+    /// ```
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Indique si les recherches ont été interrompues
+    pub fn is_cancelled(&self) -> bool {
+        return self.cancelled.load(Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,341 @@
+//! Liaisons Python du moteur de jeu via PyO3
+//!
+//! Ce module définit le module Python `connect_four`, exposant la zone de jeu (`Board`), le
+//! gestionnaire de jeux (`Engine`) et les intelligences artificielles (`Bot`), afin notamment de
+//! générer des parties entre intelligences artificielles ou de tester des agents depuis Python.
+//!
+//! Le module est compilé avec [maturin](https://www.maturin.rs) à partir du fichier
+//! `pyproject.toml` du paquet:
+//!
+//! ```sh
+//! maturin develop --release
+//! ```
+//!
+//! ```python
+//! import connect_four
+//!
+//! board = connect_four.Board()
+//! board.play(3)
+//! print(board.legal_moves(), board.cells())
+//!
+//! engine = connect_four.Engine(connect_four.Bot.minimax(6), connect_four.Bot.random(seed=42))
+//! winner, moves = engine.play()
+//! ```
+//!
+//! L’état de chaque case est représenté par un entier: 0 pour une case vide, 1 pour un jeton
+//! jaune et 2 pour un jeton rouge.
+//!
+//! Ce module n’est disponible qu’avec la fonctionnalité `python`.
+
+use super::connect_four::{Action, Area, AreaConfig, Engine, GameResult, Interface, PlayError, Player, PlayerId, State, Error};
+use super::greedy_bot::GreedyBot;
+use super::minimax_bot::MinimaxBot;
+use super::random_bot::RandomBot;
+use super::rng::Pcg32;
+use super::solver::PerfectBot;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use std::sync::{Arc, Mutex};
+
+/// Intelligence artificielle partagée entre Python et les parties qu’elle joue
+type SharedBot = Arc<Mutex<Box<dyn Interface + Send>>>;
+
+/// Convertit une erreur du moteur en exception Python
+fn value_error(e : Error) -> PyErr {
+    return PyValueError::new_err(e.to_string());
+}
+
+/// Représentation d’un état de case transmise à Python
+fn state_code(state : State) -> u32 {
+    match state {
+        State::NoToken => 0,
+        State::YellowToken => 1,
+        State::RedToken => 2,
+        State::GreenToken => 3,
+        State::BlueToken => 4,
+    }
+}
+
+/// Zone de jeu manipulable depuis Python
+///
+/// Les jetons jaunes jouent le premier coup, le jeton devant jouer étant déduit du nombre de
+/// jetons de chaque couleur.
+#[pyclass(name = "Board")]
+#[derive(Clone)]
+pub struct PyBoard {
+    #[doc(hidden)]
+    area : Area,
+}
+
+#[pymethods]
+impl PyBoard {
+    /// Crée une zone de jeu vide
+    #[new]
+    #[pyo3(signature = (cols = 7, rows = 6, win_length = 4))]
+    fn new(cols : usize, rows : usize, win_length : usize) -> PyResult<Self> {
+        let config = AreaConfig::new(cols, rows, win_length).map_err(value_error)?;
+
+        return Ok(Self { area: Area::with_config(config).map_err(value_error)? });
+    }
+
+    /// Crée une zone de jeu depuis un code de position (voir [`Area::from_code`])
+    #[staticmethod]
+    fn from_code(code : &str) -> PyResult<Self> {
+        let (area, _) = Area::from_code(code).map_err(value_error)?;
+
+        return Ok(Self { area });
+    }
+
+    /// Fourni le code de la position (voir [`Area::to_code`])
+    fn to_code(&self) -> PyResult<String> {
+        let token = self.area.next_token().map_err(value_error)?.unwrap_or(State::YellowToken);
+
+        return self.area.to_code(token).map_err(value_error);
+    }
+
+    /// Nombre de colonnes de la zone de jeu
+    #[getter]
+    fn cols(&self) -> usize {
+        return self.area.config().cols;
+    }
+
+    /// Nombre de lignes de la zone de jeu
+    #[getter]
+    fn rows(&self) -> usize {
+        return self.area.config().rows;
+    }
+
+    /// Joue un coup avec le jeton devant jouer et indique s’il gagne la partie
+    fn play(&mut self, column : usize) -> PyResult<bool> {
+        let token = self.area.next_token().map_err(value_error)?.ok_or_else(|| value_error(Error::GameAlreadyFinished))?;
+
+        return self.area.set_token(token, column).map_err(value_error);
+    }
+
+    /// Retire le jeton au sommet d’une colonne et fourni son état
+    fn undo(&mut self, column : usize) -> PyResult<u32> {
+        return self.area.unset_token(column).map(state_code).map_err(value_error);
+    }
+
+    /// Fourni les colonnes pouvant être jouées, aucune si la partie est terminée
+    fn legal_moves(&self) -> Vec<usize> {
+        if self.is_finished() {
+            return Vec::new();
+        }
+
+        return self.area.get_available_columns();
+    }
+
+    /// Fourni l’état de chaque case, ligne par ligne en partant du bas
+    fn cells(&self) -> Vec<Vec<u32>> {
+        let mut cells = vec![vec![0; self.cols()]; self.rows()];
+
+        for ((col, row), state) in self.area.iter() {
+            cells[row][col] = state_code(state);
+        }
+
+        return cells;
+    }
+
+    /// Fourni le jeton devant jouer (1 pour les jaunes, 2 pour les rouges) ou 0 si la partie est
+    /// terminée
+    fn next_token(&self) -> PyResult<u32> {
+        return Ok(state_code(self.area.next_token().map_err(value_error)?.unwrap_or(State::NoToken)));
+    }
+
+    /// Fourni le jeton ayant formé un alignement (1 ou 2) ou 0 si la partie n’est pas gagnée
+    fn winner(&self) -> u32 {
+        return self.area.winning_cells().first().map(|cell| state_code(self.area[*cell])).unwrap_or(0);
+    }
+
+    /// Fourni les cases `(colonne, ligne)` de l’alignement gagnant
+    fn winning_cells(&self) -> Vec<(usize, usize)> {
+        return self.area.winning_cells().to_vec();
+    }
+
+    /// Indique si la partie est terminée (alignement formé ou zone de jeu remplie)
+    fn is_finished(&self) -> bool {
+        return !self.area.winning_cells().is_empty() || self.area.get_available_columns().is_empty();
+    }
+
+    /// Crée une copie de la zone de jeu
+    fn copy(&self) -> Self {
+        return self.clone();
+    }
+
+    fn __str__(&self) -> String {
+        return self.area.to_string();
+    }
+}
+
+/// Intelligence artificielle utilisable depuis Python
+#[pyclass(name = "Bot")]
+pub struct PyBot {
+    #[doc(hidden)]
+    bot : SharedBot,
+}
+
+impl PyBot {
+    fn wrap<T : Interface + Send + 'static>(bot : T) -> Self {
+        return Self { bot: Arc::new(Mutex::new(Box::new(bot))) };
+    }
+}
+
+#[pymethods]
+impl PyBot {
+    /// Crée une intelligence artificielle jouant au hasard, reproductible si une graine est donnée
+    #[staticmethod]
+    #[pyo3(signature = (seed = None))]
+    fn random(seed : Option<u64>) -> Self {
+        return match seed {
+            Some(seed) => Self::wrap(RandomBot::with_rng("Robot aléatoire", Box::new(Pcg32::new(seed)))),
+            None => Self::wrap(RandomBot::new("Robot aléatoire")),
+        };
+    }
+
+    /// Crée une intelligence artificielle gloutonne
+    #[staticmethod]
+    fn greedy() -> Self {
+        return Self::wrap(GreedyBot::new("Robot glouton"));
+    }
+
+    /// Crée une intelligence artificielle minimax explorant le nombre de coups donné
+    #[staticmethod]
+    fn minimax(depth : usize) -> Self {
+        return Self::wrap(MinimaxBot::new("Robot minimax", depth));
+    }
+
+    /// Crée une intelligence artificielle jouant parfaitement
+    #[staticmethod]
+    fn perfect() -> Self {
+        return Self::wrap(PerfectBot::new("Robot parfait"));
+    }
+
+    /// Nom de l’intelligence artificielle
+    #[getter]
+    fn name(&self) -> String {
+        return self.bot.lock().map(|bot| bot.name()).unwrap_or_default();
+    }
+
+    /// Choisi la colonne à jouer dans la position donnée, pour le jeton devant jouer
+    fn play(&self, py : Python<'_>, board : &PyBoard) -> PyResult<usize> {
+        let token = board.area.next_token().map_err(value_error)?.ok_or_else(|| value_error(Error::GameAlreadyFinished))?;
+        let bot = Arc::clone(&self.bot);
+        let area = board.area.clone();
+
+        return py.allow_threads(move || {
+            let mut bot = bot.lock().map_err(|_| PyRuntimeError::new_err("L’intelligence artificielle a paniqué"))?;
+
+            return bot.play(&area, token).map_err(|e| PyRuntimeError::new_err(e.to_string()));
+        });
+    }
+}
+
+/// Joueur du gestionnaire de jeux relayant les appels à une intelligence artificielle partagée
+struct EnginePlayer(SharedBot);
+
+impl EnginePlayer {
+    fn lock(&self) -> std::result::Result<std::sync::MutexGuard<'_, Box<dyn Interface + Send>>, PlayError> {
+        return self.0.lock().map_err(|_| PlayError::Other(String::from("L’interface du joueur a paniqué")));
+    }
+}
+
+impl Interface for EnginePlayer {
+    fn play(&mut self, area : &Area, token : State) -> std::result::Result<usize, PlayError> {
+        return self.lock()?.play(area, token);
+    }
+
+    fn name(&self) -> String {
+        return self.lock().map(|player| player.name()).unwrap_or_default();
+    }
+
+    fn on_opponent_thinking(&mut self, area : &Area, token : State) {
+        if let Ok(mut player) = self.lock() {
+            player.on_opponent_thinking(area, token);
+        }
+    }
+
+    fn on_opponent_move(&mut self, area : &Area, action : Action) {
+        if let Ok(mut player) = self.lock() {
+            player.on_opponent_move(area, action);
+        }
+    }
+
+    fn on_game_start(&mut self, area : &Area, player : &Player) {
+        if let Ok(mut interface) = self.lock() {
+            interface.on_game_start(area, player);
+        }
+    }
+
+    fn on_game_aborted(&mut self, area : &Area, reason : &str) {
+        if let Ok(mut interface) = self.lock() {
+            interface.on_game_aborted(area, reason);
+        }
+    }
+
+    fn nodes_searched(&self) -> Option<u64> {
+        return self.lock().ok().and_then(|interface| interface.nodes_searched());
+    }
+}
+
+/// Gestionnaire de parties entre deux intelligences artificielles
+#[pyclass(name = "Engine", unsendable)]
+pub struct PyEngine {
+    #[doc(hidden)]
+    engine : Engine,
+}
+
+#[pymethods]
+impl PyEngine {
+    /// Crée un gestionnaire de jeux opposant deux intelligences artificielles
+    #[new]
+    #[pyo3(signature = (player_one, player_two, cols = 7, rows = 6, win_length = 4))]
+    fn new(player_one : &PyBot, player_two : &PyBot, cols : usize, rows : usize, win_length : usize) -> PyResult<Self> {
+        let config = AreaConfig::new(cols, rows, win_length).map_err(value_error)?;
+        let engine = Engine::with_config(
+            Box::new(EnginePlayer(Arc::clone(&player_one.bot))),
+            Box::new(EnginePlayer(Arc::clone(&player_two.bot))),
+            config,
+        ).map_err(value_error)?;
+
+        return Ok(Self { engine });
+    }
+
+    /// Choisi le joueur (1 ou 2) jouant le premier coup des parties suivantes
+    fn set_first_player(&mut self, player_id : usize) -> PyResult<()> {
+        return PlayerId::new(player_id).and_then(|player_id| self.engine.set_first_player(player_id)).map_err(value_error);
+    }
+
+    /// Joue une partie complète puis prépare la suivante
+    ///
+    /// Fourni le joueur gagnant (1 ou 2, 0 pour un match nul) et les colonnes jouées
+    fn play(&mut self) -> PyResult<(usize, Vec<usize>)> {
+        let result = self.engine.play().map_err(value_error)?;
+        let moves = self.engine.record().moves;
+
+        self.engine.reset();
+
+        return match result {
+            GameResult::Winner { player_id, .. } => Ok((player_id.get(), moves)),
+            GameResult::Timeout { player_id } => Ok((player_id.next(2).get(), moves)),
+            GameResult::Draw => Ok((0, moves)),
+            GameResult::Aborted => Err(PyRuntimeError::new_err(Error::Aborted.to_string())),
+        };
+    }
+
+    /// Fourni une copie de la zone de jeu de la partie en cours
+    fn board(&self) -> PyBoard {
+        return PyBoard { area: self.engine.get_disposition().clone() };
+    }
+}
+
+/// Module Python `connect_four`
+#[pymodule]
+#[pyo3(name = "connect_four")]
+fn python_module(module : &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyBoard>()?;
+    module.add_class::<PyBot>()?;
+    module.add_class::<PyEngine>()?;
+
+    return Ok(());
+}
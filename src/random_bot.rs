@@ -1,11 +1,20 @@
 //! Implémentation d’une intelligence artificielle plaçant systématiquement aléatoirement un jeton
+//!
+//! Les coups sont tirés via un générateur implémentant [`Rng`], par défaut [`OsRng`]. Un
+//! générateur initialisé par une graine ([`Pcg32`]) rend les parties reproductibles.
+//!
+//! [`Pcg32`]: super::rng::Pcg32
 
-use super::connect_four::{Interface, Area, State};
+use super::connect_four::{Interface, Area, State, PlayError};
+use super::rng::{Rng, OsRng};
+use super::score_four::{Area3, Interface3};
 
 /// Intelligence artificielle aléatoire
 pub struct RandomBot {
     #[doc(hidden)]
-    name : String
+    name : String,
+    #[doc(hidden)]
+    rng : Box<dyn Rng>,
 }
 
 impl RandomBot {
@@ -19,27 +28,51 @@ impl RandomBot {
     ///
     /// Une instance de l’intelligence artificielle
     pub fn new(name : &str) -> Self {
+        return Self::with_rng(name, Box::new(OsRng));
+    }
+
+    /// Initialise l’intelligence artificielle avec le générateur de nombres aléatoires donné
+    ///
+    /// # Arguments
+    ///
+    ///  * `name` : Le nom donné à l’intelligence artificielle.
+    ///  * `rng` : Le générateur tirant les coups joués
+    ///
+    /// # Retour
+    ///
+    /// Une instance de l’intelligence artificielle
+    pub fn with_rng(name : &str, rng : Box<dyn Rng>) -> Self {
         Self {
-            name: String::from(name)
+            name: String::from(name),
+            rng,
         }
     }
 }
 
 impl Interface for RandomBot {
-    fn play(&mut self, area: &Area, _ : State) -> usize {
+    fn play(&mut self, area: &Area, _ : State) -> Result<usize, PlayError> {
         let available = area.get_available_columns();
-        const SZ : usize = std::mem::size_of::<usize>();
 
-        let mut idx_a : [u8; SZ] = [0; SZ];
-
-        getrandom::getrandom(&mut idx_a).unwrap();
-
-        let idx : usize = unsafe { std::mem::transmute::<[u8; SZ], usize>(idx_a) } % available.len();
+        if available.is_empty() {
+            return Err(PlayError::Other(String::from("Aucune colonne disponible")));
+        }
 
-        return available[idx];
+        return Ok(available[self.rng.below(available.len())?]);
     }
 
     fn name(&self) -> String {
         return self.name.clone();
     }
-}
\ No newline at end of file
+}
+
+impl Interface3 for RandomBot {
+    fn play_3d(&mut self, area : &Area3, _ : State) -> Result<usize, PlayError> {
+        let available = area.available_pins();
+
+        if available.is_empty() {
+            return Err(PlayError::Other(String::from("Aucune tige disponible")));
+        }
+
+        return Ok(available[self.rng.below(available.len())?]);
+    }
+}
@@ -0,0 +1,251 @@
+//! Classement Elo persistant des joueurs
+//!
+//! Le classement associe à chaque joueur, identifié par son nom, une cote Elo mise à jour après
+//! chaque partie classée. Il peut être enregistré dans un fichier afin de suivre l’évolution du
+//! niveau des intelligences artificielles au fil du temps.
+//!
+//! Le fichier est un fichier texte contenant une ligne par joueur: sa cote, son nombre de parties
+//! et son nom, séparés par des tabulations.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::ratings::{Ratings, DEFAULT_RATING};
+//!
+//! let mut ratings = Ratings::new();
+//!
+//! // Le premier joueur gagne la partie
+//! ratings.add_game("Robot moyen", "Robot facile", 1.0);
+//!
+//! assert!(ratings.get("Robot moyen").elo > DEFAULT_RATING);
+//! assert!(ratings.get("Robot facile").elo < DEFAULT_RATING);
+//!
+//! let mut data = Vec::new();
+//!
+//! ratings.write(&mut data).unwrap();
+//!
+//! assert_eq!(Ratings::read(&mut data.as_slice()).unwrap(), ratings);
+//!
+//! println!("{}", ratings);
+//! ```
+
+use super::arena::MatchStats;
+use super::tournament::Tournament;
+use super::i18n;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+/// Cote attribuée à un joueur n’ayant encore joué aucune partie classée
+pub const DEFAULT_RATING : f64 = 1500.0;
+
+/// Variation maximale de la cote d’un joueur sur une partie
+const K_FACTOR : f64 = 32.0;
+
+/// Cote Elo d’un joueur
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rating {
+    /// Cote du joueur
+    pub elo : f64,
+    /// Nombre de parties classées jouées
+    pub games : usize,
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self {
+            elo: DEFAULT_RATING,
+            games: 0,
+        }
+    }
+}
+
+/// Classement Elo d’un ensemble de joueurs
+#[derive(PartialEq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ratings {
+    #[doc(hidden)]
+    players : BTreeMap<String, Rating>,
+}
+
+impl Ratings {
+    /// Crée un classement vide
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Charge un classement depuis un fichier
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Voir [`Ratings::read`]
+    pub fn load<P : AsRef<Path>>(path : P) -> io::Result<Self> {
+        return Self::read(&mut io::BufReader::new(std::fs::File::open(path)?));
+    }
+
+    /// Enregistre le classement dans un fichier
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie
+    pub fn save<P : AsRef<Path>>(&self, path : P) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(std::fs::File::create(path)?);
+
+        self.write(&mut writer)?;
+
+        return writer.flush();
+    }
+
+    /// Lit un classement
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, `InvalidData` si l’une des lignes n’est pas une cote valide.
+    pub fn read<R : BufRead>(reader : &mut R) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Le fichier n’est pas un classement");
+        let mut ratings = Self::new();
+
+        for line in reader.lines() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, '\t');
+            let elo = fields.next().and_then(|elo| elo.parse::<f64>().ok()).filter(|elo| elo.is_finite()).ok_or_else(invalid)?;
+            let games = fields.next().and_then(|games| games.parse().ok()).ok_or_else(invalid)?;
+            let name = fields.next().ok_or_else(invalid)?;
+
+            ratings.players.insert(String::from(name), Rating { elo, games });
+        }
+
+        return Ok(ratings);
+    }
+
+    /// Écrit le classement
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie
+    pub fn write<W : Write>(&self, writer : &mut W) -> io::Result<()> {
+        for (name, rating) in self.players.iter() {
+            writeln!(writer, "{}\t{}\t{}", rating.elo, rating.games, name)?;
+        }
+
+        return Ok(());
+    }
+
+    /// Fourni le nombre de joueurs classés
+    pub fn len(&self) -> usize {
+        return self.players.len();
+    }
+
+    /// Indique si le classement ne contient aucun joueur
+    pub fn is_empty(&self) -> bool {
+        return self.players.is_empty();
+    }
+
+    /// Fourni la cote d’un joueur
+    ///
+    /// # Retour
+    ///
+    /// La cote du joueur ou la cote initiale s’il n’a joué aucune partie classée
+    pub fn get(&self, name : &str) -> Rating {
+        return self.players.get(name).copied().unwrap_or_default();
+    }
+
+    /// Met à jour le classement après une partie
+    ///
+    /// # Arguments
+    ///
+    ///  * `first` : Le nom du premier joueur
+    ///  * `second` : Le nom du second joueur
+    ///  * `score` : Le score du premier joueur: 1 pour une victoire, 0.5 pour un match nul et 0
+    ///    pour une défaite
+    pub fn add_game(&mut self, first : &str, second : &str, score : f64) {
+        self.add_games(first, second, score, 1);
+    }
+
+    /// Met à jour le classement après une série de parties entre deux joueurs
+    ///
+    /// Les cotes sont mises à jour une seule fois à partir du score total de la série, l’ordre des
+    /// parties n’ayant ainsi aucune influence.
+    ///
+    /// # Arguments
+    ///
+    ///  * `first` : Le nom du premier joueur de la série
+    ///  * `second` : Le nom du second joueur de la série
+    ///  * `stats` : Les résultats de la série du point de vue du premier joueur
+    pub fn add_match(&mut self, first : &str, second : &str, stats : &MatchStats) {
+        let score = stats.wins as f64 + stats.draws as f64 / 2.0;
+
+        self.add_games(first, second, score, stats.games());
+    }
+
+    /// Met à jour le classement après chaque partie d’un tournoi
+    ///
+    /// Les parties sont prises en compte dans l’ordre où elles ont été jouées.
+    ///
+    /// # Arguments
+    ///
+    ///  * `tournament` : Le tournoi dont les parties sont classées
+    pub fn add_tournament(&mut self, tournament : &Tournament) {
+        let crosstable = tournament.crosstable();
+
+        for game in tournament.games() {
+            let score = game.half_points(game.first) as f64 / 2.0;
+
+            self.add_game(crosstable.name(game.first), crosstable.name(game.second), score);
+        }
+    }
+
+    /// Fourni les joueurs classés par cote décroissante
+    pub fn leaderboard(&self) -> Vec<(&str, Rating)> {
+        let mut leaderboard : Vec<(&str, Rating)> = self.players.iter().map(|(name, rating)| (name.as_str(), *rating)).collect();
+
+        leaderboard.sort_by(|a, b| b.1.elo.total_cmp(&a.1.elo));
+
+        return leaderboard;
+    }
+
+    /// Met à jour les cotes de deux joueurs à partir du score total du premier sur un nombre de
+    /// parties donné
+    fn add_games(&mut self, first : &str, second : &str, score : f64, games : usize) {
+        if games == 0 {
+            return;
+        }
+
+        let a = self.get(first);
+        let b = self.get(second);
+        let expected = 1.0 / (1.0 + 10f64.powf((b.elo - a.elo) / 400.0));
+        let delta = K_FACTOR * (score - expected * games as f64);
+
+        let first = self.players.entry(String::from(first)).or_default();
+
+        first.elo += delta;
+        first.games += games;
+
+        let second = self.players.entry(String::from(second)).or_default();
+
+        second.elo -= delta;
+        second.games += games;
+    }
+}
+
+/// Le classement est affiché sous forme de tableau, par cote décroissante
+impl Display for Ratings {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        let width = self.players.keys().map(|name| name.chars().count()).fold(i18n::message("table.player").chars().count(), usize::max);
+
+        writeln!(f, "{:>3}  {:<width$} {:>6} {:>7}", "#", i18n::message("table.player"), "Elo", i18n::message("table.games"), width = width)?;
+
+        for (rank, (name, rating)) in self.leaderboard().iter().enumerate() {
+            writeln!(f, "{:>3}  {:<width$} {:>6.0} {:>7}", rank + 1, name, rating.elo, rating.games, width = width)?;
+        }
+
+        return Ok(());
+    }
+}
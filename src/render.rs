@@ -0,0 +1,466 @@
+//! Mise en forme de la zone de jeu pour l’affichage
+//!
+//! Le trait [`Renderer`] convertit une zone de jeu en texte selon des options d’affichage
+//! ([`RenderOptions`]), dont le thème fournissant les symboles des jetons (voir
+//! [`theme`](super::theme)). Trois moteurs de rendu sont fournis:
+//!  * [`TextRenderer`] : Grille de texte sans couleur, utilisée par l’affichage de [`Area`]
+//!  * [`AnsiRenderer`] : Grille dont les jetons sont colorés par des séquences ANSI
+//!  * [`CompactRenderer`] : Un symbole par case, sans séparateurs
+//!
+//! Avec l’option [`RenderOptions::highlight`], le dernier jeton ajouté (voir [`Area::last_move`])
+//! et les cases de l’alignement gagnant (voir [`Area::winning_cells`]) sont mis en évidence: entre
+//! parenthèses et entre crochets dans une grille de texte, soulignés et en couleurs inversées avec
+//! les séquences ANSI.
+//!
+//! Les cases bloquées (voir [`AreaConfig::block`](super::AreaConfig::block)) sont affichées par
+//! le symbole [`Theme::blocked`], sans couleur ni mise en évidence.
+//!
+//! Les colonnes sont désignées par leur numéro ou par une lettre (`a` pour la colonne 0, voir
+//! [`column_letter`] et [`parse_column`]), l’en-tête des grilles affichant les deux.
+//!
+//! La fonction [`render_layers`] met en forme les niveaux d’une zone de jeu de la variante en trois
+//! dimensions (voir [`score_four`](super::score_four)).
+//!
+//! La fonction [`drop_frames`] fourni les images successives de la chute du dernier jeton ajouté,
+//! permettant aux interfaces d’animer les coups.
+//!
+//! Les interfaces peuvent implémenter leur propre moteur de rendu afin de personnaliser
+//! l’affichage.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, State};
+//! use connect_four::render::{CompactRenderer, Renderer, RenderOptions, TextRenderer};
+//! use connect_four::theme::Theme;
+//!
+//! let mut area = Area::new();
+//!
+//! area.set_token(State::YellowToken, 3).unwrap();
+//! area.set_token(State::RedToken, 3).unwrap();
+//!
+//! let options = RenderOptions { theme: Theme::ascii(), ..Default::default() };
+//!
+//! assert!(TextRenderer.render(&area, &options).ends_with("| . | . | . | J | . | . | . |\n-----------------------------"));
+//! assert!(CompactRenderer.render(&area, &options).ends_with("...R...\n...J..."));
+//! assert_eq!(TextRenderer.render(&area, &RenderOptions::default()), area.to_string());
+//!
+//! let options = RenderOptions { highlight: true, ..options };
+//!
+//! assert!(TextRenderer.render(&area, &options).contains("| . | . | . |(R)| . | . | . |"));
+//! ```
+
+use super::connect_four::{Area, State};
+use super::score_four::{Area3, LEVELS, SIDE};
+use super::theme::Theme;
+use super::tr;
+use std::fmt::Write;
+
+/// Options d’affichage de la zone de jeu
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct RenderOptions {
+    /// Thème fournissant les symboles et les couleurs des jetons
+    pub theme : Theme,
+    /// Affiche les numéros de colonne au dessus de la zone de jeu
+    pub header : bool,
+    /// Met en évidence le dernier jeton ajouté et les cases de l’alignement gagnant
+    pub highlight : bool,
+    /// Jeton en cours de chute, affiché dans la case `(colonne, ligne)` donnée par dessus la zone
+    /// de jeu (voir [`drop_frames`])
+    pub falling : Option<(usize, usize, State)>,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        return Self { theme: Theme::default(), header: true, highlight: false, falling: None };
+    }
+}
+
+impl RenderOptions {
+    /// Fourni l’état affiché d’une case, tenant compte du jeton en cours de chute
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu affichée
+    ///  * `cell` : La case `(colonne, ligne)`, la ligne partant du bas
+    pub fn state(&self, area : &Area, cell : (usize, usize)) -> State {
+        return match self.falling {
+            Some((col, row, token)) if (col, row) == cell => token,
+            _ => area[cell],
+        };
+    }
+}
+
+/// Mise en évidence d’une case
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Mark {
+    /// Case non mise en évidence
+    None,
+    /// Case du dernier jeton ajouté
+    LastMove,
+    /// Case de l’alignement gagnant
+    Winning,
+}
+
+impl Mark {
+    /// Fourni la mise en évidence d’une case selon les options d’affichage
+    ///
+    /// L’alignement gagnant prime sur le dernier jeton ajouté, ce dernier en faisant partie. Un
+    /// jeton en cours de chute est mis en évidence comme le dernier jeton ajouté.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu affichée
+    ///  * `cell` : La case `(colonne, ligne)`, la ligne partant du bas
+    ///  * `options` : Les options d’affichage
+    pub fn of(area : &Area, cell : (usize, usize), options : &RenderOptions) -> Self {
+        if !options.highlight {
+            return Mark::None;
+        }
+
+        if area.winning_cells().contains(&cell) {
+            return Mark::Winning;
+        }
+
+        let last_move = options.falling.or(area.last_move());
+
+        if last_move.is_some_and(|(col, row, _)| (col, row) == cell) {
+            return Mark::LastMove;
+        }
+
+        return Mark::None;
+    }
+
+    /// Paramètres SGR de la mise en évidence par séquences ANSI
+    fn sgr(self) -> Option<u8> {
+        return match self {
+            Mark::None => None,
+            Mark::LastMove => Some(4),
+            Mark::Winning => Some(7),
+        };
+    }
+}
+
+/// Met en forme un texte avec les paramètres SGR donnés
+fn ansi(text : &str, codes : &[Option<u8>]) -> String {
+    let codes : Vec<String> = codes.iter().flatten().map(u8::to_string).collect();
+
+    if codes.is_empty() {
+        return String::from(text);
+    }
+
+    return format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text);
+}
+
+/// Fourni la lettre désignant une colonne, `a` pour la colonne 0
+///
+/// # Retour
+///
+/// La lettre minuscule de la colonne ou `None` au delà de la colonne 25 (`z`)
+pub fn column_letter(column : usize) -> Option<char> {
+    return (column < 26).then(|| (b'a' + column as u8) as char);
+}
+
+/// Fourni l’étiquette d’une colonne dans l’en-tête des grilles: sa lettre suivie de son numéro
+/// (`a0`), ou son numéro seul au delà de la lettre `z`
+pub fn column_label(column : usize) -> String {
+    return match column_letter(column) {
+        Some(letter) => format!("{}{}", letter, column),
+        None => column.to_string(),
+    };
+}
+
+/// Lit une colonne désignée par son numéro (`3`) ou par sa lettre (`d` ou `D`)
+///
+/// # Retour
+///
+/// L’identifiant de la colonne, sans vérifier qu’elle existe, ou `None` si le texte ne désigne
+/// aucune colonne
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::render::parse_column;
+///
+/// assert_eq!(parse_column("3"), Some(3));
+/// assert_eq!(parse_column("d"), Some(3));
+/// assert_eq!(parse_column("D"), Some(3));
+/// assert_eq!(parse_column("d3"), None);
+/// ```
+pub fn parse_column(s : &str) -> Option<usize> {
+    if let Ok(column) = s.parse() {
+        return Some(column);
+    }
+
+    let mut chars = s.chars();
+
+    return match (chars.next().map(|c| c.to_ascii_lowercase()), chars.next()) {
+        (Some(letter @ 'a'..='z'), None) => Some(letter as usize - 'a' as usize),
+        _ => None,
+    };
+}
+
+/// Moteur de rendu de la zone de jeu
+pub trait Renderer {
+    /// Met en forme la zone de jeu, ligne du haut en premier
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu à afficher
+    ///  * `options` : Les options d’affichage
+    fn render(&self, area : &Area, options : &RenderOptions) -> String;
+}
+
+/// Met en forme la zone de jeu sous la forme d’une grille dont chaque case est entourée de
+/// séparateurs, le contenu de chaque case entre les séparateurs étant fourni par `cell`
+fn render_grid(area : &Area, options : &RenderOptions, cell : impl Fn(State, Mark) -> String) -> String {
+    let config = area.config();
+    let width = options.theme.cell_width + 2;
+    let sep = "-".repeat((width + 1) * config.cols + 1);
+    let mut board = String::new();
+
+    if options.header {
+        for col in 0..config.cols {
+            let _ = write!(board, "|{:^width$}", column_label(col), width = width);
+        }
+
+        board.push_str("|\n");
+    }
+
+    let _ = writeln!(board, "{}", sep);
+
+    for row in (0..config.rows).rev() {
+        for col in 0..config.cols {
+            if config.is_blocked(col, row) {
+                let _ = write!(board, "| {} ", options.theme.blocked);
+            } else {
+                let _ = write!(board, "|{}", cell(options.state(area, (col, row)), Mark::of(area, (col, row), options)));
+            }
+        }
+
+        board.push_str("|\n");
+    }
+
+    board.push_str(&sep);
+
+    return board;
+}
+
+/// Grille de texte sans couleur, les couleurs du thème étant ignorées
+///
+/// Le dernier jeton ajouté est mis en évidence entre parenthèses et l’alignement gagnant entre
+/// crochets.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TextRenderer;
+
+impl Renderer for TextRenderer {
+    fn render(&self, area : &Area, options : &RenderOptions) -> String {
+        return render_grid(area, options, |state, mark| {
+            let symbol = options.theme.symbol(state);
+
+            return match mark {
+                Mark::None => format!(" {} ", symbol),
+                Mark::LastMove => format!("({})", symbol),
+                Mark::Winning => format!("[{}]", symbol),
+            };
+        });
+    }
+}
+
+/// Grille dont les jetons sont colorés par des séquences ANSI
+///
+/// Les couleurs du thème sont utilisées, ou à défaut le jaune (`33`), le rouge (`31`), le vert
+/// (`32`) et le bleu (`34`). Le dernier jeton ajouté est souligné et les cases de l’alignement
+/// gagnant sont en couleurs inversées.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AnsiRenderer;
+
+impl Renderer for AnsiRenderer {
+    fn render(&self, area : &Area, options : &RenderOptions) -> String {
+        let theme = Theme { colors: Some(options.theme.colors.unwrap_or([33, 31, 32, 34])), ..options.theme.clone() };
+
+        return render_grid(area, options, |state, mark| {
+            let (symbol, color) = (theme.symbol(state), theme.color(state));
+
+            return match mark {
+                Mark::Winning => ansi(&format!(" {} ", symbol), &[color, mark.sgr()]),
+                _ => format!(" {} ", ansi(symbol, &[color, mark.sgr()])),
+            };
+        });
+    }
+}
+
+/// Affichage compact, un symbole par case sans séparateurs, utilisant les couleurs du thème
+///
+/// Faute de place, la mise en évidence n’est affichée qu’avec un thème coloré, de la même manière
+/// que [`AnsiRenderer`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CompactRenderer;
+
+impl Renderer for CompactRenderer {
+    fn render(&self, area : &Area, options : &RenderOptions) -> String {
+        let config = area.config();
+        let width = options.theme.cell_width;
+        let mut lines = Vec::with_capacity(config.rows + 1);
+
+        if options.header {
+            lines.push((0..config.cols).map(|col| format!("{:^width$}", col % 10, width = width)).collect::<String>());
+        }
+
+        for row in (0..config.rows).rev() {
+            lines.push((0..config.cols).map(|col| {
+                if config.is_blocked(col, row) {
+                    return options.theme.blocked.clone();
+                }
+
+                let state = options.state(area, (col, row));
+
+                return match options.theme.colors {
+                    Some(_) => ansi(options.theme.symbol(state), &[options.theme.color(state), Mark::of(area, (col, row), options).sgr()]),
+                    None => String::from(options.theme.symbol(state)),
+                };
+            }).collect::<String>());
+        }
+
+        return lines.join("\n");
+    }
+}
+
+/// Fourni les images successives de la chute du dernier jeton ajouté
+///
+/// Le jeton est affiché dans chacune des cases libres de sa colonne, du haut de la zone de jeu
+/// jusqu’à la case le recevant, en passant à côté des cases bloquées.
+///
+/// # Arguments
+///
+///  * `renderer` : Le moteur de rendu des images
+///  * `area` : La zone de jeu après l’ajout du jeton
+///  * `options` : Les options d’affichage
+///
+/// # Retour
+///
+/// Les images de la chute, la dernière étant l’affichage de la zone de jeu. Seule cette dernière
+/// est fournie si la zone de jeu n’a pas de dernier coup (voir [`Area::last_move`]).
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Area, State};
+/// use connect_four::render::{drop_frames, CompactRenderer, RenderOptions};
+/// use connect_four::theme::Theme;
+///
+/// let mut area = Area::new();
+///
+/// area.set_token(State::YellowToken, 0).unwrap();
+///
+/// let options = RenderOptions { theme: Theme::ascii(), header: false, ..Default::default() };
+/// let frames = drop_frames(&CompactRenderer, &area, &options);
+///
+/// assert_eq!(frames.len(), 6);
+/// assert!(frames[0].starts_with("J......\n......."));
+/// assert!(frames[5].ends_with(".......\nJ......"));
+/// ```
+pub fn drop_frames(renderer : &dyn Renderer, area : &Area, options : &RenderOptions) -> Vec<String> {
+    let mut before = area.clone();
+
+    let (col, row, token) = match area.last_move() {
+        Some((col, row, token)) if before.unset_token(col).is_ok() => (col, row, token),
+        _ => return vec![renderer.render(area, options)],
+    };
+
+    let rows = (row + 1..area.config().rows).rev().filter(|falling| !area.config().is_blocked(col, *falling));
+    let mut frames : Vec<String> = rows.map(|falling| {
+        return renderer.render(&before, &RenderOptions { falling: Some((col, falling, token)), ..options.clone() });
+    }).collect();
+
+    frames.push(renderer.render(area, options));
+
+    return frames;
+}
+
+/// Met en forme une zone de jeu de la variante en trois dimensions, ses niveaux étant affichés
+/// côte à côte, du plus bas au plus haut
+///
+/// Chaque niveau est une grille des tiges du plateau, la rangée 1 en bas. Les jetons sont colorés
+/// par des séquences ANSI si le thème est coloré et mis en évidence de la même manière que par
+/// [`TextRenderer`] ou [`AnsiRenderer`]. Le jeton en cours de chute des options est ignoré.
+///
+/// # Arguments
+///
+///  * `area` : La zone de jeu à afficher
+///  * `options` : Les options d’affichage
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::State;
+/// use connect_four::render::{render_layers, RenderOptions};
+/// use connect_four::score_four::Area3;
+/// use connect_four::theme::Theme;
+///
+/// let mut area = Area3::new();
+///
+/// area.set_token(State::YellowToken, 0).unwrap();
+/// area.set_token(State::RedToken, 0).unwrap();
+///
+/// let options = RenderOptions { theme: Theme::ascii(), ..Default::default() };
+/// let text = render_layers(&area, &options);
+///
+/// assert!(text.lines().last().unwrap().starts_with("  -----------------"));
+/// assert!(text.contains("1 | J | . | . | . |   1 | R | . | . | . |   1 | . |"));
+/// ```
+pub fn render_layers(area : &Area3, options : &RenderOptions) -> String {
+    let width = options.theme.cell_width + 2;
+    let sep = format!("  {}", "-".repeat((width + 1) * SIDE + 1));
+    let cell = |pin : usize, level : usize| {
+        let state = area[(pin, level)];
+        let mark = if !options.highlight {
+            Mark::None
+        } else if area.winning_cells().contains(&(pin, level)) {
+            Mark::Winning
+        } else if area.last_move().is_some_and(|(p, l, _)| (p, l) == (pin, level)) {
+            Mark::LastMove
+        } else {
+            Mark::None
+        };
+        let symbol = options.theme.symbol(state);
+
+        return match (options.theme.colors, mark) {
+            (Some(_), Mark::Winning) => ansi(&format!(" {} ", symbol), &[options.theme.color(state), mark.sgr()]),
+            (Some(_), _) => format!(" {} ", ansi(symbol, &[options.theme.color(state), mark.sgr()])),
+            (None, Mark::None) => format!(" {} ", symbol),
+            (None, Mark::LastMove) => format!("({})", symbol),
+            (None, Mark::Winning) => format!("[{}]", symbol),
+        };
+    };
+
+    let layers : Vec<Vec<String>> = (0..LEVELS).map(|level| {
+        let mut lines = vec![format!("{:^w$}", tr!("render.level", level + 1), w = sep.len())];
+
+        if options.header {
+            lines.push(format!("  {}|", (0..SIDE).map(|x| format!("|{:^width$}", column_letter(x).unwrap(), width = width)).collect::<String>()));
+        }
+
+        lines.push(sep.clone());
+
+        for y in (0..SIDE).rev() {
+            lines.push(format!("{} {}|", y + 1, (0..SIDE).map(|x| format!("|{}", cell(y * SIDE + x, level))).collect::<String>()));
+        }
+
+        lines.push(sep.clone());
+
+        return lines;
+    }).collect();
+
+    let mut text = String::new();
+
+    for i in 0..layers[0].len() {
+        let line : Vec<&str> = layers.iter().map(|layer| layer[i].as_str()).collect();
+
+        let _ = writeln!(text, "{}", line.join("   ").trim_end());
+    }
+
+    text.pop();
+
+    return text;
+}
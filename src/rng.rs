@@ -0,0 +1,137 @@
+//! Générateurs de nombres aléatoires utilisés par les intelligences artificielles
+//!
+//! Les intelligences artificielles tirant leurs coups au hasard reçoivent un générateur
+//! implémentant le trait [`Rng`]. Par défaut, le générateur [`OsRng`] s’appuie sur la source
+//! d’aléa du système d’exploitation. Le générateur [`Pcg32`], initialisé par une graine, permet
+//! quant à lui de reproduire exactement une partie ou une simulation.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::Engine;
+//! use connect_four::random_bot::RandomBot;
+//! use connect_four::rng::Pcg32;
+//!
+//! let play = |seed : u64| {
+//!     let player1 = Box::new(RandomBot::with_rng("Robot 1", Box::new(Pcg32::new(seed))));
+//!     let player2 = Box::new(RandomBot::with_rng("Robot 2", Box::new(Pcg32::new(seed + 1))));
+//!
+//!     let mut game = Engine::new(player1, player2);
+//!
+//!     game.play().unwrap();
+//!
+//!     return game.get_disposition().clone();
+//! };
+//!
+//! // Une même graine produit la même partie
+//! assert_eq!(play(42), play(42));
+//! ```
+
+use std::io;
+
+/// Multiplicateur du générateur congruentiel linéaire sous-jacent à [`Pcg32`]
+const PCG_MULTIPLIER : u64 = 6_364_136_223_846_793_005;
+
+/// Séquence utilisée par [`Pcg32::new`]
+const PCG_DEFAULT_STREAM : u64 = 0xDA3E_39CB_94B9_5BDB;
+
+/// Générateur de nombres aléatoires
+pub trait Rng : Send {
+    /// Fourni un entier aléatoire uniformément réparti sur 64 bits
+    ///
+    /// # Retour
+    ///
+    /// L’entier tiré ou une erreur si la source d’aléa est indisponible
+    fn next_u64(&mut self) -> io::Result<u64>;
+
+    /// Fourni un entier aléatoire compris entre 0 inclus et `bound` exclus
+    ///
+    /// # Arguments
+    ///
+    ///  * `bound` : La borne supérieure, exclue. Doit être non nulle.
+    ///
+    /// # Retour
+    ///
+    /// L’entier tiré ou une erreur si la source d’aléa est indisponible
+    fn below(&mut self, bound : usize) -> io::Result<usize> {
+        return Ok((self.next_u64()? % bound as u64) as usize);
+    }
+}
+
+/// Générateur s’appuyant sur la source d’aléa du système d’exploitation
+#[derive(Copy, Clone, Debug, Default)]
+pub struct OsRng;
+
+impl Rng for OsRng {
+    fn next_u64(&mut self) -> io::Result<u64> {
+        let mut bytes = [0; 8];
+
+        getrandom::getrandom(&mut bytes).map_err(|e| io::Error::other(e.to_string()))?;
+
+        return Ok(u64::from_ne_bytes(bytes));
+    }
+}
+
+/// Générateur pseudo-aléatoire PCG (variante XSH RR 64/32) initialisé par une graine
+///
+/// Deux générateurs initialisés avec la même graine et la même séquence fournissent les mêmes
+/// nombres.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Pcg32 {
+    #[doc(hidden)]
+    state : u64,
+    #[doc(hidden)]
+    increment : u64,
+}
+
+impl Pcg32 {
+    /// Initialise le générateur
+    ///
+    /// # Arguments
+    ///
+    ///  * `seed` : La graine du générateur
+    pub fn new(seed : u64) -> Self {
+        return Self::with_stream(seed, PCG_DEFAULT_STREAM);
+    }
+
+    /// Initialise le générateur sur une séquence donnée
+    ///
+    /// Des générateurs de même graine mais de séquences différentes fournissent des nombres
+    /// indépendants.
+    ///
+    /// # Arguments
+    ///
+    ///  * `seed` : La graine du générateur
+    ///  * `stream` : L’identifiant de la séquence
+    pub fn with_stream(seed : u64, stream : u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+
+        return rng;
+    }
+
+    /// Fourni un entier aléatoire sur 32 bits
+    pub fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+
+        self.state = state.wrapping_mul(PCG_MULTIPLIER).wrapping_add(self.increment);
+
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+
+        return xorshifted.rotate_right((state >> 59) as u32);
+    }
+}
+
+impl Rng for Pcg32 {
+    fn next_u64(&mut self) -> io::Result<u64> {
+        let high = self.next_u32() as u64;
+
+        return Ok(high << 32 | self.next_u32() as u64);
+    }
+}
@@ -0,0 +1,572 @@
+//! Variante en trois dimensions du puissance 4 (Score Four)
+//!
+//! Le plateau est formé de 4 × 4 tiges sur lesquelles les joueurs empilent leurs jetons, jusqu’à
+//! quatre par tige. Le premier joueur alignant quatre jetons gagne la partie, les alignements
+//! pouvant être horizontaux, verticaux ou diagonaux dans chacun des plans du cube ainsi que selon
+//! ses quatre grandes diagonales, soit 76 alignements possibles.
+//!
+//! Les tiges sont identifiées par l’indice `y * 4 + x`, `x` et `y` étant leur position sur le
+//! plateau, et désignées dans les interfaces par la lettre de leur colonne suivie du numéro de
+//! leur rangée (`a1` pour la tige 0, voir [`pin_label`] et [`parse_pin`]). Une case est identifiée
+//! par sa tige et son niveau `(tige, niveau)`, le niveau partant du bas: un coup joué sur le
+//! plateau est ainsi décrit par un [`Move`] dont la colonne est la tige et la ligne le niveau.
+//!
+//! La zone de jeu ([`Area3`]) est menée par son propre gestionnaire de jeux ([`Engine3`]), les
+//! joueurs implémentant, en plus de l’[`Interface`] du jeu classique, le trait [`Interface3`] via
+//! lequel le gestionnaire demande leur coup.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{GameResult, State};
+//! use connect_four::random_bot::RandomBot;
+//! use connect_four::score_four::{Area3, Engine3};
+//!
+//! let mut area = Area3::new();
+//!
+//! // Une grande diagonale du cube
+//! for (pin, below) in [(0, 0), (5, 1), (10, 2)].iter() {
+//!     for _ in 0..*below {
+//!         area.set_token(State::RedToken, *pin).unwrap();
+//!     }
+//!
+//!     area.set_token(State::YellowToken, *pin).unwrap();
+//! }
+//!
+//! for _ in 0..3 {
+//!     area.set_token(State::RedToken, 15).unwrap();
+//! }
+//!
+//! assert!(area.is_winning_move(State::YellowToken, 15).unwrap());
+//!
+//! let mut game = Engine3::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")));
+//!
+//! // Un coup peut compléter plusieurs alignements à la fois
+//! match game.play().unwrap() {
+//!     GameResult::Winner { winning_line, .. } => assert!(winning_line.len() >= 4),
+//!     result => assert_eq!(result, GameResult::Draw),
+//! }
+//! ```
+
+use super::connect_four::{Interface, Error, GameResult, GameStatus, Move, MoveKind, PlayError, PlayerId, Result, State};
+use super::render::{self, RenderOptions};
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// Nombre de tiges sur chaque côté du plateau
+pub const SIDE : usize = 4;
+
+/// Nombre de tiges du plateau
+pub const PINS : usize = SIDE * SIDE;
+
+/// Nombre de jetons pouvant être empilés sur une tige, ainsi que de jetons à aligner
+pub const LEVELS : usize = 4;
+
+/// Nombre d’alignements possibles sur le plateau
+const LINE_COUNT : usize = 76;
+
+/// Masques des cases de chaque alignement possible, la case `(tige, niveau)` occupant le bit
+/// `tige * LEVELS + niveau`
+const LINES : [u64; LINE_COUNT] = lines();
+
+/// Énumère les alignements possibles selon les 13 directions du cube
+const fn lines() -> [u64; LINE_COUNT] {
+    let side = SIDE as isize;
+    let mut lines = [0u64; LINE_COUNT];
+    let mut count = 0;
+    let mut direction = 0;
+
+    while direction < 27 {
+        let (dx, dy, dz) = (direction % 3 - 1, direction / 3 % 3 - 1, direction / 9 - 1);
+
+        // Seule l’une des deux directions opposées est retenue
+        if dz > 0 || (dz == 0 && (dy > 0 || (dy == 0 && dx > 0))) {
+            let mut start = 0;
+
+            while start < side * side * side {
+                let (x, y, z) = (start % side, start / side % side, start / (side * side));
+                let (ex, ey, ez) = (x + (side - 1) * dx, y + (side - 1) * dy, z + (side - 1) * dz);
+
+                if ex >= 0 && ex < side && ey >= 0 && ey < side && ez >= 0 && ez < side {
+                    let mut line = 0u64;
+                    let mut i = 0;
+
+                    while i < side {
+                        line |= 1 << (((y + i * dy) * side + x + i * dx) * side + z + i * dz);
+                        i += 1;
+                    }
+
+                    lines[count] = line;
+                    count += 1;
+                }
+
+                start += 1;
+            }
+        }
+
+        direction += 1;
+    }
+
+    return lines;
+}
+
+/// Fourni l’étiquette d’une tige: la lettre de sa colonne suivie du numéro de sa rangée (`a1`)
+pub fn pin_label(pin : usize) -> String {
+    return format!("{}{}", render::column_letter(pin % SIDE).unwrap_or('?'), pin / SIDE + 1);
+}
+
+/// Lit une tige désignée par son étiquette (`b3` ou `B3`, voir [`pin_label`])
+///
+/// # Retour
+///
+/// L’identifiant de la tige ou `None` si le texte ne désigne aucune tige du plateau
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::score_four::{parse_pin, pin_label};
+///
+/// assert_eq!(parse_pin("a1"), Some(0));
+/// assert_eq!(parse_pin("C2"), Some(6));
+/// assert_eq!(parse_pin("e1"), None);
+/// assert_eq!(pin_label(6), "c2");
+/// ```
+pub fn parse_pin(s : &str) -> Option<usize> {
+    let mut chars = s.chars();
+    let x = chars.next()?.to_ascii_lowercase() as usize;
+    let y = chars.as_str().parse::<usize>().ok()?;
+
+    if !(('a' as usize)..('a' as usize + SIDE)).contains(&x) || !(1..=SIDE).contains(&y) {
+        return None;
+    }
+
+    return Some((y - 1) * SIDE + x - 'a' as usize);
+}
+
+/// Zone de jeu de la variante en trois dimensions
+///
+/// Les jetons de chaque couleur sont stockés sur un entier de 64 bits, une case par bit.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct Area3 {
+    #[doc(hidden)]
+    red : u64,
+    #[doc(hidden)]
+    yellow : u64,
+    #[doc(hidden)]
+    winning_cells : Vec<(usize, usize)>,
+    #[doc(hidden)]
+    last_move : Option<(usize, usize, State)>,
+}
+
+impl Area3 {
+    /// Crée une zone de jeu vide
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Ajoute un jeton sur une tige
+    ///
+    /// # Arguments
+    ///
+    ///  * `token` : Le jeton. Doit avoir pour valeur `State::RedToken` ou `State::YellowToken`
+    ///  * `pin` : La tige où empiler le jeton, doit être comprise entre 0 et 15
+    ///
+    /// # Retour
+    ///
+    /// Le niveau où le jeton a été placé
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `NotAToken` - L’état demandé ne correspond pas à celui d’un jeton.
+    ///  * `InvalidColumn` - L’identifiant de la tige est invalide.
+    ///  * `FilledColumn` - La tige est déjà remplie.
+    pub fn set_token(&mut self, token : State, pin : usize) -> Result<usize> {
+        if token == State::NoToken {
+            return Err(Error::NotAToken);
+        }
+
+        let level = self.pin_height(pin)?;
+
+        if level >= LEVELS {
+            return Err(Error::FilledColumn);
+        }
+
+        let bit = 1u64 << (pin * LEVELS + level);
+
+        match token {
+            State::RedToken => self.red |= bit,
+            _ => self.yellow |= bit,
+        }
+
+        self.winning_cells = self.lines_through(token, bit);
+        self.last_move = Some((pin, level, token));
+
+        return Ok(level);
+    }
+
+    /// Vérifie si ajouter un jeton sur une tige permet la victoire, sans modifier la zone de jeu
+    ///
+    /// # Arguments
+    ///
+    ///  * `token` : Le jeton. Doit avoir pour valeur `State::RedToken` ou `State::YellowToken`
+    ///  * `pin` : La tige où ajouter le jeton, doit être comprise entre 0 et 15
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `NotAToken` - L’état demandé ne correspond pas à celui d’un jeton.
+    ///  * `InvalidColumn` - L’identifiant de la tige est invalide.
+    ///  * `FilledColumn` - La tige est déjà remplie.
+    pub fn is_winning_move(&self, token : State, pin : usize) -> Result<bool> {
+        if token == State::NoToken {
+            return Err(Error::NotAToken);
+        }
+
+        let level = self.pin_height(pin)?;
+
+        if level >= LEVELS {
+            return Err(Error::FilledColumn);
+        }
+
+        let bit = 1u64 << (pin * LEVELS + level);
+        let board = self.bits(token) | bit;
+
+        return Ok(LINES.iter().any(|line| line & bit != 0 && board & line == *line));
+    }
+
+    /// Fourni le nombre de jetons empilés sur une tige
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidColumn` - L’identifiant de la tige est invalide.
+    pub fn pin_height(&self, pin : usize) -> Result<usize> {
+        if pin >= PINS {
+            return Err(Error::InvalidColumn);
+        }
+
+        let occupied = (self.red | self.yellow) >> (pin * LEVELS);
+
+        return Ok((occupied & ((1 << LEVELS) - 1)).trailing_ones() as usize);
+    }
+
+    /// Fourni la liste des tiges pouvant encore recevoir un jeton
+    pub fn available_pins(&self) -> Vec<usize> {
+        return (0..PINS).filter(|pin| self.pin_height(*pin).unwrap() < LEVELS).collect();
+    }
+
+    /// Fourni le nombre de jetons présents sur le plateau
+    pub fn token_count(&self) -> usize {
+        return (self.red | self.yellow).count_ones() as usize;
+    }
+
+    /// Vérifie si toutes les tiges sont remplies
+    pub fn is_full(&self) -> bool {
+        return self.red | self.yellow == u64::MAX;
+    }
+
+    /// Fourni les cases `(tige, niveau)` des alignements formés par le dernier jeton ajouté
+    ///
+    /// La liste est vide si ce jeton n’a formé aucun alignement.
+    pub fn winning_cells(&self) -> &[(usize, usize)] {
+        return &self.winning_cells;
+    }
+
+    /// Fourni la tige, le niveau et le jeton du dernier coup joué ou `None` si aucun jeton n’a été
+    /// ajouté
+    pub fn last_move(&self) -> Option<(usize, usize, State)> {
+        return self.last_move;
+    }
+
+    fn bits(&self, token : State) -> u64 {
+        return match token {
+            State::RedToken => self.red,
+            State::YellowToken => self.yellow,
+            _ => 0,
+        };
+    }
+
+    /// Fourni les cases des alignements de `token` passant par le bit `bit`
+    fn lines_through(&self, token : State, bit : u64) -> Vec<(usize, usize)> {
+        let board = self.bits(token);
+        let mut cells = Vec::new();
+
+        for line in LINES.iter().filter(|line| *line & bit != 0 && board & *line == **line) {
+            let mut bits = *line;
+
+            while bits != 0 {
+                let offset = bits.trailing_zeros() as usize;
+                let cell = (offset / LEVELS, offset % LEVELS);
+
+                if !cells.contains(&cell) {
+                    cells.push(cell);
+                }
+
+                bits &= bits - 1;
+            }
+        }
+
+        return cells;
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for Area3 {
+    type Output = State;
+
+    fn index(&self, index : (usize, usize)) -> &Self::Output {
+        assert!(index.0 < PINS && index.1 < LEVELS, "Case ({}, {}) hors du plateau", index.0, index.1);
+
+        let bit = 1u64 << (index.0 * LEVELS + index.1);
+
+        return if self.red & bit != 0 {
+            &State::RedToken
+        } else if self.yellow & bit != 0 {
+            &State::YellowToken
+        } else {
+            &State::NoToken
+        };
+    }
+}
+
+impl Display for Area3 {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(&render::render_layers(self, &RenderOptions::default()));
+    }
+}
+
+/// Interface vers un joueur de la variante en trois dimensions
+///
+/// Le nom du joueur et les informations sur sa recherche sont fournis par l’[`Interface`] du jeu
+/// classique, dont ce trait ne fait qu’ajouter la demande de coup sur le cube.
+///
+/// # Exemple
+///
+/// ```rust
+/// use connect_four::{Interface, Area, PlayError, State};
+/// use connect_four::score_four::{Area3, Engine3, Interface3};
+/// use connect_four::random_bot::RandomBot;
+///
+/// /// Joueur empilant ses jetons sur la première tige disponible
+/// struct FirstPin;
+///
+/// impl Interface for FirstPin {
+///     fn play(&mut self, area : &Area, _ : State) -> Result<usize, PlayError> {
+///         return Ok(area.get_available_columns()[0]);
+///     }
+///
+///     fn name(&self) -> String {
+///         return String::from("Première tige");
+///     }
+/// }
+///
+/// impl Interface3 for FirstPin {
+///     fn play_3d(&mut self, area : &Area3, _ : State) -> Result<usize, PlayError> {
+///         return Ok(area.available_pins()[0]);
+///     }
+/// }
+///
+/// let mut game = Engine3::new(Box::new(FirstPin), Box::new(RandomBot::new("Robot")));
+///
+/// game.step().unwrap();
+///
+/// assert_eq!(game.history()[0].column, 0);
+/// ```
+pub trait Interface3 : Interface {
+    /// Demande au joueur de jouer
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La zone de jeu actuelle
+    ///  * `token` : Le jeton joué par le joueur
+    ///
+    /// # Retour
+    ///
+    /// La tige où empiler le jeton, comprise entre 0 et 15, ou une erreur si le joueur ne peut pas
+    /// jouer
+    fn play_3d(&mut self, area : &Area3, token : State) -> std::result::Result<usize, PlayError>;
+}
+
+/// Gère les parties de la variante en trois dimensions
+///
+/// Le joueur 1 joue les jetons jaunes et le joueur 2 les jetons rouges. Contrairement à
+/// [`Engine`](super::connect_four::Engine), un coup invalide proposé par un joueur est signalé par
+/// [`Engine3::step`] sans que la main ne change: l’appelant peut alors simplement redemander le
+/// coup.
+pub struct Engine3 {
+    #[doc(hidden)]
+    area : Area3,
+    #[doc(hidden)]
+    interfaces : [Box<dyn Interface3 + Send>; 2],
+    #[doc(hidden)]
+    current_player : PlayerId,
+    #[doc(hidden)]
+    status : GameStatus,
+    #[doc(hidden)]
+    history : Vec<Move>,
+}
+
+impl Engine3 {
+    /// Crée un nouveau gestionnaire de jeux, le joueur 1 commençant la partie
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_one_interface` : Interface vers le premier joueur
+    ///  * `player_two_interface` : Interface vers le second joueur
+    pub fn new(player_one_interface : Box<dyn Interface3 + Send>, player_two_interface : Box<dyn Interface3 + Send>) -> Self {
+        return Self {
+            area: Area3::new(),
+            interfaces: [player_one_interface, player_two_interface],
+            current_player: PlayerId::ONE,
+            status: GameStatus::InProgress,
+            history: Vec::new(),
+        };
+    }
+
+    /// Choisi le joueur commençant la partie
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId` - L’identifiant du joueur n’est ni 1 ni 2.
+    ///  * `GameAlreadyStarted` - Un coup a déjà été joué.
+    pub fn set_first_player(&mut self, player_id : PlayerId) -> Result<()> {
+        if player_id.get() > 2 {
+            return Err(Error::InvalidPlayerId(player_id.get()));
+        }
+
+        if !self.history.is_empty() {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        self.current_player = player_id;
+
+        return Ok(());
+    }
+
+    /// Fourni la zone de jeu
+    pub fn get_disposition(&self) -> &Area3 {
+        return &self.area;
+    }
+
+    /// Fourni les coups joués depuis le début de la partie
+    pub fn history(&self) -> &[Move] {
+        return &self.history;
+    }
+
+    /// Fourni l’identifiant du joueur devant jouer (1 ou 2)
+    pub fn current_player(&self) -> PlayerId {
+        return self.current_player;
+    }
+
+    /// Fourni l’état de la partie
+    pub fn status(&self) -> GameStatus {
+        return self.status;
+    }
+
+    /// Fourni le nom d’un joueur
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId` - L’identifiant du joueur n’est ni 1 ni 2.
+    pub fn player_name(&self, player_id : PlayerId) -> Result<String> {
+        return match player_id {
+            PlayerId::ONE | PlayerId::TWO => Ok(self.interfaces[player_id.index()].name()),
+            _ => Err(Error::InvalidPlayerId(player_id.get())),
+        };
+    }
+
+    /// Fourni le jeton d’un joueur, jaune pour le joueur 1 et rouge pour le joueur 2
+    pub fn token(player_id : PlayerId) -> State {
+        return if player_id == PlayerId::ONE { State::YellowToken } else { State::RedToken };
+    }
+
+    /// Joue un coup pour le joueur devant jouer, sans temps de réflexion enregistré
+    ///
+    /// # Arguments
+    ///
+    ///  * `pin` : La tige où empiler le jeton
+    ///
+    /// # Retour
+    ///
+    /// L’état de la partie après le coup
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` - La partie est déjà terminée.
+    ///  * `InvalidColumn` - L’identifiant de la tige est invalide.
+    ///  * `FilledColumn` - La tige est déjà remplie.
+    pub fn apply_move(&mut self, pin : usize) -> Result<GameStatus> {
+        if self.status != GameStatus::InProgress {
+            return Err(Error::GameAlreadyFinished);
+        }
+
+        let token = Self::token(self.current_player);
+        let level = self.area.set_token(token, pin)?;
+
+        self.history.push(Move { player: self.current_player, column: pin, row: level, token, kind: MoveKind::Drop, think_time: Duration::default(), nodes: None });
+
+        if !self.area.winning_cells().is_empty() {
+            self.status = GameStatus::Won(self.current_player);
+        } else if self.area.is_full() {
+            self.status = GameStatus::Draw;
+        } else {
+            self.current_player = self.current_player.next(2);
+        }
+
+        return Ok(self.status);
+    }
+
+    /// Demande son coup au joueur devant jouer puis le joue
+    ///
+    /// # Retour
+    ///
+    /// L’état de la partie après le coup
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` - La partie est déjà terminée.
+    ///  * `InterfaceFailure` - Le joueur n’a pas pu jouer.
+    ///  * `InvalidColumn` ou `FilledColumn` - Le coup proposé est invalide, le même joueur devant
+    ///    rejouer.
+    pub fn step(&mut self) -> Result<GameStatus> {
+        if self.status != GameStatus::InProgress {
+            return Err(Error::GameAlreadyFinished);
+        }
+
+        let token = Self::token(self.current_player);
+        let interface = &mut self.interfaces[self.current_player.index()];
+        let started = Instant::now();
+        let pin = interface.play_3d(&self.area, token).map_err(Error::InterfaceFailure)?;
+        let (think_time, nodes) = (started.elapsed(), interface.nodes_searched());
+        let status = self.apply_move(pin)?;
+
+        if let Some(played) = self.history.last_mut() {
+            played.think_time = think_time;
+            played.nodes = nodes;
+        }
+
+        return Ok(status);
+    }
+
+    /// Joue la partie jusqu’à son terme
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Voir [`Engine3::step`], un coup invalide interrompant la partie.
+    pub fn play(&mut self) -> Result<GameResult> {
+        while self.step()? == GameStatus::InProgress {}
+
+        return Ok(self.result());
+    }
+
+    /// Fourni le résultat de la partie, `GameResult::Aborted` si elle n’est pas terminée
+    pub fn result(&self) -> GameResult {
+        return match self.status {
+            GameStatus::Won(player_id) => GameResult::Winner {
+                player_id,
+                token: Self::token(player_id),
+                winning_line: self.area.winning_cells().to_vec(),
+            },
+            GameStatus::Draw => GameResult::Draw,
+            GameStatus::Timeout(player_id) => GameResult::Timeout { player_id },
+            GameStatus::InProgress | GameStatus::Aborted => GameResult::Aborted,
+        };
+    }
+}
@@ -0,0 +1,162 @@
+//! Positions de départ aléatoires (mode « Scramble »)
+//!
+//! Les joueurs expérimentés connaissent par cœur les meilleures ouvertures de la zone de jeu
+//! classique. Afin de les en dispenser, une partie peut commencer par un nombre donné de coups
+//! tirés au hasard. Aucun de ces coups ne termine la partie et la position obtenue est évaluée par
+//! le [`Solver`]: elle n’est retenue que si son score reste, en valeur absolue, inférieur ou égal
+//! au seuil choisi, de sorte qu’aucun des joueurs ne soit trop avantagé.
+//!
+//! Un score nul correspond à une position nulle en cas de jeu parfait, un score faible à une
+//! victoire obtenue dans les derniers coups de la partie (voir le module
+//! [`solver`](super::solver)).
+//!
+//! La résolution des positions de peu de coups étant coûteuse sur les grandes zones de jeu, une
+//! [`OpeningBook`] peut être consultée avant le solveur.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, AreaConfig};
+//! use connect_four::rng::Pcg32;
+//! use connect_four::scramble::Scramble;
+//! use connect_four::solver::Solver;
+//!
+//! let config = AreaConfig::new(5, 4, 4).unwrap();
+//! let mut solver = Solver::new();
+//! let mut scramble = Scramble::new(4);
+//!
+//! scramble.threshold = 1;
+//!
+//! let moves = scramble.generate(config, &mut Pcg32::new(42), &mut solver, None).unwrap();
+//! let mut area = Area::with_config(config).unwrap();
+//!
+//! for col in moves.iter() {
+//!     let token = area.next_token().unwrap().unwrap();
+//!
+//!     area.set_token(token, *col).unwrap();
+//! }
+//!
+//! let (_, score) = solver.best_move(&area, area.next_token().unwrap().unwrap()).unwrap();
+//!
+//! assert_eq!(moves.len(), 4);
+//! assert!(score.abs() <= 1);
+//! ```
+
+use super::book::OpeningBook;
+use super::connect_four::{Area, AreaConfig, Error, PlayError, Result};
+use super::rng::Rng;
+use super::solver::Solver;
+
+/// Écart de score toléré par défaut
+pub const DEFAULT_THRESHOLD : i32 = 2;
+
+/// Nombre de tirages effectués par défaut avant d’abandonner
+pub const DEFAULT_MAX_ATTEMPTS : usize = 100;
+
+/// Générateur de positions de départ aléatoires
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Scramble {
+    /// Nombre de coups tirés au hasard
+    pub plies : usize,
+    /// Valeur absolue maximale du score de la position obtenue
+    pub threshold : i32,
+    /// Nombre de positions tirées avant d’abandonner
+    pub max_attempts : usize,
+}
+
+impl Scramble {
+    /// Crée un générateur de positions du nombre de coups donné, avec le seuil et le nombre de
+    /// tirages par défaut
+    ///
+    /// # Arguments
+    ///
+    ///  * `plies` : Le nombre de coups tirés au hasard
+    pub fn new(plies : usize) -> Self {
+        Self {
+            plies,
+            threshold: DEFAULT_THRESHOLD,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    /// Tire une position de départ équilibrée
+    ///
+    /// Les coups sont joués depuis la zone de jeu vide, en alternant les jetons à partir du jeton
+    /// jaune. Les couleurs n’ont toutefois pas d’importance: la position peut être rejouée par un
+    /// gestionnaire de jeux quel que soit le joueur commençant la partie.
+    ///
+    /// # Arguments
+    ///
+    ///  * `config` : La configuration de la zone de jeu
+    ///  * `rng` : Le générateur tirant les coups
+    ///  * `solver` : Le solveur évaluant les positions tirées
+    ///  * `book` : La bibliothèque d’ouvertures consultée avant le solveur, si elle existe
+    ///
+    /// # Retour
+    ///
+    /// Les colonnes jouées, du premier au dernier coup
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - La configuration de la zone de jeu est invalide.
+    ///  * `NoBalancedPosition` - Aucune des positions tirées n’est assez équilibrée ou la zone de
+    ///    jeu ne compte pas assez de cases pour jouer le nombre de coups demandé.
+    ///  * `InterfaceFailure` - Le générateur de nombres aléatoires n’a pas pu fournir de coup.
+    ///  * `Aborted` - La résolution a été interrompue (voir [`Solver::set_progress`]).
+    pub fn generate(&self, config : AreaConfig, rng : &mut dyn Rng, solver : &mut Solver, book : Option<&OpeningBook>) -> Result<Vec<usize>> {
+        config.validate()?;
+
+        for _ in 0..self.max_attempts {
+            let (area, moves) = match self.draw(config, rng)? {
+                Some(drawn) => drawn,
+                None => continue,
+            };
+            let token = match area.next_token()? {
+                Some(token) if !area.get_available_columns().is_empty() => token,
+                _ => continue,
+            };
+            let score = match book.and_then(|book| book.get(&area, token)) {
+                Some(entry) => entry.score,
+                None => solver.best_move(&area, token)?.1,
+            };
+
+            if score.abs() <= self.threshold {
+                return Ok(moves);
+            }
+        }
+
+        return Err(Error::NoBalancedPosition);
+    }
+
+    /// Joue au hasard le nombre de coups demandé, en évitant ceux terminant la partie
+    ///
+    /// # Retour
+    ///
+    /// La position obtenue et les colonnes jouées ou `None` si la partie ne peut plus continuer
+    /// sans se terminer
+    fn draw(&self, config : AreaConfig, rng : &mut dyn Rng) -> Result<Option<(Area, Vec<usize>)>> {
+        let mut area = Area::with_config(config)?;
+        let mut moves = Vec::with_capacity(self.plies);
+
+        for _ in 0..self.plies {
+            let token = match area.next_token()? {
+                Some(token) => token,
+                None => return Ok(None),
+            };
+            let columns : Vec<usize> = area.get_available_columns().into_iter()
+                .filter(|col| matches!(area.is_winning_move(token, *col), Ok(false)))
+                .collect();
+
+            if columns.is_empty() {
+                return Ok(None);
+            }
+
+            let col = columns[rng.below(columns.len()).map_err(|e| Error::InterfaceFailure(PlayError::Io(e)))?];
+
+            area.set_token(token, col)?;
+            moves.push(col);
+        }
+
+        return Ok(Some((area, moves)));
+    }
+}
@@ -0,0 +1,233 @@
+//! Gestion de nombreuses parties simultanées
+//!
+//! Un [`GameServer`] conserve un ensemble de parties, chacune identifiée par un [`GameId`] tiré
+//! au hasard. Les coups reçus par un serveur réseau ou une API web sont transmis à la partie
+//! concernée via [`GameServer::with_game`].
+//!
+//! Chaque partie est verrouillée indépendamment: la liste des parties n’est verrouillée que le
+//! temps de retrouver la partie, de sorte que la réflexion d’une intelligence artificielle dans
+//! une partie ne bloque pas les autres. Deux requêtes visant la même partie sont en revanche
+//! traitées l’une après l’autre.
+//!
+//! Le serveur est générique: il peut conserver des gestionnaires de jeux [`Engine`] ou tout autre
+//! représentation d’une partie, comme celle de l’API REST (voir [`http`](super::http)).
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Engine, Error, GameStatus};
+//! use connect_four::random_bot::RandomBot;
+//! use connect_four::server::GameServer;
+//!
+//! let server = GameServer::new();
+//! let first = server.create(Engine::new(Box::new(RandomBot::new("Robot 1")), Box::new(RandomBot::new("Robot 2")))).unwrap();
+//! let second = server.create(Engine::new(Box::new(RandomBot::new("Robot 3")), Box::new(RandomBot::new("Robot 4")))).unwrap();
+//!
+//! assert_ne!(first, second);
+//! assert_eq!(server.play_move(first, 3).unwrap(), GameStatus::InProgress);
+//! assert_eq!(server.with_game(first, |engine| engine.history().len()).unwrap(), 1);
+//! assert_eq!(server.with_game(second, |engine| engine.history().len()).unwrap(), 0);
+//!
+//! server.remove(first).unwrap();
+//!
+//! assert!(matches!(server.play_move(first, 3), Err(Error::UnknownGame(id)) if id == first.get()));
+//! assert_eq!(server.len(), 1);
+//! ```
+
+use super::connect_four::{Action, Engine, Error, GameStatus, PlayError, Result};
+use super::rng::{OsRng, Rng};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Identifiant d’une partie conservée par un [`GameServer`]
+///
+/// L’identifiant s’écrit sous la forme de 16 chiffres hexadécimaux.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameId(u64);
+
+impl GameId {
+    /// Crée un identifiant de partie
+    pub fn new(id : u64) -> Self {
+        return Self(id);
+    }
+
+    /// Fourni la valeur de l’identifiant
+    pub fn get(&self) -> u64 {
+        return self.0;
+    }
+}
+
+impl Display for GameId {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{:016x}", self.0);
+    }
+}
+
+impl FromStr for GameId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s : &str) -> std::result::Result<Self, Self::Err> {
+        return u64::from_str_radix(s, 16).map(Self);
+    }
+}
+
+/// Partie partagée entre les requêtes qui la concernent
+type SharedGame<G> = Arc<Mutex<G>>;
+
+/// Ensemble de parties simultanées
+pub struct GameServer<G> {
+    #[doc(hidden)]
+    games : Mutex<HashMap<GameId, SharedGame<G>>>,
+    #[doc(hidden)]
+    rng : Mutex<Box<dyn Rng + Send>>,
+}
+
+impl<G> GameServer<G> {
+    /// Crée un serveur sans partie, les identifiants étant tirés avec [`OsRng`]
+    pub fn new() -> Self {
+        return Self::with_rng(Box::new(OsRng));
+    }
+
+    /// Crée un serveur sans partie tirant les identifiants avec le générateur donné
+    ///
+    /// # Arguments
+    ///
+    ///  * `rng` : Le générateur des identifiants, par exemple [`Pcg32`](super::rng::Pcg32) afin
+    ///    de reproduire les identifiants lors des tests
+    pub fn with_rng(rng : Box<dyn Rng + Send>) -> Self {
+        return Self {
+            games: Mutex::new(HashMap::new()),
+            rng: Mutex::new(rng),
+        };
+    }
+
+    /// Ajoute une partie au serveur
+    ///
+    /// # Retour
+    ///
+    /// L’identifiant attribué à la partie, différent de ceux des parties conservées
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InterfaceFailure` - Le générateur n’a pas pu fournir d’identifiant.
+    pub fn create(&self, game : G) -> Result<GameId> {
+        let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+        let mut games = self.games();
+
+        loop {
+            let id = GameId(rng.next_u64().map_err(|e| Error::InterfaceFailure(PlayError::Io(e)))?);
+
+            if let std::collections::hash_map::Entry::Vacant(entry) = games.entry(id) {
+                entry.insert(Arc::new(Mutex::new(game)));
+
+                return Ok(id);
+            }
+        }
+    }
+
+    /// Fourni la partie identifiée, afin de la verrouiller plus tard
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `UnknownGame` - Aucune partie ne porte cet identifiant.
+    pub fn get(&self, id : GameId) -> Result<SharedGame<G>> {
+        return self.games().get(&id).cloned().ok_or(Error::UnknownGame(id.get()));
+    }
+
+    /// Verrouille la partie identifiée le temps d’exécuter l’opération donnée
+    ///
+    /// # Arguments
+    ///
+    ///  * `id` : L’identifiant de la partie
+    ///  * `f` : L’opération, recevant la partie
+    ///
+    /// # Retour
+    ///
+    /// Le résultat de l’opération
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `UnknownGame` - Aucune partie ne porte cet identifiant.
+    ///  * `Aborted` - Une opération précédente sur la partie a paniqué, la laissant dans un état
+    ///    incohérent.
+    pub fn with_game<R, F : FnOnce(&mut G) -> R>(&self, id : GameId, f : F) -> Result<R> {
+        let game = self.get(id)?;
+        let mut game = game.lock().map_err(|_| Error::Aborted)?;
+
+        return Ok(f(&mut game));
+    }
+
+    /// Retire une partie du serveur
+    ///
+    /// Une opération en cours sur la partie se termine normalement.
+    ///
+    /// # Retour
+    ///
+    /// La partie retirée
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `UnknownGame` - Aucune partie ne porte cet identifiant.
+    pub fn remove(&self, id : GameId) -> Result<SharedGame<G>> {
+        return self.games().remove(&id).ok_or(Error::UnknownGame(id.get()));
+    }
+
+    /// Fourni les identifiants des parties conservées, dans un ordre quelconque
+    pub fn ids(&self) -> Vec<GameId> {
+        return self.games().keys().copied().collect();
+    }
+
+    /// Fourni le nombre de parties conservées
+    pub fn len(&self) -> usize {
+        return self.games().len();
+    }
+
+    /// Indique si le serveur ne conserve aucune partie
+    pub fn is_empty(&self) -> bool {
+        return self.games().is_empty();
+    }
+
+    /// Verrouille la liste des parties, qu’une panique ne peut pas laisser incohérente
+    fn games(&self) -> MutexGuard<'_, HashMap<GameId, SharedGame<G>>> {
+        return self.games.lock().unwrap_or_else(|e| e.into_inner());
+    }
+}
+
+impl<G> Default for GameServer<G> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl GameServer<Engine> {
+    /// Joue un coup dans la partie identifiée (voir [`Engine::apply_move`])
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Celles de [`GameServer::with_game`] et de [`Engine::apply_move`]
+    pub fn play_move(&self, id : GameId, column : usize) -> Result<GameStatus> {
+        return self.with_game(id, |engine| engine.apply_move(column))?;
+    }
+
+    /// Joue une action dans la partie identifiée (voir [`Engine::apply_action`])
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Celles de [`GameServer::with_game`] et de [`Engine::apply_action`]
+    pub fn apply_action(&self, id : GameId, action : Action) -> Result<GameStatus> {
+        return self.with_game(id, |engine| engine.apply_action(action))?;
+    }
+
+    /// Demande son coup au joueur courant de la partie identifiée puis l’applique (voir
+    /// [`Engine::step`])
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Celles de [`GameServer::with_game`] et de [`Engine::step`]
+    pub fn step(&self, id : GameId) -> Result<GameStatus> {
+        return self.with_game(id, Engine::step)?;
+    }
+}
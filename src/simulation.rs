@@ -0,0 +1,165 @@
+//! Gestionnaire de jeux générique destiné aux simulations
+//!
+//! Le gestionnaire [`Engine`](super::connect_four::Engine) accède aux joueurs via des objets
+//! [`Interface`] partagés entre fils d’exécution, ce qui permet de limiter leur temps de
+//! réflexion ou de les remplacer en cours de partie. Le gestionnaire [`Engine`] de ce module
+//! possède directement ses deux joueurs, dont les types sont connus à la compilation, et les
+//! appelle sans verrou ni indirection. Le gain reste faible, la réflexion des joueurs dominant la
+//! durée d’une partie, mais ces derniers restent accessibles entre les parties (voir
+//! [`Engine::players_mut`]) sans passer par des objets partagés.
+//!
+//! Les règles, l’état de la partie et son enregistrement restent ceux d’un gestionnaire
+//! classique, accessible via [`Engine::engine`] et [`Engine::engine_mut`]: variantes, objectif,
+//! handicap, comportement face aux coups illégaux ou temps de réflexion se règlent de la même
+//! façon. Seules les parties à deux joueurs sont prises en charge. Les joueurs étant appelés
+//! directement, le dépassement d’une limite de temps n’est constaté qu’une fois le coup proposé.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{GameResult, PlayerId};
+//! use connect_four::greedy_bot::GreedyBot;
+//! use connect_four::random_bot::RandomBot;
+//! use connect_four::rng::Pcg32;
+//! use connect_four::simulation::Engine;
+//!
+//! let mut engine = Engine::new(GreedyBot::new("Robot glouton"), RandomBot::with_rng("Robot", Box::new(Pcg32::new(42))));
+//! let mut wins = 0;
+//!
+//! for _ in 0..100 {
+//!     if let GameResult::Winner { player_id: PlayerId::ONE, .. } = engine.play().unwrap() {
+//!         wins += 1;
+//!     }
+//!
+//!     assert!(engine.engine().record().verify(engine.engine().status()).is_ok());
+//!
+//!     engine.reset();
+//! }
+//!
+//! assert!(wins > 50);
+//! ```
+
+use super::connect_four::{Area, AreaConfig, GameResult, GameStatus, Interface, PlayError, PlayerId, Result, State};
+
+/// Gestionnaire de jeux générique
+pub struct Engine<P1 : Interface, P2 : Interface> {
+    #[doc(hidden)]
+    engine : super::connect_four::Engine,
+    #[doc(hidden)]
+    player_one : P1,
+    #[doc(hidden)]
+    player_two : P2,
+}
+
+impl<P1 : Interface, P2 : Interface> Engine<P1, P2> {
+    /// Crée un nouveau gestionnaire de jeux sur la zone de jeu classique, le joueur 1 commençant
+    /// la partie
+    ///
+    /// # Arguments
+    ///
+    ///  * `player_one` : Le premier joueur
+    ///  * `player_two` : Le second joueur
+    pub fn new(player_one : P1, player_two : P2) -> Self {
+        let engine = super::connect_four::Engine::new(Box::new(Seat(player_one.name())), Box::new(Seat(player_two.name())));
+
+        return Self { engine, player_one, player_two };
+    }
+
+    /// Crée un nouveau gestionnaire de jeux sur une zone de jeu de la configuration donnée
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` - La configuration de la zone de jeu est invalide.
+    pub fn with_config(player_one : P1, player_two : P2, config : AreaConfig) -> Result<Self> {
+        let engine = super::connect_four::Engine::with_config(Box::new(Seat(player_one.name())), Box::new(Seat(player_two.name())), config)?;
+
+        return Ok(Self { engine, player_one, player_two });
+    }
+
+    /// Fourni le gestionnaire de jeux menant la partie: zone de jeu, historique, état ou
+    /// enregistrement de la partie
+    pub fn engine(&self) -> &super::connect_four::Engine {
+        return &self.engine;
+    }
+
+    /// Fourni le gestionnaire de jeux menant la partie afin d’en modifier les règles
+    ///
+    /// Les interfaces enregistrées dans ce gestionnaire ne sont jamais sollicitées: les coups sont
+    /// demandés via [`Engine::step`] aux joueurs possédés par le gestionnaire générique.
+    pub fn engine_mut(&mut self) -> &mut super::connect_four::Engine {
+        return &mut self.engine;
+    }
+
+    /// Recommence la partie (voir [`Engine::reset`](super::connect_four::Engine::reset)), le nom
+    /// des joueurs étant mis à jour
+    pub fn reset(&mut self) {
+        let _ = self.engine.set_player(PlayerId::ONE, Box::new(Seat(self.player_one.name())));
+        let _ = self.engine.set_player(PlayerId::TWO, Box::new(Seat(self.player_two.name())));
+
+        self.engine.reset();
+    }
+
+    /// Fourni les deux joueurs
+    pub fn players(&self) -> (&P1, &P2) {
+        return (&self.player_one, &self.player_two);
+    }
+
+    /// Fourni les deux joueurs afin de les modifier entre deux parties
+    pub fn players_mut(&mut self) -> (&mut P1, &mut P2) {
+        return (&mut self.player_one, &mut self.player_two);
+    }
+
+    /// Détruit le gestionnaire de jeux et rend ses joueurs
+    pub fn into_players(self) -> (P1, P2) {
+        return (self.player_one, self.player_two);
+    }
+
+    /// Demande son coup au joueur devant jouer puis le joue
+    ///
+    /// Les joueurs sont prévenus du début de la partie, de la réflexion de leur adversaire et de
+    /// ses coups, et les coups illégaux sont traités, comme avec
+    /// [`Engine::step`](super::connect_four::Engine::step).
+    ///
+    /// # Retour
+    ///
+    /// L’état de la partie après le coup
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Voir [`Engine::step`](super::connect_four::Engine::step).
+    pub fn step(&mut self) -> Result<GameStatus> {
+        return if self.engine.current_player() == PlayerId::ONE {
+            self.engine.step_with(&mut self.player_one, &mut self.player_two)
+        } else {
+            self.engine.step_with(&mut self.player_two, &mut self.player_one)
+        };
+    }
+
+    /// Joue la partie jusqu’à son terme
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Voir [`Engine::step`].
+    pub fn play(&mut self) -> Result<GameResult> {
+        loop {
+            let status = self.step()?;
+
+            if let Some(result) = self.engine.result(status) {
+                return Ok(result);
+            }
+        }
+    }
+}
+
+/// Place d’un joueur dans le gestionnaire de jeux menant la partie, ne servant qu’à porter son nom
+struct Seat(String);
+
+impl Interface for Seat {
+    fn play(&mut self, _area : &Area, _token : State) -> std::result::Result<usize, PlayError> {
+        return Err(PlayError::Other(String::from("Le joueur est appelé par le gestionnaire générique")));
+    }
+
+    fn name(&self) -> String {
+        return self.0.clone();
+    }
+}
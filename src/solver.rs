@@ -0,0 +1,634 @@
+//! Solveur exact du puissance 4
+//!
+//! Le solveur calcule le score théorique d’une position, c’est-à-dire l’issue de la partie si les
+//! deux joueurs jouent parfaitement. Il repose sur une recherche negamax avec élagage alpha-bêta
+//! opérant directement sur des bitboards, une [`TranspositionTable`] et une succession de
+//! recherches à fenêtre nulle resserrant progressivement l’intervalle du score.
+//!
+//! # Score
+//!
+//! Le score est donné du point de vue du joueur devant jouer:
+//!  * Un score positif indique que ce joueur gagne. Il vaut alors le nombre de cases restant vides
+//!    au moment de son dernier coup, plus un, divisé par deux: plus la victoire est rapide, plus
+//!    le score est élevé.
+//!  * Un score négatif indique que l’adversaire gagne, suivant le même calcul.
+//!  * Un score nul indique une partie nulle.
+//!
+//! Le solveur supporte toutes les configurations de zone de jeu mais la durée de la résolution
+//! croît très vite avec le nombre de cases vides. Sur le plateau classique, la résolution des
+//! premiers coups peut prendre plusieurs minutes.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{Area, State};
+//! use connect_four::solver::Solver;
+//!
+//! let area : Area = "
+//!     .......
+//!     .......
+//!     .......
+//!     .......
+//!     ...R...
+//!     .YYY.R.
+//! ".parse().unwrap();
+//!
+//! let mut solver = Solver::new();
+//!
+//! // Les jetons rouges ne peuvent pas bloquer les deux côtés de l’alignement jaune
+//! assert!(solver.solve(&area, State::RedToken).unwrap() < 0);
+//! ```
+
+use super::connect_four::{Area, AreaConfig, State, Error, Result, Interface, PlayError};
+use super::transposition::{TranspositionTable, Entry, Bound};
+use super::book::OpeningBook;
+use super::analysis::{Analyzer, ColumnScore, Score};
+use super::progress::SearchProgress;
+use std::sync::Arc;
+
+/// Mémoire allouée par défaut à la table de transposition (64 Mio)
+const DEFAULT_TABLE_MEMORY : usize = 64 << 20;
+
+/// Nombre de positions explorées entre deux mises à jour du suivi de l’avancement
+const PROGRESS_BATCH : u64 = 1024;
+
+/// Position manipulée par le solveur
+///
+/// La disposition des bits suit celle de [`Area`]: le bit `col * (rows + 1) + row` correspond à
+/// la case `(col, row)`, la ligne supplémentaire de chaque colonne restant vide.
+#[derive(Copy, Clone)]
+struct Position {
+    /// Jetons du joueur devant jouer
+    current : u64,
+    /// Cases occupées
+    mask : u64,
+    /// Nombre de jetons joués
+    moves : usize,
+}
+
+/// Solveur exact
+pub struct Solver {
+    #[doc(hidden)]
+    table : TranspositionTable,
+    #[doc(hidden)]
+    config : AreaConfig,
+    /// Bit du bas de chaque colonne
+    #[doc(hidden)]
+    bottom_mask : u64,
+    /// Ensemble des cases de la zone de jeu pouvant recevoir un jeton
+    #[doc(hidden)]
+    board_mask : u64,
+    /// Cases bloquées, y compris celles rendues inaccessibles par un blocage plus haut dans la
+    /// même colonne
+    #[doc(hidden)]
+    blocked : u64,
+    /// Colonnes, des plus centrales aux plus excentrées
+    #[doc(hidden)]
+    column_order : Vec<usize>,
+    /// Nombre de positions explorées depuis la création du solveur
+    #[doc(hidden)]
+    explored : u64,
+    #[doc(hidden)]
+    progress : Option<Arc<SearchProgress>>,
+    /// Indique si la résolution en cours a été interrompue via le suivi de son avancement
+    #[doc(hidden)]
+    cancelled : bool,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver {
+    /// Crée un solveur disposant d’une table de transposition de 64 Mio
+    pub fn new() -> Self {
+        return Self::with_table(TranspositionTable::with_memory(DEFAULT_TABLE_MEMORY));
+    }
+
+    /// Crée un solveur utilisant la table de transposition donnée
+    ///
+    /// # Arguments
+    ///
+    ///  * `table` : La table de transposition, dont la taille borne la mémoire utilisée
+    pub fn with_table(table : TranspositionTable) -> Self {
+        let mut solver = Self {
+            table,
+            config: AreaConfig::default(),
+            bottom_mask: 0,
+            board_mask: 0,
+            blocked: 0,
+            column_order: Vec::new(),
+            explored: 0,
+            progress: None,
+            cancelled: false,
+        };
+
+        solver.configure(AreaConfig::default());
+
+        return solver;
+    }
+
+    /// Fourni le nombre de positions explorées depuis la création du solveur
+    pub fn explored(&self) -> u64 {
+        return self.explored;
+    }
+
+    /// Partage l’avancement des résolutions
+    ///
+    /// Au début de chaque résolution, le suivi est remis à zéro sans profondeur, la recherche
+    /// n’étant pas bornée. Les positions explorées y sont comptabilisées par lots.
+    ///
+    /// Une fois le suivi interrompu (voir [`SearchProgress::cancel`]), les résolutions échouent
+    /// avec l’erreur `Aborted`.
+    ///
+    /// # Arguments
+    ///
+    ///  * `progress` : Le suivi à mettre à jour ou `None` afin de ne pas suivre les résolutions
+    pub fn set_progress(&mut self, progress : Option<Arc<SearchProgress>>) {
+        self.progress = progress;
+    }
+
+    /// Calcule le score exact d’une position
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position à résoudre
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///
+    /// # Retour
+    ///
+    /// Le score de la position du point de vue de `token` (voir la documentation du module)
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `NotAToken` - L’état donné ne correspond pas à celui d’un jeton.
+    ///  * `InvalidPosition` - La position ne peut pas être atteinte en cours de partie.
+    ///  * `GameAlreadyFinished` - La position contient déjà un alignement ou la zone de jeu est
+    ///    remplie.
+    ///  * `Aborted` - La résolution a été interrompue (voir [`Solver::set_progress`]).
+    pub fn solve(&mut self, area : &Area, token : State) -> Result<i32> {
+        let position = self.load(area, token)?;
+        let score = self.solve_position(position);
+
+        if self.cancelled {
+            return Err(Error::Aborted);
+        }
+
+        return Ok(score);
+    }
+
+    /// Calcule le score exact de chaque coup possible
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position à analyser
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///
+    /// # Retour
+    ///
+    /// Pour chaque colonne, le score de la position du point de vue de `token` si ce dernier y
+    /// joue ou `None` si la colonne est remplie
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `NotAToken` - L’état donné ne correspond pas à celui d’un jeton.
+    ///  * `InvalidPosition` - La position ne peut pas être atteinte en cours de partie.
+    ///  * `GameAlreadyFinished` - La position contient déjà un alignement ou la zone de jeu est
+    ///    remplie.
+    ///  * `Aborted` - La résolution a été interrompue (voir [`Solver::set_progress`]).
+    pub fn analyze(&mut self, area : &Area, token : State) -> Result<Vec<Option<i32>>> {
+        let position = self.load(area, token)?;
+        let cells = self.cells();
+        let mut scores = vec![None; self.config.cols];
+
+        for (col, score) in scores.iter_mut().enumerate() {
+            let played = self.possible(&position) & self.column_mask(col);
+
+            if played == 0 {
+                continue;
+            }
+
+            *score = Some(if self.winning_positions(position.current, position.mask) & played != 0 {
+                (cells + 1 - position.moves as i32) / 2
+            } else {
+                -self.solve_position(self.play(&position, played))
+            });
+        }
+
+        if self.cancelled {
+            return Err(Error::Aborted);
+        }
+
+        return Ok(scores);
+    }
+
+    /// Recherche le meilleur coup d’une position
+    ///
+    /// Parmi les coups de meilleur score, le plus central est choisi.
+    ///
+    /// # Arguments
+    ///
+    ///  * `area` : La position à analyser
+    ///  * `token` : Le jeton devant jouer le prochain coup
+    ///
+    /// # Retour
+    ///
+    /// La colonne à jouer ainsi que le score de la position du point de vue de `token`
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `NotAToken` - L’état donné ne correspond pas à celui d’un jeton.
+    ///  * `InvalidPosition` - La position ne peut pas être atteinte en cours de partie.
+    ///  * `GameAlreadyFinished` - La position contient déjà un alignement ou la zone de jeu est
+    ///    remplie.
+    ///  * `Aborted` - La résolution a été interrompue (voir [`Solver::set_progress`]).
+    pub fn best_move(&mut self, area : &Area, token : State) -> Result<(usize, i32)> {
+        let scores = self.analyze(area, token)?;
+        let mut best : Option<(usize, i32)> = None;
+
+        for col in self.column_order.iter() {
+            if let Some(score) = scores[*col] {
+                match best {
+                    Some((_, best_score)) if best_score >= score => {},
+                    _ => best = Some((*col, score)),
+                }
+            }
+        }
+
+        return best.ok_or(Error::GameAlreadyFinished);
+    }
+
+    /// Convertit une zone de jeu en position, en adaptant le solveur à sa configuration
+    fn load(&mut self, area : &Area, token : State) -> Result<Position> {
+        if token == State::NoToken {
+            return Err(Error::NotAToken);
+        }
+
+        area.validate()?;
+
+        if area.find_winner().is_some() || area.get_available_columns().is_empty() {
+            return Err(Error::GameAlreadyFinished);
+        }
+
+        if *area.config() != self.config {
+            self.configure(*area.config());
+        }
+
+        if let Some(progress) = &self.progress {
+            if progress.is_cancelled() {
+                return Err(Error::Aborted);
+            }
+
+            progress.start(None);
+        }
+
+        self.cancelled = false;
+
+        let height = self.config.rows + 1;
+        let mut position = Position { current: 0, mask: 0, moves: 0 };
+
+        for ((col, row), state) in area.iter() {
+            let bit = 1u64 << (col * height + row);
+
+            if state != State::NoToken {
+                position.mask |= bit;
+                position.moves += 1;
+            }
+
+            if state == token {
+                position.current |= bit;
+            }
+        }
+
+        return Ok(position);
+    }
+
+    /// Adapte les masques du solveur à une configuration et vide la table de transposition
+    fn configure(&mut self, config : AreaConfig) {
+        let height = config.rows + 1;
+        let column = u64::MAX >> (64 - config.rows);
+
+        self.config = config;
+        self.bottom_mask = (0..config.cols).fold(0, |acc, col| acc | 1u64 << (col * height));
+        self.blocked = config.blocked_cells();
+        self.board_mask = (0..config.cols).fold(0, |acc, col| acc | column << (col * height)) & !self.blocked;
+        self.column_order = (0..config.cols).collect();
+        self.column_order.sort_by_key(|col| (2 * col).abs_diff(config.cols - 1));
+        self.table.clear();
+    }
+
+    /// Résout une position dans laquelle le joueur devant jouer ne peut pas gagner immédiatement
+    /// ou le fait, via une succession de recherches à fenêtre nulle
+    fn solve_position(&mut self, position : Position) -> i32 {
+        let cells = self.cells();
+
+        if self.winning_positions(position.current, position.mask) & self.possible(&position) != 0 {
+            return (cells + 1 - position.moves as i32) / 2;
+        }
+
+        let mut min = -(cells - position.moves as i32) / 2;
+        let mut max = (cells + 1 - position.moves as i32) / 2;
+
+        self.table.new_search();
+
+        while min < max && !self.cancelled {
+            let mut med = min + (max - min) / 2;
+
+            // Les scores proches de zéro sont les plus probables et les plus rapides à réfuter
+            if med <= 0 && min / 2 < med {
+                med = min / 2;
+            } else if med >= 0 && max / 2 > med {
+                med = max / 2;
+            }
+
+            let score = self.negamax(position, med, med + 1);
+
+            if score <= med {
+                max = score;
+            } else {
+                min = score;
+            }
+        }
+
+        return min;
+    }
+
+    /// Recherche negamax à fenêtre `]alpha; beta[`. Le joueur devant jouer ne doit pas pouvoir
+    /// gagner immédiatement.
+    fn negamax(&mut self, position : Position, mut alpha : i32, mut beta : i32) -> i32 {
+        self.explored += 1;
+
+        if self.explored.is_multiple_of(PROGRESS_BATCH) {
+            if let Some(progress) = &self.progress {
+                progress.add_nodes(PROGRESS_BATCH);
+                self.cancelled = progress.is_cancelled();
+            }
+        }
+
+        if self.cancelled {
+            return 0;
+        }
+
+        let cells = self.cells();
+        let moves = position.moves as i32;
+        let next = self.non_losing_moves(&position);
+
+        if next == 0 {
+            return -(cells - moves) / 2;
+        }
+
+        if moves >= cells - 2 {
+            return 0;
+        }
+
+        let min = -(cells - 2 - moves) / 2;
+
+        if alpha < min {
+            alpha = min;
+
+            if alpha >= beta {
+                return alpha;
+            }
+        }
+
+        let key = position.current.wrapping_add(position.mask);
+        let mut max = (cells - 1 - moves) / 2;
+
+        if let Some(entry) = self.table.probe(key) {
+            match entry.bound {
+                Bound::Upper => max = i32::min(max, entry.score),
+                Bound::Lower => {
+                    if entry.score > alpha {
+                        alpha = entry.score;
+
+                        if alpha >= beta {
+                            return alpha;
+                        }
+                    }
+                },
+                Bound::Exact => return entry.score,
+            }
+        }
+
+        if beta > max {
+            beta = max;
+
+            if alpha >= beta {
+                return beta;
+            }
+        }
+
+        // Les coups créant le plus de menaces sont explorés en premier, les colonnes centrales
+        // l’emportant à nombre de menaces égal (tri par insertion stable)
+        let mut candidates = [(0u64, 0u32); 64];
+        let mut count = 0;
+
+        for col in self.column_order.iter() {
+            let played = next & self.column_mask(*col);
+
+            if played != 0 {
+                let threats = self.winning_positions(position.current | played, position.mask | played).count_ones();
+                let mut i = count;
+
+                while i > 0 && candidates[i - 1].1 < threats {
+                    candidates[i] = candidates[i - 1];
+                    i -= 1;
+                }
+
+                candidates[i] = (played, threats);
+                count += 1;
+            }
+        }
+
+        let depth = self.cells() as usize - position.moves;
+
+        for &(played, _) in candidates[..count].iter() {
+            let score = -self.negamax(self.play(&position, played), -beta, -alpha);
+
+            if self.cancelled {
+                return 0;
+            }
+
+            if score >= beta {
+                self.table.store(Entry { key, depth, score, bound: Bound::Lower, best_move: None });
+
+                return score;
+            }
+
+            if score > alpha {
+                alpha = score;
+            }
+        }
+
+        self.table.store(Entry { key, depth, score: alpha, bound: Bound::Upper, best_move: None });
+
+        return alpha;
+    }
+
+    /// Joue un coup, la position retournée étant du point de vue de l’adversaire
+    fn play(&self, position : &Position, played : u64) -> Position {
+        return Position {
+            current: position.current ^ position.mask,
+            mask: position.mask | played,
+            moves: position.moves + 1,
+        };
+    }
+
+    /// Cases jouables, une par colonne non remplie
+    fn possible(&self, position : &Position) -> u64 {
+        return (position.mask | self.blocked).wrapping_add(self.bottom_mask) & self.board_mask;
+    }
+
+    /// Coups jouables ne permettant pas à l’adversaire de gagner au coup suivant
+    fn non_losing_moves(&self, position : &Position) -> u64 {
+        let mut possible = self.possible(position);
+        let opponent_wins = self.winning_positions(position.current ^ position.mask, position.mask);
+        let forced = possible & opponent_wins;
+
+        if forced != 0 {
+            // Deux menaces ne peuvent pas être bloquées en un seul coup
+            if forced & (forced - 1) != 0 {
+                return 0;
+            }
+
+            possible = forced;
+        }
+
+        // Jouer sous une menace adverse permettrait à l’adversaire de la compléter
+        if self.blocked == 0 {
+            return possible & !(opponent_wins >> 1);
+        }
+
+        // Les cases bloquées séparent la case jouée de la suivante dans la colonne
+        let above = (position.mask | self.blocked | possible).wrapping_add(self.bottom_mask) & self.board_mask & opponent_wins;
+
+        for col in 0..self.config.cols {
+            if above & self.column_mask(col) != 0 {
+                possible &= !self.column_mask(col);
+            }
+        }
+
+        return possible;
+    }
+
+    /// Cases vides complétant un alignement des jetons de `board`
+    fn winning_positions(&self, board : u64, mask : u64) -> u64 {
+        let height = self.config.rows + 1;
+        let length = self.config.win_length as isize;
+        let mut winning = 0;
+
+        for shift in [1, height, height + 1, height - 1].iter() {
+            let shift = *shift as isize;
+
+            // La case vide occupe la position `gap` dans l’alignement
+            for gap in 0..length {
+                let mut cells = self.board_mask;
+
+                for i in (0..length).filter(|i| *i != gap) {
+                    cells &= self.config.shift(board, (i - gap) * shift);
+                }
+
+                winning |= cells;
+            }
+        }
+
+        return winning & self.board_mask & !mask;
+    }
+
+    fn column_mask(&self, col : usize) -> u64 {
+        return (u64::MAX >> (64 - self.config.rows)) << (col * (self.config.rows + 1));
+    }
+
+    fn cells(&self) -> i32 {
+        return (self.config.cols * self.config.rows) as i32 - self.blocked.count_ones() as i32;
+    }
+}
+
+/// Intelligence artificielle jouant parfaitement grâce au [`Solver`]
+///
+/// Parmi les coups de meilleur score, le plus central est choisi.
+pub struct PerfectBot {
+    #[doc(hidden)]
+    name : String,
+    #[doc(hidden)]
+    solver : Solver,
+    #[doc(hidden)]
+    book : Option<Arc<OpeningBook>>,
+}
+
+impl PerfectBot {
+    /// Initialise l’intelligence artificielle
+    ///
+    /// # Arguments
+    ///
+    ///  * `name` : Le nom donné à l’intelligence artificielle.
+    ///
+    /// # Retour
+    ///
+    /// Une instance de l’intelligence artificielle
+    pub fn new(name : &str) -> Self {
+        Self {
+            name: String::from(name),
+            solver: Solver::new(),
+            book: None,
+        }
+    }
+
+    /// Remplace la bibliothèque d’ouvertures consultée avant toute résolution
+    ///
+    /// # Arguments
+    ///
+    ///  * `book` : La nouvelle bibliothèque ou `None` afin de toujours résoudre la position
+    pub fn set_opening_book(&mut self, book : Option<Arc<OpeningBook>>) {
+        self.book = book;
+    }
+
+    /// Partage l’avancement des résolutions (voir [`Solver::set_progress`])
+    ///
+    /// # Arguments
+    ///
+    ///  * `progress` : Le suivi à mettre à jour ou `None` afin de ne pas suivre les résolutions
+    pub fn set_progress(&mut self, progress : Option<Arc<SearchProgress>>) {
+        self.solver.set_progress(progress);
+    }
+}
+
+/// Les scores du solveur sont convertis en victoires, défaites ou matchs nuls exacts.
+impl Analyzer for Solver {
+    fn analyze(&mut self, area : &Area, token : State) -> Result<Vec<ColumnScore>> {
+        let scores = Solver::analyze(self, area, token)?;
+        let (cells, moves) = (self.cells(), area.token_count() as i32);
+
+        return Ok(scores.into_iter().enumerate().filter_map(|(column, score)| {
+            let score = match score? {
+                0 => Score::Draw,
+                s if s > 0 => Score::Win(((cells + 1 - moves) / 2 - s + 1) as usize),
+                s => Score::Loss(((cells - moves) / 2 + s + 1) as usize),
+            };
+
+            return Some(ColumnScore { column, score });
+        }).collect());
+    }
+}
+
+impl Analyzer for PerfectBot {
+    fn analyze(&mut self, area : &Area, token : State) -> Result<Vec<ColumnScore>> {
+        return Analyzer::analyze(&mut self.solver, area, token);
+    }
+}
+
+impl Interface for PerfectBot {
+    fn play(&mut self, area : &Area, token : State) -> std::result::Result<usize, PlayError> {
+        if let Some(entry) = self.book.as_ref().and_then(|book| book.get(area, token)) {
+            return Ok(entry.column);
+        }
+
+        return self.solver.best_move(area, token).map(|(col, _)| col).map_err(|e| PlayError::Other(e.to_string()));
+    }
+
+    fn name(&self) -> String {
+        return self.name.clone();
+    }
+}
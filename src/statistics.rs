@@ -0,0 +1,278 @@
+//! Statistiques des confrontations entre joueurs
+//!
+//! Pour chaque paire de joueurs, identifiés par leur nom, sont comptabilisés les victoires, les
+//! matchs nuls, les défaites ainsi que le nombre de coups joués afin d’en déduire la durée
+//! moyenne des parties. Les statistiques peuvent être enregistrées dans un fichier afin d’être
+//! conservées d’une session à l’autre.
+//!
+//! Le fichier est un fichier texte contenant une ligne par joueur et par adversaire: le nombre de
+//! victoires, de matchs nuls, de défaites et de coups joués suivi des noms du joueur et de son
+//! adversaire, séparés par des tabulations.
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::{GameStatus, PlayerId};
+//! use connect_four::statistics::Statistics;
+//!
+//! let mut statistics = Statistics::new();
+//!
+//! statistics.add_game("Alice", "Bob", GameStatus::Won(PlayerId::ONE), 15);
+//! statistics.add_game("Bob", "Alice", GameStatus::Draw, 42);
+//!
+//! let alice = statistics.head_to_head("Alice", "Bob");
+//!
+//! assert_eq!((alice.stats.wins, alice.stats.draws, alice.stats.losses), (1, 1, 0));
+//! assert_eq!(alice.average_length(), Some(28.5));
+//!
+//! let mut data = Vec::new();
+//!
+//! statistics.write(&mut data).unwrap();
+//!
+//! assert_eq!(Statistics::read(&mut data.as_slice()).unwrap(), statistics);
+//! ```
+
+use super::connect_four::{GameRecord, GameStatus, PlayerId, Result};
+use super::arena::MatchStats;
+use super::i18n;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+/// Bilan des parties d’un joueur contre un ou plusieurs adversaires
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeadToHead {
+    /// Victoires, matchs nuls et défaites du joueur
+    pub stats : MatchStats,
+    /// Nombre total de coups joués lors des parties, par les deux joueurs
+    pub moves : usize,
+}
+
+impl HeadToHead {
+    /// Fourni le nombre moyen de coups joués par partie
+    ///
+    /// # Retour
+    ///
+    /// La durée moyenne des parties ou `None` si aucune partie n’a été comptabilisée
+    pub fn average_length(&self) -> Option<f64> {
+        if self.stats.games() == 0 {
+            return None;
+        }
+
+        return Some(self.moves as f64 / self.stats.games() as f64);
+    }
+
+    /// Cumule deux bilans
+    fn merge(&mut self, other : &HeadToHead) {
+        self.stats.wins += other.stats.wins;
+        self.stats.draws += other.stats.draws;
+        self.stats.losses += other.stats.losses;
+        self.moves += other.moves;
+    }
+}
+
+/// Statistiques des confrontations d’un ensemble de joueurs
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Statistics {
+    /// Bilan de chaque joueur contre chacun de ses adversaires, indexé par les noms du joueur et
+    /// de l’adversaire
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(with = "entries"))]
+    records : BTreeMap<(String, String), HeadToHead>,
+}
+
+impl Statistics {
+    /// Crée des statistiques vides
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Charge des statistiques depuis un fichier
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Voir [`Statistics::read`]
+    pub fn load<P : AsRef<Path>>(path : P) -> io::Result<Self> {
+        return Self::read(&mut io::BufReader::new(std::fs::File::open(path)?));
+    }
+
+    /// Enregistre les statistiques dans un fichier
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie
+    pub fn save<P : AsRef<Path>>(&self, path : P) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(std::fs::File::create(path)?);
+
+        self.write(&mut writer)?;
+
+        return writer.flush();
+    }
+
+    /// Lit des statistiques
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie, `InvalidData` si l’une des lignes n’est pas un bilan valide.
+    pub fn read<R : BufRead>(reader : &mut R) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Le fichier n’est pas un fichier de statistiques");
+        let mut statistics = Self::new();
+
+        for line in reader.lines() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut fields = line.splitn(6, '\t');
+            let mut count = || fields.next().and_then(|count| count.parse::<usize>().ok()).ok_or_else(invalid);
+            let stats = MatchStats {
+                wins: count()?,
+                draws: count()?,
+                losses: count()?,
+            };
+            let moves = count()?;
+            let player = fields.next().ok_or_else(invalid)?;
+            let opponent = fields.next().ok_or_else(invalid)?;
+
+            statistics.records.insert((String::from(player), String::from(opponent)), HeadToHead { stats, moves });
+        }
+
+        return Ok(statistics);
+    }
+
+    /// Écrit les statistiques
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur d’entrée/sortie
+    pub fn write<W : Write>(&self, writer : &mut W) -> io::Result<()> {
+        for ((player, opponent), record) in self.records.iter() {
+            writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{}", record.stats.wins, record.stats.draws, record.stats.losses, record.moves, player, opponent)?;
+        }
+
+        return Ok(());
+    }
+
+    /// Indique si aucune partie n’a été comptabilisée
+    pub fn is_empty(&self) -> bool {
+        return self.records.is_empty();
+    }
+
+    /// Comptabilise une partie terminée
+    ///
+    /// # Arguments
+    ///
+    ///  * `first` : Le nom du premier joueur
+    ///  * `second` : Le nom du second joueur
+    ///  * `status` : L’état de fin de la partie, les identifiants de joueur 1 et 2 désignant
+    ///    respectivement `first` et `second`. Une partie en cours ou interrompue n’est pas comptabilisée.
+    ///  * `moves` : Le nombre de coups joués lors de la partie
+    pub fn add_game(&mut self, first : &str, second : &str, status : GameStatus, moves : usize) {
+        let (first_stats, second_stats) = match status {
+            GameStatus::Won(PlayerId::ONE) | GameStatus::Timeout(PlayerId::TWO) => (MatchStats { wins: 1, ..Default::default() }, MatchStats { losses: 1, ..Default::default() }),
+            GameStatus::Won(_) | GameStatus::Timeout(_) => (MatchStats { losses: 1, ..Default::default() }, MatchStats { wins: 1, ..Default::default() }),
+            GameStatus::Draw => (MatchStats { draws: 1, ..Default::default() }, MatchStats { draws: 1, ..Default::default() }),
+            GameStatus::InProgress | GameStatus::Aborted => return,
+        };
+
+        self.records.entry((String::from(first), String::from(second))).or_default().merge(&HeadToHead { stats: first_stats, moves });
+        self.records.entry((String::from(second), String::from(first))).or_default().merge(&HeadToHead { stats: second_stats, moves });
+    }
+
+    /// Comptabilise une partie enregistrée après l’avoir vérifiée (voir [`GameRecord::verify`])
+    ///
+    /// # Arguments
+    ///
+    ///  * `first` : Le nom du joueur 1 de l’enregistrement
+    ///  * `second` : Le nom du joueur 2 de l’enregistrement
+    ///  * `record` : L’enregistrement de la partie
+    ///  * `status` : L’état de fin de la partie (voir [`Statistics::add_game`])
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Voir [`GameRecord::verify`], une partie invalide n’étant pas comptabilisée.
+    pub fn add_record(&mut self, first : &str, second : &str, record : &GameRecord, status : GameStatus) -> Result<()> {
+        record.verify(status)?;
+
+        self.add_game(first, second, status, record.moves.len());
+
+        return Ok(());
+    }
+
+    /// Fourni le bilan d’un joueur contre un adversaire donné
+    pub fn head_to_head(&self, player : &str, opponent : &str) -> HeadToHead {
+        return self.records.get(&(String::from(player), String::from(opponent))).copied().unwrap_or_default();
+    }
+
+    /// Fourni le bilan d’un joueur contre l’ensemble de ses adversaires
+    pub fn player(&self, player : &str) -> HeadToHead {
+        let mut total = HeadToHead::default();
+
+        for record in self.opponents(player).map(|(_, record)| record) {
+            total.merge(record);
+        }
+
+        return total;
+    }
+
+    /// Fourni les noms des joueurs, par ordre alphabétique
+    pub fn players(&self) -> Vec<&str> {
+        let mut players : Vec<&str> = self.records.keys().map(|(player, _)| player.as_str()).collect();
+
+        players.dedup();
+
+        return players;
+    }
+
+    /// Parcourt les adversaires d’un joueur et son bilan contre chacun d’eux, par ordre
+    /// alphabétique
+    pub fn opponents<'a>(&'a self, player : &'a str) -> impl Iterator<Item = (&'a str, &'a HeadToHead)> + 'a {
+        return self.records.iter()
+            .filter(move |((name, _), _)| name == player)
+            .map(|((_, opponent), record)| (opponent.as_str(), record));
+    }
+}
+
+/// Les statistiques sont affichées sous forme de tableau donnant le bilan global de chaque joueur
+impl Display for Statistics {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        let players = self.players();
+        let width = players.iter().map(|name| name.chars().count()).fold(i18n::message("table.player").chars().count(), usize::max);
+
+        writeln!(f, "{:<width$} {:>7} {:>9} {:>5} {:>9} {:>13}", i18n::message("table.player"), i18n::message("table.games"), i18n::message("table.wins"),
+            i18n::message("table.draws"), i18n::message("table.losses"), i18n::message("table.length"), width = width)?;
+
+        for player in players {
+            let record = self.player(player);
+
+            writeln!(f, "{:<width$} {:>7} {:>9} {:>5} {:>9} {:>13.1}", player, record.stats.games(), record.stats.wins, record.stats.draws,
+                record.stats.losses, record.average_length().unwrap_or(0.0), width = width)?;
+        }
+
+        return Ok(());
+    }
+}
+
+/// Sérialisation des bilans sous forme de liste, les clés composées n’étant pas représentables
+/// dans tous les formats
+#[cfg(feature = "serde")]
+mod entries {
+    use super::HeadToHead;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::collections::BTreeMap;
+
+    pub fn serialize<S : Serializer>(records : &BTreeMap<(String, String), HeadToHead>, serializer : S) -> Result<S::Ok, S::Error> {
+        return serializer.collect_seq(records.iter());
+    }
+
+    pub fn deserialize<'de, D : Deserializer<'de>>(deserializer : D) -> Result<BTreeMap<(String, String), HeadToHead>, D::Error> {
+        let entries : Vec<((String, String), HeadToHead)> = Vec::deserialize(deserializer)?;
+
+        return Ok(entries.into_iter().collect());
+    }
+}
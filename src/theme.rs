@@ -0,0 +1,170 @@
+//! Thèmes d’affichage de la zone de jeu dans le terminal
+//!
+//! Un thème définit les symboles des jetons et des cases vides, leurs couleurs ainsi que la
+//! largeur des cases. Trois thèmes sont prédéfinis:
+//!  * `emoji` : Jetons 🟡 et 🔴 (🟢 et 🔵 pour les troisième et quatrième joueurs), affichage par
+//!    défaut de la zone de jeu
+//!  * `ascii` : Jetons `J` et `R` (`V` et `B`), pour les terminaux ne supportant pas l’Unicode
+//!  * `ansi-color` : Jetons `O` colorés par des séquences ANSI
+//!
+//! [`Theme::detect`] choisit le thème `emoji` lorsque la langue du terminal utilise l’UTF-8 et
+//! le thème `ascii` sinon.
+//!
+//! Les thèmes sont utilisés par les moteurs de rendu du module [`render`](super::render).
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::State;
+//! use connect_four::theme::Theme;
+//!
+//! let theme : Theme = "ansi-color".parse().unwrap();
+//!
+//! assert_eq!(theme.glyph(State::RedToken), "\x1b[31mO\x1b[0m");
+//! assert_eq!(Theme::ascii().glyph(State::YellowToken), "J");
+//! ```
+
+use super::connect_four::State;
+use std::str::FromStr;
+
+/// Thème d’affichage de la zone de jeu
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Theme {
+    /// Symbole des jetons jaunes
+    pub yellow : String,
+    /// Symbole des jetons rouges
+    pub red : String,
+    /// Symbole des jetons verts, utilisés par le troisième joueur
+    pub green : String,
+    /// Symbole des jetons bleus, utilisés par le quatrième joueur
+    pub blue : String,
+    /// Symbole des cases vides
+    pub empty : String,
+    /// Symbole des cases bloquées (voir [`AreaConfig::block`](crate::AreaConfig::block))
+    pub blocked : String,
+    /// Couleurs ANSI (paramètres SGR, par exemple `33` pour le jaune) des jetons jaunes, rouges,
+    /// verts puis bleus, `None` pour un affichage sans couleur
+    pub colors : Option<[u8; 4]>,
+    /// Largeur, en colonnes du terminal, de chacun des symboles
+    pub cell_width : usize,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        return Self::emoji();
+    }
+}
+
+impl Theme {
+    /// Noms des thèmes prédéfinis, acceptés par [`Theme::from_str`]
+    pub const PRESETS : [&'static str; 3] = ["emoji", "ascii", "ansi-color"];
+
+    /// Thème utilisant des emoji
+    pub fn emoji() -> Self {
+        return Self {
+            yellow: String::from("🟡"),
+            red: String::from("🔴"),
+            green: String::from("🟢"),
+            blue: String::from("🔵"),
+            empty: String::from("  "),
+            blocked: String::from("⬛"),
+            colors: None,
+            cell_width: 2,
+        };
+    }
+
+    /// Thème n’utilisant que des caractères ASCII
+    pub fn ascii() -> Self {
+        return Self {
+            yellow: String::from("J"),
+            red: String::from("R"),
+            green: String::from("V"),
+            blue: String::from("B"),
+            empty: String::from("."),
+            blocked: String::from("#"),
+            colors: None,
+            cell_width: 1,
+        };
+    }
+
+    /// Thème ASCII dont les jetons sont colorés par des séquences ANSI
+    pub fn ansi_color() -> Self {
+        return Self {
+            yellow: String::from("O"),
+            red: String::from("O"),
+            green: String::from("O"),
+            blue: String::from("O"),
+            empty: String::from("."),
+            blocked: String::from("#"),
+            colors: Some([33, 31, 32, 34]),
+            cell_width: 1,
+        };
+    }
+
+    /// Choisi le thème adapté au terminal
+    ///
+    /// Les variables d’environnement `LC_ALL`, `LC_CTYPE` puis `LANG` indiquent si le terminal
+    /// supporte l’UTF-8, auquel cas les emoji sont utilisés. Un terminal `dumb` ou dont la langue
+    /// n’utilise pas l’UTF-8 reçoit le thème ASCII.
+    pub fn detect() -> Self {
+        if std::env::var("TERM").is_ok_and(|term| term == "dumb") {
+            return Self::ascii();
+        }
+
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if locale.contains("utf-8") || locale.contains("utf8") {
+            return Self::emoji();
+        }
+
+        return Self::ascii();
+    }
+
+    /// Fourni le symbole d’un état de case, sans couleur
+    pub fn symbol(&self, state : State) -> &str {
+        return match state {
+            State::NoToken => &self.empty,
+            State::YellowToken => &self.yellow,
+            State::RedToken => &self.red,
+            State::GreenToken => &self.green,
+            State::BlueToken => &self.blue,
+        };
+    }
+
+    /// Fourni la couleur ANSI d’un état de case ou `None` s’il n’est pas coloré
+    pub fn color(&self, state : State) -> Option<u8> {
+        return match state {
+            State::NoToken => None,
+            State::YellowToken => self.colors.map(|colors| colors[0]),
+            State::RedToken => self.colors.map(|colors| colors[1]),
+            State::GreenToken => self.colors.map(|colors| colors[2]),
+            State::BlueToken => self.colors.map(|colors| colors[3]),
+        };
+    }
+
+    /// Fourni le symbole d’un état de case, coloré selon le thème
+    pub fn glyph(&self, state : State) -> String {
+        return match self.color(state) {
+            Some(color) => format!("\x1b[{}m{}\x1b[0m", color, self.symbol(state)),
+            None => String::from(self.symbol(state)),
+        };
+    }
+}
+
+impl FromStr for Theme {
+    type Err = ();
+
+    /// Fourni le thème prédéfini de nom donné (voir [`Theme::PRESETS`])
+    fn from_str(s : &str) -> Result<Self, Self::Err> {
+        return match s {
+            "emoji" => Ok(Self::emoji()),
+            "ascii" => Ok(Self::ascii()),
+            "ansi-color" => Ok(Self::ansi_color()),
+            _ => Err(()),
+        };
+    }
+}
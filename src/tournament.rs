@@ -0,0 +1,487 @@
+//! Tournois entre intelligences artificielles
+//!
+//! Un [`Tournament`] fait s’affronter un nombre quelconque de joueurs. Chaque joueur est inscrit
+//! une seule fois puis prend part à toutes ses parties, son état (table de transposition,
+//! bibliothèque d’ouvertures…) étant conservé d’une partie à l’autre.
+//!
+//! Deux formules sont proposées:
+//!  * le tournoi toutes rondes ([`Tournament::play_round_robin`]), où chaque joueur affronte tous
+//!    les autres
+//!  * le système suisse ([`Tournament::play_swiss`]), où chaque ronde oppose des joueurs de
+//!    scores proches, permettant de départager un grand nombre de joueurs en peu de parties
+//!
+//! Le joueur commençant une partie joue toujours les jetons jaunes. Une victoire rapporte un point
+//! et un match nul un demi-point. Les résultats sont résumés par un [`Crosstable`], les joueurs à
+//! égalité de points étant départagés par leur score Buchholz (somme des points de leurs
+//! adversaires).
+//!
+//! # Exemple
+//!
+//! ```rust
+//! use connect_four::tournament::Tournament;
+//! use connect_four::greedy_bot::GreedyBot;
+//! use connect_four::minimax_bot::MinimaxBot;
+//! use connect_four::random_bot::RandomBot;
+//!
+//! let mut tournament = Tournament::new();
+//!
+//! tournament.add_player(Box::new(RandomBot::new("Aléatoire")));
+//! tournament.add_player(Box::new(GreedyBot::new("Glouton")));
+//! tournament.add_player(Box::new(MinimaxBot::new("Minimax", 4)));
+//! tournament.set_double_round_robin(true);
+//!
+//! let crosstable = tournament.play_round_robin().unwrap();
+//!
+//! assert_eq!(crosstable.games(), 6);
+//! println!("{}", crosstable);
+//! ```
+
+use super::connect_four::{Action, Engine, GameRecord, GameResult, Interface, Area, AreaConfig, Player, PlayerId, State, PlayError, Error, Result};
+use super::i18n;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+/// Joueur inscrit au tournoi, partagé entre les gestionnaires de jeux de ses parties
+type SharedPlayer = Arc<Mutex<Box<dyn Interface + Send>>>;
+
+/// Interface transmettant les demandes du gestionnaire de jeux à un joueur inscrit au tournoi
+struct TournamentPlayer(SharedPlayer);
+
+impl TournamentPlayer {
+    fn lock(&self) -> std::result::Result<std::sync::MutexGuard<'_, Box<dyn Interface + Send>>, PlayError> {
+        return self.0.lock().map_err(|_| PlayError::Other(String::from("L’interface du joueur a paniqué")));
+    }
+}
+
+impl Interface for TournamentPlayer {
+    fn play(&mut self, area : &Area, token : State) -> std::result::Result<usize, PlayError> {
+        return self.lock()?.play(area, token);
+    }
+
+    fn name(&self) -> String {
+        return self.lock().map(|player| player.name()).unwrap_or_default();
+    }
+
+    fn on_opponent_thinking(&mut self, area : &Area, token : State) {
+        if let Ok(mut player) = self.lock() {
+            player.on_opponent_thinking(area, token);
+        }
+    }
+
+    fn on_opponent_move(&mut self, area : &Area, action : Action) {
+        if let Ok(mut player) = self.lock() {
+            player.on_opponent_move(area, action);
+        }
+    }
+
+    fn on_game_start(&mut self, area : &Area, player : &Player) {
+        if let Ok(mut interface) = self.lock() {
+            interface.on_game_start(area, player);
+        }
+    }
+
+    fn on_game_aborted(&mut self, area : &Area, reason : &str) {
+        if let Ok(mut interface) = self.lock() {
+            interface.on_game_aborted(area, reason);
+        }
+    }
+
+    fn nodes_searched(&self) -> Option<u64> {
+        return self.lock().ok().and_then(|interface| interface.nodes_searched());
+    }
+}
+
+/// Partie jouée lors d’un tournoi
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct TournamentGame {
+    /// Indice du joueur ayant commencé la partie avec les jetons jaunes
+    pub first : usize,
+    /// Indice de son adversaire
+    pub second : usize,
+    /// Résultat de la partie, le joueur 1 désignant `first` et le joueur 2 `second`
+    pub result : GameResult,
+    /// Enregistrement de la partie, le joueur 1 désignant `first` et le joueur 2 `second`
+    pub record : GameRecord,
+}
+
+impl TournamentGame {
+    /// Fourni le nombre de demi-points obtenus par le joueur donné lors de la partie
+    ///
+    /// # Arguments
+    ///
+    ///  * `player` : L’indice du joueur, devant être `first` ou `second`
+    pub fn half_points(&self, player : usize) -> usize {
+        let player_id = if player == self.first { PlayerId::ONE } else { PlayerId::TWO };
+
+        return match self.result {
+            GameResult::Winner { player_id: winner, .. } if winner == player_id => 2,
+            GameResult::Timeout { player_id: loser } if loser != player_id => 2,
+            GameResult::Draw => 1,
+            _ => 0,
+        };
+    }
+}
+
+/// Tournoi entre plusieurs joueurs
+pub struct Tournament {
+    #[doc(hidden)]
+    players : Vec<SharedPlayer>,
+    #[doc(hidden)]
+    config : AreaConfig,
+    #[doc(hidden)]
+    double : bool,
+    #[doc(hidden)]
+    games : Vec<TournamentGame>,
+    /// Joueurs exemptés d’une ronde du système suisse, une fois par ronde exemptée
+    #[doc(hidden)]
+    byes : Vec<usize>,
+}
+
+impl Default for Tournament {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tournament {
+    /// Crée un tournoi sans joueur, joué sur le plateau classique
+    pub fn new() -> Self {
+        Self {
+            players: Vec::new(),
+            config: AreaConfig::default(),
+            double: false,
+            games: Vec::new(),
+            byes: Vec::new(),
+        }
+    }
+
+    /// Inscrit un joueur au tournoi
+    ///
+    /// # Arguments
+    ///
+    ///  * `player` : L’interface vers le joueur
+    ///
+    /// # Retour
+    ///
+    /// L’indice du joueur, utilisé dans les résultats du tournoi
+    pub fn add_player(&mut self, player : Box<dyn Interface + Send>) -> usize {
+        self.players.push(Arc::new(Mutex::new(player)));
+
+        return self.players.len() - 1;
+    }
+
+    /// Fourni le nombre de joueurs inscrits
+    pub fn player_count(&self) -> usize {
+        return self.players.len();
+    }
+
+    /// Modifie la configuration de la zone de jeu des parties
+    ///
+    /// # Arguments
+    ///
+    ///  * `config` : Les dimensions du plateau et le nombre de jetons à aligner
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig` : L’une des dimensions ou le nombre de jetons à aligner est nul ou la
+    ///    zone de jeu est trop grande.
+    pub fn set_config(&mut self, config : AreaConfig) -> Result<()> {
+        config.validate()?;
+
+        self.config = config;
+
+        return Ok(());
+    }
+
+    /// Fait jouer chaque paire de joueurs deux fois, chacun commençant une partie
+    ///
+    /// Par défaut, chaque paire ne joue qu’une partie, le joueur commençant étant choisi afin
+    /// que chacun commence autant de parties que possible.
+    ///
+    /// # Arguments
+    ///
+    ///  * `double` : `true` afin de jouer deux parties par paire de joueurs
+    pub fn set_double_round_robin(&mut self, double : bool) {
+        self.double = double;
+    }
+
+    /// Fourni les parties jouées depuis la création du tournoi
+    pub fn games(&self) -> &[TournamentGame] {
+        return &self.games;
+    }
+
+    /// Fait s’affronter chaque joueur contre tous les autres
+    ///
+    /// # Retour
+    ///
+    /// Le tableau des résultats de l’ensemble des parties jouées par le tournoi ou la première
+    /// erreur survenue lors d’une partie (voir [`Engine::play`])
+    pub fn play_round_robin(&mut self) -> Result<Crosstable> {
+        let count = self.players.len();
+
+        for i in 0..count {
+            for j in (i + 1)..count {
+                let (first, second) = if (i + j) % 2 == 0 { (i, j) } else { (j, i) };
+
+                self.play_game(first, second)?;
+
+                if self.double {
+                    self.play_game(second, first)?;
+                }
+            }
+        }
+
+        return Ok(self.crosstable());
+    }
+
+    /// Construit le tableau des résultats de l’ensemble des parties jouées par le tournoi
+    pub fn crosstable(&self) -> Crosstable {
+        let names = self.players.iter().map(|player| player.lock().map(|p| p.name()).unwrap_or_default()).collect();
+
+        return Crosstable::new(names, &self.games, &self.byes);
+    }
+
+    /// Organise un tournoi suivant le système suisse
+    ///
+    /// À chaque ronde, les joueurs sont classés selon leurs points puis leur score Buchholz et
+    /// chacun affronte le joueur classé le plus près de lui qu’il n’a pas encore rencontré. Le
+    /// joueur ayant commencé le moins de parties commence la partie. Si le nombre de joueurs est
+    /// impair, le joueur le moins bien classé n’ayant pas encore été exempté ne joue pas la ronde
+    /// et reçoit un point.
+    ///
+    /// # Arguments
+    ///
+    ///  * `rounds` : Le nombre de rondes à jouer
+    ///
+    /// # Retour
+    ///
+    /// Le tableau des résultats de l’ensemble des parties jouées par le tournoi ou la première
+    /// erreur survenue lors d’une partie (voir [`Engine::play`])
+    pub fn play_swiss(&mut self, rounds : usize) -> Result<Crosstable> {
+        for _ in 0..rounds {
+            let mut ranking = self.crosstable().ranking();
+
+            if ranking.len() % 2 == 1 {
+                let bye = ranking.iter().rposition(|player| !self.byes.contains(player)).unwrap_or(ranking.len() - 1);
+
+                self.byes.push(ranking.remove(bye));
+            }
+
+            let pairings = swiss_pairings(&ranking, &self.games).unwrap_or_else(|| {
+                ranking.chunks(2).map(|pair| (pair[0], pair[1])).collect()
+            });
+
+            for (a, b) in pairings {
+                let starts = |player : usize| self.games.iter().filter(|game| game.first == player).count();
+
+                if starts(a) <= starts(b) {
+                    self.play_game(a, b)?;
+                } else {
+                    self.play_game(b, a)?;
+                }
+            }
+        }
+
+        return Ok(self.crosstable());
+    }
+
+    /// Fait jouer une partie entre deux joueurs puis l’enregistre
+    ///
+    /// # Arguments
+    ///
+    ///  * `first` : L’indice du joueur commençant la partie avec les jetons jaunes
+    ///  * `second` : L’indice de son adversaire
+    ///
+    /// # Retour
+    ///
+    /// Le résultat de la partie, le joueur 1 désignant `first` et le joueur 2 `second`
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidPlayerId` : L’un des indices ne correspond à aucun joueur inscrit ou les deux
+    ///    indices sont identiques.
+    ///  * Toute erreur émise par [`Engine::play`]
+    pub fn play_game(&mut self, first : usize, second : usize) -> Result<GameResult> {
+        for player in [first, second] {
+            if player >= self.players.len() || first == second {
+                return Err(Error::InvalidPlayerId(player));
+            }
+        }
+
+        let mut engine = Engine::with_config(
+            Box::new(TournamentPlayer(Arc::clone(&self.players[first]))),
+            Box::new(TournamentPlayer(Arc::clone(&self.players[second]))),
+            self.config,
+        )?;
+
+        engine.set_colors(State::YellowToken)?;
+
+        let result = engine.play()?;
+
+        self.games.push(TournamentGame { first, second, result: result.clone(), record: engine.record() });
+
+        return Ok(result);
+    }
+}
+
+/// Apparie les joueurs, donnés du mieux classé au moins bien classé, sans qu’aucune paire ne se
+/// soit déjà affrontée. Retourne `None` si aucun appariement ne le permet.
+fn swiss_pairings(players : &[usize], games : &[TournamentGame]) -> Option<Vec<(usize, usize)>> {
+    let (first, others) = match players.split_first() {
+        Some(split) => split,
+        None => return Some(Vec::new()),
+    };
+
+    let met = |opponent : usize| games.iter().any(|game| {
+        (game.first == *first && game.second == opponent) || (game.first == opponent && game.second == *first)
+    });
+
+    for (i, opponent) in others.iter().enumerate() {
+        if met(*opponent) {
+            continue;
+        }
+
+        let remaining : Vec<usize> = others.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, p)| *p).collect();
+
+        if let Some(mut pairings) = swiss_pairings(&remaining, games) {
+            pairings.insert(0, (*first, *opponent));
+
+            return Some(pairings);
+        }
+    }
+
+    return None;
+}
+
+/// Tableau des résultats d’un tournoi
+///
+/// Les joueurs sont classés par nombre de points décroissant puis par score Buchholz décroissant.
+/// L’affichage présente, pour chaque joueur, les points obtenus contre chacun de ses adversaires.
+#[derive(Clone, Debug)]
+pub struct Crosstable {
+    #[doc(hidden)]
+    names : Vec<String>,
+    /// Demi-points obtenus par chaque joueur contre chaque adversaire
+    #[doc(hidden)]
+    half_points : Vec<Vec<usize>>,
+    /// Nombre de parties jouées entre chaque paire de joueurs
+    #[doc(hidden)]
+    encounters : Vec<Vec<usize>>,
+    /// Nombre de rondes dont chaque joueur a été exempté
+    #[doc(hidden)]
+    byes : Vec<usize>,
+}
+
+impl Crosstable {
+    /// Construit le tableau à partir des parties jouées
+    ///
+    /// # Arguments
+    ///
+    ///  * `names` : Les noms des joueurs, par indice
+    ///  * `games` : Les parties jouées
+    ///  * `byes` : Les joueurs exemptés d’une ronde, une fois par ronde exemptée, chaque
+    ///    exemption rapportant un point
+    pub fn new(names : Vec<String>, games : &[TournamentGame], byes : &[usize]) -> Self {
+        let count = names.len();
+        let mut half_points = vec![vec![0; count]; count];
+        let mut encounters = vec![vec![0; count]; count];
+        let mut bye_counts = vec![0; count];
+
+        for player in byes {
+            bye_counts[*player] += 1;
+        }
+
+        for game in games {
+            half_points[game.first][game.second] += game.half_points(game.first);
+            half_points[game.second][game.first] += game.half_points(game.second);
+            encounters[game.first][game.second] += 1;
+            encounters[game.second][game.first] += 1;
+        }
+
+        Self { names, half_points, encounters, byes: bye_counts }
+    }
+
+    /// Fourni le nombre de parties jouées
+    pub fn games(&self) -> usize {
+        return self.encounters.iter().flatten().sum::<usize>() / 2;
+    }
+
+    /// Fourni le nom du joueur donné
+    pub fn name(&self, player : usize) -> &str {
+        return &self.names[player];
+    }
+
+    /// Fourni le nombre de points obtenus par le joueur donné, exemptions comprises
+    pub fn points(&self, player : usize) -> f64 {
+        return self.total_half_points(player) as f64 / 2.0;
+    }
+
+    /// Fourni le score Buchholz du joueur donné: la somme des points de chacun de ses
+    /// adversaires, comptés autant de fois qu’il les a affrontés
+    pub fn buchholz(&self, player : usize) -> f64 {
+        return self.buchholz_half_points(player) as f64 / 2.0;
+    }
+
+    /// Fourni le nombre de points obtenus par un joueur contre un adversaire
+    ///
+    /// # Retour
+    ///
+    /// Les points obtenus ou `None` si les joueurs ne se sont pas affrontés
+    pub fn points_against(&self, player : usize, opponent : usize) -> Option<f64> {
+        if self.encounters[player][opponent] == 0 {
+            return None;
+        }
+
+        return Some(self.half_points[player][opponent] as f64 / 2.0);
+    }
+
+    /// Fourni les indices des joueurs, du mieux classé au moins bien classé
+    ///
+    /// À points égaux, les joueurs restent dans l’ordre de leur inscription.
+    pub fn ranking(&self) -> Vec<usize> {
+        let mut ranking : Vec<usize> = (0..self.names.len()).collect();
+
+        ranking.sort_by_key(|player| std::cmp::Reverse((self.total_half_points(*player), self.buchholz_half_points(*player))));
+
+        return ranking;
+    }
+
+    fn total_half_points(&self, player : usize) -> usize {
+        return self.half_points[player].iter().sum::<usize>() + 2 * self.byes[player];
+    }
+
+    fn buchholz_half_points(&self, player : usize) -> usize {
+        return self.encounters[player].iter().enumerate().map(|(opponent, count)| count * self.total_half_points(opponent)).sum();
+    }
+}
+
+impl Display for Crosstable {
+    fn fmt(&self, f : &mut Formatter<'_>) -> std::fmt::Result {
+        let ranking = self.ranking();
+        let width = self.names.iter().map(|name| name.chars().count()).fold(i18n::message("table.player").chars().count(), usize::max);
+
+        write!(f, "{:>3}  {:<width$}", "#", i18n::message("table.player"), width = width)?;
+
+        for rank in 1..=ranking.len() {
+            write!(f, " {:>4}", rank)?;
+        }
+
+        writeln!(f, " {:>6} {:>8}", "Points", "Buchholz")?;
+
+        for (rank, player) in ranking.iter().enumerate() {
+            write!(f, "{:>3}  {:<width$}", rank + 1, self.names[*player], width = width)?;
+
+            for opponent in ranking.iter() {
+                match self.points_against(*player, *opponent) {
+                    Some(points) => write!(f, " {:>4}", points)?,
+                    None if opponent == player => write!(f, " {:>4}", "X")?,
+                    None => write!(f, " {:>4}", "")?,
+                }
+            }
+
+            writeln!(f, " {:>6} {:>8}", self.points(*player), self.buchholz(*player))?;
+        }
+
+        return Ok(());
+    }
+}
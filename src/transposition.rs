@@ -0,0 +1,289 @@
+//! Table de transposition partagée par les algorithmes de recherche
+//!
+//! Une même position pouvant être atteinte par plusieurs ordres de coups, la table conserve le
+//! résultat des positions déjà évaluées, indexées par leur clé de Zobrist (voir
+//! [`Area::zobrist_key`]). Sa taille est fixée à sa création afin de borner la mémoire utilisée.
+//!
+//! Lorsque deux positions se disputent un même emplacement, la position évaluée le plus
+//! profondément est conservée, sauf si elle provient d’une recherche précédente (voir
+//! [`TranspositionTable::new_search`]).
+//!
+//! La [`SharedTranspositionTable`] offre le même service à plusieurs fils d’exécution à la fois,
+//! sans verrou: chaque emplacement est formé de deux entiers atomiques dont la cohérence est
+//! vérifiée à la lecture.
+//!
+//! [`Area::zobrist_key`]: crate::connect_four::Area::zobrist_key
+
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+/// Bit signalant un emplacement occupé de la [`SharedTranspositionTable`]
+const OCCUPIED_BIT : u64 = 1 << 63;
+
+/// Valeur de la meilleure colonne signalant son absence dans la [`SharedTranspositionTable`]
+const NO_MOVE : u64 = 0xFF;
+
+/// Nature du score enregistré pour une position
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Bound {
+    /// Le score est exact
+    Exact,
+    /// Le score réel est supérieur ou égal au score enregistré
+    Lower,
+    /// Le score réel est inférieur ou égal au score enregistré
+    Upper,
+}
+
+/// Résultat de l’évaluation d’une position
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Entry {
+    /// Clé de Zobrist de la position
+    pub key : u64,
+    /// Profondeur à laquelle la position a été explorée
+    pub depth : usize,
+    /// Score de la position du point de vue du joueur devant jouer
+    pub score : i32,
+    /// Nature du score
+    pub bound : Bound,
+    /// Meilleure colonne trouvée, à explorer en premier lors des recherches suivantes
+    pub best_move : Option<usize>,
+}
+
+/// Table de transposition à taille fixe
+pub struct TranspositionTable {
+    #[doc(hidden)]
+    entries : Vec<Option<(Entry, u8)>>,
+    #[doc(hidden)]
+    generation : u8,
+}
+
+impl TranspositionTable {
+    /// Crée une table pouvant contenir le nombre de positions donné
+    ///
+    /// # Arguments
+    ///
+    ///  * `capacity` : Le nombre d’emplacements de la table. Une capacité nulle est ramenée à un.
+    pub fn new(capacity : usize) -> Self {
+        Self {
+            entries: vec![None; usize::max(capacity, 1)],
+            generation: 0,
+        }
+    }
+
+    /// Crée une table occupant au plus la quantité de mémoire donnée
+    ///
+    /// # Arguments
+    ///
+    ///  * `bytes` : La mémoire allouable à la table, en octets
+    pub fn with_memory(bytes : usize) -> Self {
+        return Self::new(bytes / std::mem::size_of::<Option<(Entry, u8)>>());
+    }
+
+    /// Fourni le nombre d’emplacements de la table
+    pub fn capacity(&self) -> usize {
+        return self.entries.len();
+    }
+
+    /// Signale le début d’une nouvelle recherche
+    ///
+    /// Les positions enregistrées restent consultables mais peuvent être remplacées par toute
+    /// nouvelle position, quelle que soit sa profondeur.
+    pub fn new_search(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Vide la table
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|e| *e = None);
+    }
+
+    /// Recherche une position dans la table
+    ///
+    /// # Arguments
+    ///
+    ///  * `key` : La clé de Zobrist de la position
+    ///
+    /// # Retour
+    ///
+    /// Le résultat enregistré pour la position ou `None` si elle n’a pas été évaluée ou si son
+    /// résultat a été remplacé
+    pub fn probe(&self, key : u64) -> Option<&Entry> {
+        return match &self.entries[self.index(key)] {
+            Some((entry, _)) if entry.key == key => Some(entry),
+            _ => None,
+        };
+    }
+
+    /// Enregistre le résultat de l’évaluation d’une position
+    ///
+    /// Le résultat n’est pas enregistré si l’emplacement est occupé par une autre position de la
+    /// recherche en cours explorée plus profondément.
+    ///
+    /// # Arguments
+    ///
+    ///  * `entry` : Le résultat à enregistrer
+    pub fn store(&mut self, entry : Entry) {
+        let index = self.index(entry.key);
+        let generation = self.generation;
+
+        let replace = match &self.entries[index] {
+            Some((old, old_generation)) => {
+                old.key == entry.key || *old_generation != generation || entry.depth >= old.depth
+            },
+            None => true,
+        };
+
+        if replace {
+            self.entries[index] = Some((entry, generation));
+        }
+    }
+
+    fn index(&self, key : u64) -> usize {
+        return (key % self.entries.len() as u64) as usize;
+    }
+}
+
+/// Table de transposition à taille fixe partageable entre plusieurs fils d’exécution
+///
+/// Chaque emplacement conserve la clé de la position combinée (par ou exclusif) au résultat
+/// encodé sur 64 bits. Une lecture concurrente d’une écriture fournit ainsi une clé incohérente
+/// et le résultat est ignoré, sans qu’aucun verrou ne soit nécessaire.
+///
+/// La profondeur enregistrée est limitée à 255 et la meilleure colonne à 254.
+pub struct SharedTranspositionTable {
+    #[doc(hidden)]
+    entries : Vec<(AtomicU64, AtomicU64)>,
+    #[doc(hidden)]
+    generation : AtomicU8,
+}
+
+impl SharedTranspositionTable {
+    /// Crée une table pouvant contenir le nombre de positions donné
+    ///
+    /// # Arguments
+    ///
+    ///  * `capacity` : Le nombre d’emplacements de la table. Une capacité nulle est ramenée à un.
+    pub fn new(capacity : usize) -> Self {
+        Self {
+            entries: (0..usize::max(capacity, 1)).map(|_| (AtomicU64::new(0), AtomicU64::new(0))).collect(),
+            generation: AtomicU8::new(0),
+        }
+    }
+
+    /// Crée une table occupant au plus la quantité de mémoire donnée
+    ///
+    /// # Arguments
+    ///
+    ///  * `bytes` : La mémoire allouable à la table, en octets
+    pub fn with_memory(bytes : usize) -> Self {
+        return Self::new(bytes / std::mem::size_of::<(AtomicU64, AtomicU64)>());
+    }
+
+    /// Fourni le nombre d’emplacements de la table
+    pub fn capacity(&self) -> usize {
+        return self.entries.len();
+    }
+
+    /// Signale le début d’une nouvelle recherche
+    ///
+    /// Voir [`TranspositionTable::new_search`].
+    pub fn new_search(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Vide la table
+    pub fn clear(&self) {
+        for (key, data) in self.entries.iter() {
+            key.store(0, Ordering::Relaxed);
+            data.store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// Recherche une position dans la table
+    ///
+    /// # Arguments
+    ///
+    ///  * `key` : La clé de Zobrist de la position
+    ///
+    /// # Retour
+    ///
+    /// Le résultat enregistré pour la position ou `None` si elle n’a pas été évaluée, si son
+    /// résultat a été remplacé ou s’il est en cours d’écriture
+    pub fn probe(&self, key : u64) -> Option<Entry> {
+        let (stored_key, data) = &self.entries[self.index(key)];
+        let data = data.load(Ordering::Relaxed);
+
+        if data & OCCUPIED_BIT == 0 || stored_key.load(Ordering::Relaxed) ^ data != key {
+            return None;
+        }
+
+        return Some(decode(key, data).0);
+    }
+
+    /// Enregistre le résultat de l’évaluation d’une position
+    ///
+    /// Voir [`TranspositionTable::store`].
+    ///
+    /// # Arguments
+    ///
+    ///  * `entry` : Le résultat à enregistrer
+    pub fn store(&self, entry : Entry) {
+        let (stored_key, stored_data) = &self.entries[self.index(entry.key)];
+        let generation = self.generation.load(Ordering::Relaxed);
+        let old_data = stored_data.load(Ordering::Relaxed);
+
+        if old_data & OCCUPIED_BIT != 0 {
+            let old_key = stored_key.load(Ordering::Relaxed) ^ old_data;
+            let (old, old_generation) = decode(old_key, old_data);
+
+            if old.key != entry.key && old_generation == generation && entry.depth < old.depth {
+                return;
+            }
+        }
+
+        let data = encode(&entry, generation);
+
+        stored_key.store(entry.key ^ data, Ordering::Relaxed);
+        stored_data.store(data, Ordering::Relaxed);
+    }
+
+    fn index(&self, key : u64) -> usize {
+        return (key % self.entries.len() as u64) as usize;
+    }
+}
+
+/// Encode un résultat et sa génération sur 64 bits, la clé étant conservée à part
+fn encode(entry : &Entry, generation : u8) -> u64 {
+    let bound = match entry.bound {
+        Bound::Exact => 0,
+        Bound::Lower => 1,
+        Bound::Upper => 2,
+    };
+    let best_move = entry.best_move.map_or(NO_MOVE, |col| u64::min(col as u64, NO_MOVE - 1));
+
+    return OCCUPIED_BIT
+        | (entry.score as u32 as u64)
+        | (usize::min(entry.depth, 0xFF) as u64) << 32
+        | bound << 40
+        | best_move << 42
+        | (generation as u64) << 50;
+}
+
+/// Décode un résultat encodé par [`encode`], fourni avec sa génération
+fn decode(key : u64, data : u64) -> (Entry, u8) {
+    let bound = match (data >> 40) & 0b11 {
+        0 => Bound::Exact,
+        1 => Bound::Lower,
+        _ => Bound::Upper,
+    };
+    let best_move = (data >> 42) & 0xFF;
+
+    let entry = Entry {
+        key,
+        depth: ((data >> 32) & 0xFF) as usize,
+        score: data as u32 as i32,
+        bound,
+        best_move: if best_move == NO_MOVE { None } else { Some(best_move as usize) },
+    };
+
+    return (entry, (data >> 50) as u8);
+}
@@ -0,0 +1,437 @@
+//! Interface plein écran dans le terminal
+//!
+//! Les joueurs choisissent leur colonne en déplaçant un curseur avec les flèches gauche et droite
+//! (ou en saisissant son numéro) puis jouent avec Entrée. À la souris, la colonne survolée est
+//! mise en évidence et un clic y joue le coup. Des panneaux latéraux affichent les pendules,
+//! l’évaluation de la position et l’historique des coups. Échap ou `q` quitte la partie, le
+//! joueur renvoyant alors `PlayError::Disconnected`.
+//!
+//! L’écran est partagé entre les joueurs humains d’une partie, créés par [`Tui::player`]. Le
+//! gestionnaire de jeux est mené coup par coup via [`Engine::step`], l’écran étant mis à jour
+//! avant chaque coup par [`Tui::update`] afin d’afficher également les coups des intelligences
+//! artificielles.
+//!
+//! Ce module n’est disponible qu’avec la fonctionnalité `tui`.
+//!
+//! # Exemple
+//!
+//! ```rust,no_run
+//! use connect_four::{Engine, GameStatus};
+//! use connect_four::minimax_bot::MinimaxBot;
+//! use connect_four::tui::Tui;
+//!
+//! let tui = Tui::new();
+//! let mut game = Engine::new(Box::new(tui.player("Joueur")), Box::new(MinimaxBot::new("Robot", 6)));
+//!
+//! tui.start().unwrap();
+//!
+//! let status = loop {
+//!     tui.update(&game);
+//!
+//!     match game.step() {
+//!         Ok(GameStatus::InProgress) => {},
+//!         status => break status,
+//!     }
+//! };
+//!
+//! tui.stop().unwrap();
+//! ```
+
+use super::analysis::{Analyzer, Score};
+use super::connect_four::{Area, Engine, GameStatus, Interface, PlayError, PlayerId, State};
+use super::minimax_bot::MinimaxBot;
+use super::render::column_label;
+use super::{i18n, tr};
+use ratatui::crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::crossterm::execute;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Profondeur d’exploration de l’intelligence artificielle évaluant la position
+const EVALUATION_DEPTH : usize = 8;
+
+/// Intervalle de rafraîchissement de l’écran pendant la réflexion d’un joueur, afin que les
+/// pendules défilent
+const REFRESH_INTERVAL : Duration = Duration::from_millis(200);
+
+/// Contenu de l’écran, mis à jour avant chaque coup
+struct Screen {
+    terminal : Option<DefaultTerminal>,
+    area : Area,
+    names : [String; 2],
+    tokens : [State; 2],
+    /// Temps restant à chaque joueur au début du coup en cours
+    clocks : [Option<Duration>; 2],
+    /// Joueur devant jouer (1 ou 2), 0 si la partie est terminée
+    current : usize,
+    turn_started : Instant,
+    history : Vec<(State, usize)>,
+    evaluation : String,
+    advisor : MinimaxBot,
+    /// Emplacement de la zone de jeu lors du dernier affichage, pour la saisie à la souris
+    board : Rect,
+}
+
+impl Screen {
+    /// Temps restant au joueur donné, en tenant compte de la réflexion en cours
+    fn remaining(&self, player_id : usize) -> Option<Duration> {
+        let remaining = self.clocks[player_id - 1]?;
+
+        if player_id == self.current {
+            return Some(remaining.saturating_sub(self.turn_started.elapsed()));
+        }
+
+        return Some(remaining);
+    }
+
+    /// Affiche l’écran, le curseur désignant la colonne choisie par le joueur humain
+    fn draw(&mut self, cursor : Option<usize>, status : &str) -> io::Result<()> {
+        let mut terminal = match self.terminal.take() {
+            Some(terminal) => terminal,
+            None => return Ok(()),
+        };
+
+        let mut board = self.board;
+        let result = terminal.draw(|frame| board = self.render(frame, cursor, status)).map(|_| ());
+
+        self.terminal = Some(terminal);
+        self.board = board;
+
+        return result;
+    }
+
+    /// Fourni la colonne affichée à la position du terminal donnée
+    fn column_at(&self, x : u16, y : u16) -> Option<usize> {
+        let inner = self.board.inner(Margin::new(1, 1));
+        let cols = self.area.config().cols;
+
+        if !inner.contains((x, y).into()) {
+            return None;
+        }
+
+        return Some(((x - inner.x) / 3) as usize).filter(|col| *col < cols);
+    }
+
+    /// Affiche l’écran et fourni l’emplacement de la zone de jeu
+    fn render(&self, frame : &mut Frame<'_>, cursor : Option<usize>, status : &str) -> Rect {
+        let cols = self.area.config().cols as u16;
+        let [main, help] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [board, side] = Layout::horizontal([Constraint::Length(cols * 3 + 2), Constraint::Min(24)]).areas(main);
+        let [players, evaluation, history] = Layout::vertical([Constraint::Length(4), Constraint::Length(3), Constraint::Min(0)]).areas(side);
+
+        self.render_board(frame, board, cursor);
+        self.render_players(frame, players);
+
+        frame.render_widget(Paragraph::new(self.evaluation.as_str()).block(Block::bordered().title(i18n::message("tui.evaluation"))), evaluation);
+
+        let moves : Vec<ListItem<'_>> = self.history.iter().enumerate().rev().map(|(i, (token, column))| {
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:>3}. ", i + 1)),
+                Span::styled("●", token_style(*token)),
+                Span::raw(format!(" colonne {}", column)),
+            ]))
+        }).collect();
+
+        frame.render_widget(List::new(moves).block(Block::bordered().title(i18n::message("tui.history"))), history);
+        frame.render_widget(Paragraph::new(status).style(Style::new().add_modifier(Modifier::DIM)), help);
+
+        return board;
+    }
+
+    fn render_board(&self, frame : &mut Frame<'_>, area : Rect, cursor : Option<usize>) {
+        let config = self.area.config();
+        let highlight = Style::new().bg(Color::DarkGray);
+        let mut lines = Vec::with_capacity(config.rows + 2);
+
+        lines.push(Line::from((0..config.cols).map(|col| {
+            Span::raw(if Some(col) == cursor { " ▼ " } else { "   " })
+        }).collect::<Vec<_>>()));
+
+        for row in (0..config.rows).rev() {
+            lines.push(Line::from((0..config.cols).map(|col| {
+                let mut style = if Some(col) == cursor { highlight } else { Style::new() };
+
+                // Mise en évidence de l’alignement gagnant puis du dernier jeton ajouté
+                if self.area.winning_cells().contains(&(col, row)) {
+                    style = style.add_modifier(Modifier::REVERSED);
+                } else if self.area.last_move().is_some_and(|(c, r, _)| (c, r) == (col, row)) {
+                    style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+                }
+
+                match self.area[(col, row)] {
+                    State::NoToken if config.is_blocked(col, row) => Span::styled(" ■ ", style.fg(Color::DarkGray)),
+                    State::NoToken => Span::styled(" · ", style.fg(Color::DarkGray)),
+                    token => Span::styled(" ● ", style.patch(token_style(token))),
+                }
+            }).collect::<Vec<_>>()));
+        }
+
+        lines.push(Line::from((0..config.cols).map(|col| Span::raw(format!("{:^3}", column_label(col)))).collect::<Vec<_>>()));
+
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(i18n::message("tui.title"))), area);
+    }
+
+    fn render_players(&self, frame : &mut Frame<'_>, area : Rect) {
+        let lines : Vec<Line<'_>> = (1..=2).map(|player_id| {
+            let clock = self.remaining(player_id).map(format_clock).unwrap_or_default();
+            let marker = if player_id == self.current { "▶ " } else { "  " };
+
+            Line::from(vec![
+                Span::raw(marker),
+                Span::styled("● ", token_style(self.tokens[player_id - 1])),
+                Span::raw(format!("{:<16} {}", self.names[player_id - 1], clock)),
+            ])
+        }).collect();
+
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(i18n::message("tui.players"))), area);
+    }
+}
+
+/// Style d’affichage d’un jeton
+fn token_style(token : State) -> Style {
+    return match token {
+        State::YellowToken => Style::new().fg(Color::Yellow),
+        State::RedToken => Style::new().fg(Color::Red),
+        State::GreenToken => Style::new().fg(Color::Green),
+        State::BlueToken => Style::new().fg(Color::Blue),
+        State::NoToken => Style::new(),
+    };
+}
+
+/// Formate un temps de réflexion restant en minutes et secondes
+fn format_clock(remaining : Duration) -> String {
+    let secs = remaining.as_millis().div_ceil(1000);
+
+    return format!("{:02}:{:02}", secs / 60, secs % 60);
+}
+
+/// Écran partagé entre les joueurs humains d’une partie
+#[derive(Clone)]
+pub struct Tui {
+    #[doc(hidden)]
+    screen : Arc<Mutex<Screen>>,
+}
+
+impl Default for Tui {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Tui {
+    /// Crée l’écran, sans prendre le contrôle du terminal (voir [`Tui::start`])
+    pub fn new() -> Self {
+        return Self {
+            screen: Arc::new(Mutex::new(Screen {
+                terminal: None,
+                area: Area::new(),
+                names: [String::new(), String::new()],
+                tokens: [State::YellowToken, State::RedToken],
+                clocks: [None; 2],
+                current: 0,
+                turn_started: Instant::now(),
+                history: Vec::new(),
+                evaluation: String::new(),
+                advisor: MinimaxBot::new("Évaluation", EVALUATION_DEPTH),
+                board: Rect::default(),
+            })),
+        };
+    }
+
+    fn lock(&self) -> io::Result<MutexGuard<'_, Screen>> {
+        return self.screen.lock().map_err(|_| io::Error::other("L’affichage a paniqué"));
+    }
+
+    /// Crée un joueur humain jouant sur cet écran
+    ///
+    /// # Arguments
+    ///
+    ///  * `name` : Le nom du joueur
+    pub fn player(&self, name : &str) -> TuiPlayer {
+        return TuiPlayer {
+            name: String::from(name),
+            tui: self.clone(),
+            cursor: None,
+        };
+    }
+
+    /// Prend le contrôle du terminal: écran alternatif, saisie des touches une à une et capture de
+    /// la souris
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur survenant lors de la configuration du terminal
+    pub fn start(&self) -> io::Result<()> {
+        let mut screen = self.lock()?;
+
+        if screen.terminal.is_none() {
+            screen.terminal = Some(ratatui::try_init()?);
+
+            execute!(io::stdout(), EnableMouseCapture)?;
+        }
+
+        return Ok(());
+    }
+
+    /// Rend le contrôle du terminal, qui retrouve son état initial
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    /// Toute erreur survenant lors de la restauration du terminal
+    pub fn stop(&self) -> io::Result<()> {
+        if self.lock()?.terminal.take().is_some() {
+            execute!(io::stdout(), DisableMouseCapture)?;
+
+            ratatui::try_restore()?;
+        }
+
+        return Ok(());
+    }
+
+    /// Met à jour l’écran depuis le gestionnaire de jeux puis l’affiche
+    ///
+    /// Appelée avant chaque coup, elle relève la position, les pendules, l’historique et évalue
+    /// la position pour le joueur devant jouer.
+    pub fn update(&self, engine : &Engine) {
+        let mut screen = match self.lock() {
+            Ok(screen) => screen,
+            Err(_) => return,
+        };
+
+        let area = engine.get_disposition().clone();
+        let clocks = engine.clocks();
+
+        for player_id in [PlayerId::ONE, PlayerId::TWO] {
+            screen.names[player_id.index()] = engine.player_name(player_id).unwrap_or_default();
+            screen.tokens[player_id.index()] = engine.player_token(player_id).unwrap_or(State::NoToken);
+            screen.clocks[player_id.index()] = clocks.as_ref().map(|clocks| clocks[player_id.index()]);
+        }
+
+        let in_progress = engine.status() == GameStatus::InProgress;
+        let to_move = if in_progress { engine.player_token(engine.current_player()).ok() } else { None };
+
+        screen.current = if in_progress { engine.current_player().get() } else { 0 };
+        screen.turn_started = Instant::now();
+        screen.history = engine.history().iter().map(|m| (m.token, m.column)).collect();
+        screen.evaluation = match to_move {
+            Some(token) => evaluation_text(&mut screen.advisor, &area, token),
+            None => tr!("tui.finished"),
+        };
+        screen.area = area;
+
+        let status = match screen.current {
+            0 => String::new(),
+            current => tr!("tui.thinking", screen.names[current - 1]),
+        };
+
+        let _ = screen.draw(None, &status);
+    }
+}
+
+/// Décrit l’évaluation de la position du point de vue du jeton devant jouer
+fn evaluation_text(advisor : &mut MinimaxBot, area : &Area, token : State) -> String {
+    let opponent = if token == State::YellowToken { State::RedToken } else { State::YellowToken };
+
+    return match advisor.best(area, token) {
+        Ok(Some(best)) => match best.score {
+            Score::Win(n) => tr!("tui.wins_in", format!("{:#}", token), n),
+            Score::Loss(n) => tr!("tui.wins_in", format!("{:#}", opponent), n),
+            Score::Draw => tr!("result.draw"),
+            Score::Heuristic(score) => {
+                let leader = if score >= 0 { token } else { opponent };
+
+                tr!("tui.advantage", format!("{:.1}", score.abs() as f64 / 100.0), format!("{:#}", leader))
+            },
+        },
+        Ok(None) => String::new(),
+        Err(e) => e.to_string(),
+    };
+}
+
+/// Joueur humain jouant sur l’écran plein terminal
+pub struct TuiPlayer {
+    #[doc(hidden)]
+    name : String,
+    #[doc(hidden)]
+    tui : Tui,
+    /// Dernière colonne désignée par le curseur
+    #[doc(hidden)]
+    cursor : Option<usize>,
+}
+
+impl Interface for TuiPlayer {
+    fn play(&mut self, area : &Area, token : State) -> Result<usize, PlayError> {
+        let columns = area.get_available_columns();
+        let center = area.config().cols / 2;
+        let mut cursor = match self.cursor.filter(|col| columns.contains(col)) {
+            Some(col) => col,
+            None => *columns.iter().min_by_key(|col| col.abs_diff(center)).ok_or_else(|| PlayError::Other(tr!("tui.no_column")))?,
+        };
+        let status = tr!("tui.prompt", self.name, format!("{:#}", token));
+
+        loop {
+            {
+                let mut screen = self.tui.lock()?;
+
+                screen.area = area.clone();
+                screen.draw(Some(cursor), &status)?;
+
+                // Le moteur constate le dépassement du temps une fois le coup joué
+                if screen.remaining(screen.current).is_some_and(|remaining| remaining.is_zero()) {
+                    return Ok(cursor);
+                }
+            }
+
+            if !event::poll(REFRESH_INTERVAL)? {
+                continue;
+            }
+
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key,
+                Event::Mouse(mouse) => {
+                    let column = self.tui.lock()?.column_at(mouse.column, mouse.row).filter(|col| columns.contains(col));
+
+                    match (mouse.kind, column) {
+                        (MouseEventKind::Moved, Some(column)) => cursor = column,
+                        (MouseEventKind::Down(MouseButton::Left), Some(column)) => {
+                            self.cursor = Some(column);
+
+                            return Ok(column);
+                        },
+                        _ => {},
+                    }
+
+                    continue;
+                },
+                _ => continue,
+            };
+
+            let position = columns.iter().position(|col| *col == cursor).unwrap_or(0);
+
+            match key.code {
+                KeyCode::Left => cursor = columns[position.saturating_sub(1)],
+                KeyCode::Right => cursor = columns[usize::min(position + 1, columns.len() - 1)],
+                KeyCode::Char(c) if c.to_digit(10).is_some_and(|col| columns.contains(&(col as usize))) => {
+                    cursor = c.to_digit(10).unwrap_or_default() as usize;
+                },
+                KeyCode::Enter | KeyCode::Down | KeyCode::Char(' ') => {
+                    self.cursor = Some(cursor);
+
+                    return Ok(cursor);
+                },
+                KeyCode::Esc | KeyCode::Char('q') => return Err(PlayError::Disconnected),
+                _ => {},
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        return self.name.clone();
+    }
+}
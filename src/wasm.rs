@@ -0,0 +1,179 @@
+//! Liaisons JavaScript du moteur de jeu via `wasm-bindgen`
+//!
+//! Ce module expose une partie de puissance 4 à une interface web. La bibliothèque doit alors
+//! être compilée pour la cible `wasm32-unknown-unknown` sans les fonctionnalités par défaut,
+//! l’interface en ligne de commande et la recherche parallèle n’y étant pas disponibles:
+//!
+//! ```sh
+//! cargo build --lib --target wasm32-unknown-unknown --no-default-features --features wasm
+//! ```
+//!
+//! La partie est menée coup par coup depuis JavaScript, sans fil d’exécution ni contrôle du
+//! temps:
+//!
+//! ```js
+//! const game = new_game(7, 6, 4);
+//!
+//! game.play_column(3);
+//! console.log(game.legal_moves(), game.board_state());
+//! ```
+//!
+//! L’état de chaque case est représenté par un entier: 0 pour une case vide, 1 pour un jeton
+//! jaune et 2 pour un jeton rouge. Les cases sont numérotées ligne par ligne en partant du bas,
+//! la case de la colonne `c` et de la ligne `r` ayant l’indice `r * cols + c`.
+//!
+//! Ce module n’est disponible qu’avec la fonctionnalité `wasm`.
+
+use super::connect_four::{Area, AreaConfig, Interface, State};
+use super::minimax_bot::MinimaxBot;
+use wasm_bindgen::prelude::*;
+
+/// Représentation d’un état de case transmise à JavaScript
+fn state_code(state : State) -> u8 {
+    match state {
+        State::NoToken => 0,
+        State::YellowToken => 1,
+        State::RedToken => 2,
+        State::GreenToken => 3,
+        State::BlueToken => 4,
+    }
+}
+
+/// Partie de puissance 4 manipulable depuis JavaScript
+///
+/// Les jetons jaunes jouent le premier coup.
+#[wasm_bindgen]
+pub struct Game {
+    #[doc(hidden)]
+    area : Area,
+}
+
+/// Crée une nouvelle partie
+///
+/// # Arguments
+///
+///  * `cols` : Le nombre de colonnes de la zone de jeu
+///  * `rows` : Le nombre de lignes de la zone de jeu
+///  * `win_length` : Le nombre de jetons à aligner afin de gagner
+///
+/// # Liste des erreurs possibles
+///
+///  * `InvalidConfig` : La configuration de la zone de jeu est invalide.
+#[wasm_bindgen]
+pub fn new_game(cols : usize, rows : usize, win_length : usize) -> Result<Game, JsError> {
+    let config = AreaConfig::new(cols, rows, win_length).map_err(|e| JsError::new(&e.to_string()))?;
+    let area = Area::with_config(config).map_err(|e| JsError::new(&e.to_string()))?;
+
+    return Ok(Game { area });
+}
+
+#[wasm_bindgen]
+impl Game {
+    /// Reprend une partie depuis un code de position (voir [`Area::from_code`])
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `InvalidConfig`, `InvalidPosition` : Le code ne décrit pas une position valide, ou le
+    ///    jeton devant jouer ne correspond pas au nombre de jetons de chaque couleur.
+    pub fn from_code(code : &str) -> Result<Game, JsError> {
+        let (area, to_move) = Area::from_code(code).map_err(|e| JsError::new(&e.to_string()))?;
+
+        if area.next_token().map_err(|e| JsError::new(&e.to_string()))?.is_some_and(|token| token != to_move) {
+            return Err(JsError::new("Le jeton devant jouer ne correspond pas à la position"));
+        }
+
+        return Ok(Game { area });
+    }
+
+    /// Fourni le code de la position actuelle (voir [`Area::to_code`])
+    pub fn code(&self) -> String {
+        let token = self.area.next_token().ok().flatten().unwrap_or(State::YellowToken);
+
+        return self.area.to_code(token).unwrap_or_default();
+    }
+
+    /// Fourni le nombre de colonnes de la zone de jeu
+    pub fn cols(&self) -> usize {
+        return self.area.config().cols;
+    }
+
+    /// Fourni le nombre de lignes de la zone de jeu
+    pub fn rows(&self) -> usize {
+        return self.area.config().rows;
+    }
+
+    /// Joue un coup dans la colonne donnée avec le jeton du joueur dont c’est le tour
+    ///
+    /// # Retour
+    ///
+    /// `true` si le coup gagne la partie
+    ///
+    /// # Liste des erreurs possibles
+    ///
+    ///  * `GameAlreadyFinished` : La partie est déjà terminée.
+    ///  * `InvalidColumn`, `FilledColumn` : La colonne n’existe pas ou est déjà remplie.
+    pub fn play_column(&mut self, column : usize) -> Result<bool, JsError> {
+        let token = self.area.next_token().ok().flatten().ok_or_else(|| JsError::new("La partie est déjà terminée"))?;
+
+        return self.area.set_token(token, column).map_err(|e| JsError::new(&e.to_string()));
+    }
+
+    /// Fourni les colonnes pouvant être jouées, aucune si la partie est terminée
+    pub fn legal_moves(&self) -> Vec<u32> {
+        if self.current_token() == 0 {
+            return Vec::new();
+        }
+
+        return self.area.get_available_columns().into_iter().map(|col| col as u32).collect();
+    }
+
+    /// Fourni l’état de chaque case de la zone de jeu, ligne par ligne en partant du bas
+    pub fn board_state(&self) -> Vec<u8> {
+        let (cols, rows) = (self.cols(), self.rows());
+        let mut board = vec![0; cols * rows];
+
+        for ((col, row), state) in self.area.iter() {
+            board[row * cols + col] = state_code(state);
+        }
+
+        return board;
+    }
+
+    /// Fourni le jeton devant jouer (1 pour les jaunes, 2 pour les rouges) ou 0 si la partie est
+    /// terminée
+    pub fn current_token(&self) -> u8 {
+        return state_code(self.area.next_token().ok().flatten().unwrap_or(State::NoToken));
+    }
+
+    /// Fourni le jeton ayant gagné la partie (1 pour les jaunes, 2 pour les rouges) ou 0 si aucun
+    /// alignement n’a été formé
+    pub fn winner(&self) -> u8 {
+        return match self.area.winning_cells().first() {
+            Some(cell) => state_code(self.area[*cell]),
+            None => 0,
+        };
+    }
+
+    /// Fourni les indices des cases de l’alignement gagnant, aucune si la partie n’est pas gagnée
+    pub fn winning_cells(&self) -> Vec<u32> {
+        let cols = self.cols();
+
+        return self.area.winning_cells().iter().map(|(col, row)| (row * cols + col) as u32).collect();
+    }
+
+    /// Recherche un coup à jouer pour le joueur dont c’est le tour
+    ///
+    /// # Arguments
+    ///
+    ///  * `depth` : La profondeur d’exploration du robot minimax utilisé
+    ///
+    /// # Retour
+    ///
+    /// La colonne conseillée ou `undefined` si la partie est terminée
+    pub fn suggest_move(&self, depth : usize) -> Option<u32> {
+        let token = self.area.next_token().ok().flatten()?;
+        let mut bot = MinimaxBot::new("Conseiller", depth);
+
+        return bot.play(&self.area, token).ok().map(|col| col as u32);
+    }
+}